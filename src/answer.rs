@@ -1,10 +1,24 @@
 use std::fmt::{Debug, Display};
 
+use crate::helper::json::JsonValue;
+
 /// An answer/solution abstraction.
 pub type Answer = Box<dyn Report>;
 
-pub trait Report : Debug {
+pub trait Report : Debug + Send {
     fn report(&self) -> String;
+
+    /// A short, single-line form of the answer, used where many answers are shown together.
+    /// Defaults to the first line of the report.
+    fn summary(&self) -> String {
+        self.report().lines().next().unwrap_or_default().to_string()
+    }
+
+    /// Structured form of the answer, used for machine-readable output.
+    /// Defaults to the report as a JSON string.
+    fn to_json(&self) -> JsonValue {
+        JsonValue::string(self.report())
+    }
 }
 
 /// A common shorthand for a container that implements the `Answer` abstraction
@@ -13,14 +27,22 @@ pub struct DisplayableAnswer<T: Display + Eq + PartialEq + 'static> {
     answer: T
 }
 
-impl <T: Display + Eq + PartialEq + Debug + 'static> DisplayableAnswer<T> {
+impl <T: Display + Eq + PartialEq + Debug + Send + 'static> DisplayableAnswer<T> {
     pub fn new(answer: T) -> Answer {
         Box::new(DisplayableAnswer{ answer })
     }
 }
 
-impl <T: Display + Eq + PartialEq + Debug + 'static> Report for DisplayableAnswer<T> {
+impl <T: Display + Eq + PartialEq + Debug + Send + 'static> Report for DisplayableAnswer<T> {
     fn report(&self) -> String {
         format!("The answer is: {}\n", self.answer)
     }
+
+    fn summary(&self) -> String {
+        self.answer.to_string()
+    }
+
+    fn to_json(&self) -> JsonValue {
+        JsonValue::from_display(&self.answer)
+    }
 }
\ No newline at end of file
@@ -1,48 +1,426 @@
+use std::collections::HashMap;
 
+use aoc_2024::{helper::display::vector_display, parameters::Parameters};
 
-fn find_and_get_argument_at_offset(keywords: &[&str], args: &Vec<String>, offset: usize) -> Option<String> {
-    let str_keywords = keywords
-        .iter()
-        .map(|keyword| keyword.to_string())
-        .collect::<Vec<_>>();
+mod test;
+
+const PROGRAM: &str = "aoc_2024";
+
+mod error {
+    const PREFIX: &str = "[Arguments]";
+
+    pub fn unknown_command(command: &str) -> String {
+        format!("{} unknown command '{}'", PREFIX, command)
+    }
+
+    pub fn unknown_option(command: &str, option: &str) -> String {
+        format!("{} unknown option '{}' for command '{}'", PREFIX, option, command)
+    }
+
+    pub fn missing_value(option: &str) -> String {
+        format!("{} option '{}' requires a value", PREFIX, option)
+    }
+
+    pub fn missing_option(command: &str, option: &str) -> String {
+        format!("{} command '{}' requires option '--{}'", PREFIX, command, option)
+    }
 
-    args.iter()
-        .enumerate()
-        .filter(|(_, arg)| str_keywords.contains(arg))
-        .map_while(|(i, _)| args.get(i + offset))
-        .collect::<Vec<_>>()
-        .first()
-        .map(|&s| s.clone())
+    pub fn invalid_value(option: &str, value: &str, reason: String) -> String {
+        format!("{} invalid value '{}' for option '--{}': {}", PREFIX, value, option, reason)
+    }
+
+    pub fn conflicting_options(option: &str, other: &str) -> String {
+        format!("{} option '--{}' cannot be combined with '--{}'", PREFIX, option, other)
+    }
 }
 
-fn is_part_2(args: &Vec<String>) -> bool {
-    find_and_get_argument_at_offset(&["--p2","--alt"], args, 0)
-        .is_some()
+/// Specification of a single command line option. Options with a `value` placeholder
+/// take the next argument as their value, the others are simple switches.
+struct OptionSpec {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    value: Option<&'static str>,
+    description: &'static str,
 }
 
-fn try_extract_day(args: &Vec<String>) -> Option<u8> {
-    find_and_get_argument_at_offset(&["--d", "--p"], args, 1)
-        .and_then(|argument| argument.parse::<u8>().ok())
+impl OptionSpec {
+    fn matches(&self, argument: &str) -> bool {
+        argument.strip_prefix("--").is_some_and(|name|name == self.name) || self.aliases.contains(&argument)
+    }
+
+    fn usage(&self) -> String {
+        let names = vector_display(&[vec![format!("--{}", self.name)], self.aliases.iter().map(|a|a.to_string()).collect()].concat(), ", ");
+        let names = match self.value {
+            Some(value) => format!("{} <{}>", names, value),
+            None => names,
+        };
+        format!("  {:<36} {}", names, self.description)
+    }
 }
 
-fn try_get_input_file(args: &Vec<String>) -> Option<String> {
-    find_and_get_argument_at_offset(&["--f", "--i"], args, 1)
+/// Specification of a subcommand: its name, a one-line summary and the options it accepts
+struct CommandSpec {
+    name: &'static str,
+    summary: &'static str,
+    options: &'static [OptionSpec],
+}
+
+const HELP_OPTION: OptionSpec = OptionSpec { name: "help", aliases: &["-h"], value: None, description: "Print help" };
+const JOBS_OPTION: OptionSpec = OptionSpec { name: "jobs", aliases: &["-j"], value: Some("N"), description: "Number of worker threads (default: number of CPUs)" };
+const PARAM_OPTION: OptionSpec = OptionSpec { name: "param", aliases: &["-P"], value: Some("KEY=VALUE"), description: "Override a parameter of the solver; may be repeated, see 'list' for the parameters" };
+const YEAR_OPTION: OptionSpec = OptionSpec { name: "year", aliases: &["-y"], value: Some("YEAR"), description: "Event year; if omitted, resolved from a 'yYYYY' or 'YYYY' component of the input path, or else the only registered year" };
+const FORMAT_OPTION: OptionSpec = OptionSpec { name: "format", aliases: &[], value: Some("FORMAT"), description: "Output format: 'text' (default) or 'json', one object per execution" };
+
+const RUN: CommandSpec = CommandSpec {
+    name: "run",
+    summary: "Solve a single day and part against an input file",
+    options: &[
+        OptionSpec { name: "file", aliases: &["-f", "--f", "--i"], value: Some("PATH"), description: "Path to the puzzle input file; '-' reads stdin" },
+        OptionSpec { name: "day", aliases: &["-d", "--d", "--p"], value: Some("DAY"), description: "Day to solve; resolved from the file path if omitted" },
+        YEAR_OPTION,
+        OptionSpec { name: "part2", aliases: &["-2", "--p2", "--alt"], value: None, description: "Solve part 2 instead of part 1" },
+        OptionSpec { name: "both", aliases: &["-b"], value: None, description: "Solve both parts, reading and parsing the input once" },
+        OptionSpec { name: "timings", aliases: &["-t"], value: None, description: "Print time spent in each pipeline stage" },
+        OptionSpec { name: "trace", aliases: &[], value: None, description: "Print the output of each pipeline stage: read lines, parsed and verified model, answer" },
+        PARAM_OPTION,
+        FORMAT_OPTION,
+        HELP_OPTION,
+    ],
+};
+
+const BENCH: CommandSpec = CommandSpec {
+    name: "bench",
+    summary: "Repeatedly solve a day and part, reporting min/median/max time per pipeline stage",
+    options: &[
+        OptionSpec { name: "file", aliases: &["-f"], value: Some("PATH"), description: "Path to the puzzle input file" },
+        OptionSpec { name: "day", aliases: &["-d"], value: Some("DAY"), description: "Day to solve; resolved from the file path if omitted" },
+        YEAR_OPTION,
+        OptionSpec { name: "part2", aliases: &["-2"], value: None, description: "Solve part 2 instead of part 1" },
+        OptionSpec { name: "runs", aliases: &["-n"], value: Some("N"), description: "Number of runs (default: 10)" },
+        PARAM_OPTION,
+        HELP_OPTION,
+    ],
+};
+
+const RUN_ALL: CommandSpec = CommandSpec {
+    name: "run-all",
+    summary: "Solve both parts of every day with an input file in a directory",
+    options: &[
+        OptionSpec { name: "dir", aliases: &["-D"], value: Some("DIR"), description: "Directory with input files; days are resolved from file names" },
+        YEAR_OPTION,
+        JOBS_OPTION,
+        PARAM_OPTION,
+        FORMAT_OPTION,
+        HELP_OPTION,
+    ],
+};
+
+const CHECK: CommandSpec = CommandSpec {
+    name: "check",
+    summary: "Check current answers against an expected-answers file",
+    options: &[
+        OptionSpec { name: "answers", aliases: &["-a"], value: Some("FILE"), description: "Expected-answers file" },
+        OptionSpec { name: "record", aliases: &[], value: None, description: "Fill in missing answers from the current results" },
+        YEAR_OPTION,
+        JOBS_OPTION,
+        HELP_OPTION,
+    ],
+};
+
+const WATCH: CommandSpec = CommandSpec {
+    name: "watch",
+    summary: "Re-solve a day and part whenever its input file changes",
+    options: &[
+        OptionSpec { name: "file", aliases: &["-f"], value: Some("PATH"), description: "Path to the puzzle input file" },
+        OptionSpec { name: "day", aliases: &["-d"], value: Some("DAY"), description: "Day to solve; resolved from the file path if omitted" },
+        YEAR_OPTION,
+        OptionSpec { name: "part2", aliases: &["-2"], value: None, description: "Solve part 2 instead of part 1" },
+        OptionSpec { name: "interval", aliases: &["-i"], value: Some("MS"), description: "Milliseconds between checks of the file (default: 500)" },
+        PARAM_OPTION,
+        HELP_OPTION,
+    ],
+};
+
+const REPL: CommandSpec = CommandSpec {
+    name: "repl",
+    summary: "Start an interactive shell to run days against input files",
+    options: &[
+        OptionSpec { name: "file", aliases: &["-f"], value: Some("PATH"), description: "Path to the initially selected input file" },
+        YEAR_OPTION,
+        PARAM_OPTION,
+        HELP_OPTION,
+    ],
+};
+
+const LIST: CommandSpec = CommandSpec {
+    name: "list",
+    summary: "List the registered days and parts, with the parameters of each day",
+    options: &[HELP_OPTION],
+};
+
+const COMMANDS: &[&CommandSpec] = &[&RUN, &RUN_ALL, &CHECK, &BENCH, &WATCH, &REPL, &LIST];
+
+/// Options of a subcommand after parsing, keyed by the option's name
+struct ParsedOptions {
+    command: &'static str,
+    values: HashMap<&'static str, Vec<String>>,
+}
+
+impl ParsedOptions {
+    fn parse(spec: &CommandSpec, args: &[String]) -> Result<ParsedOptions, String> {
+        let mut values: HashMap<&'static str, Vec<String>> = HashMap::new();
+        let mut iter = args.iter();
+        while let Some(argument) = iter.next() {
+            let option = spec.options.iter()
+                .find(|option|option.matches(argument))
+                .ok_or_else(||error::unknown_option(spec.name, argument))?;
+            let value = match option.value {
+                Some(_) => iter.next().ok_or_else(||error::missing_value(argument))?.clone(),
+                None => String::new(),
+            };
+            values.entry(option.name).or_default().push(value);
+        }
+        Ok(ParsedOptions { command: spec.name, values })
+    }
+
+    fn is_set(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// Returns the value of the option; if it is given multiple times, the last one is used
+    fn get(&self, name: &str) -> Option<&String> {
+        self.values.get(name).and_then(|values|values.last())
+    }
+
+    /// Parses all values of the `param` option, each given as `key=value`; a key given multiple times keeps its last value
+    fn parameters(&self) -> Result<Parameters, String> {
+        let mut parameters = Parameters::new();
+        for value in self.values.get(PARAM_OPTION.name).into_iter().flatten() {
+            match value.split_once('=') {
+                Some((key, parameter)) if !key.is_empty() && !parameter.is_empty() => { parameters.insert(key, parameter); },
+                _ => return Err(error::invalid_value(PARAM_OPTION.name, value, String::from("expected KEY=VALUE"))),
+            }
+        }
+        Ok(parameters)
+    }
+
+    /// Fails if the option is set together with any of the `others`
+    fn check_conflicts(&self, name: &str, others: &[&str]) -> Result<(), String> {
+        match others.iter().find(|&&other|self.is_set(name) && self.is_set(other)) {
+            Some(other) => Err(error::conflicting_options(name, other)),
+            None => Ok(()),
+        }
+    }
+
+    fn require(&self, name: &str) -> Result<&String, String> {
+        self.get(name).ok_or_else(||error::missing_option(self.command, name))
+    }
+
+    fn parse_value<T>(&self, name: &str) -> Result<Option<T>, String>
+    where T: std::str::FromStr, T::Err: std::fmt::Display {
+        match self.get(name) {
+            Some(value) => value.parse::<T>()
+                .map(Some)
+                .map_err(|e|error::invalid_value(name, value, e.to_string())),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Output format of the executions' outcomes
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(String::from("expected 'text' or 'json'")),
+        }
+    }
 }
 
 #[derive(Eq, PartialEq, Debug)]
-pub struct Arguments {
-    pub optional_filepath: Option<String>,
+pub struct RunArguments {
+    pub filepath: String,
+    pub optional_day: Option<u8>,
+    pub optional_year: Option<u16>,
+    pub is_part_2: bool,
+    pub both_parts: bool,
+    pub show_timings: bool,
+    pub trace: bool,
+    pub format: OutputFormat,
+    pub parameters: Parameters,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct RunAllArguments {
+    pub input_directory: String,
+    pub optional_year: Option<u16>,
+    pub jobs: Option<usize>,
+    pub format: OutputFormat,
+    pub parameters: Parameters,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct BenchArguments {
+    pub filepath: String,
+    pub optional_day: Option<u8>,
+    pub optional_year: Option<u16>,
+    pub is_part_2: bool,
+    pub runs: usize,
+    pub parameters: Parameters,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct WatchArguments {
+    pub filepath: String,
     pub optional_day: Option<u8>,
-    pub is_part_2: bool
+    pub optional_year: Option<u16>,
+    pub is_part_2: bool,
+    pub interval_ms: u64,
+    pub parameters: Parameters,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct ReplArguments {
+    pub optional_filepath: Option<String>,
+    pub optional_year: Option<u16>,
+    pub parameters: Parameters,
 }
 
-impl Arguments {
-    pub fn new(args: &Vec<String>) -> Arguments {
-        Arguments {
-            optional_filepath: try_get_input_file(args),
-            optional_day: try_extract_day(args),
-            is_part_2: is_part_2(args)
+#[derive(Eq, PartialEq, Debug)]
+pub struct CheckArguments {
+    pub answers_filepath: String,
+    pub record: bool,
+    pub optional_year: Option<u16>,
+    pub jobs: Option<usize>,
+}
+
+/// A command requested on the command line
+#[derive(Eq, PartialEq, Debug)]
+pub enum Command {
+    Run(RunArguments),
+    /// Run all registered executers against input files in the given directory
+    RunAll(RunAllArguments),
+    Check(CheckArguments),
+    Bench(BenchArguments),
+    /// Re-run the executer whenever its input file is modified
+    Watch(WatchArguments),
+    /// Start an interactive shell
+    Repl(ReplArguments),
+    List,
+    /// Print the given help text
+    Help(String),
+}
+
+impl Command {
+    /// Parses the command line arguments, where `args[0]` is the program name.
+    /// When the first argument is an option rather than a command name, the `run` command is assumed,
+    /// so that `--f input.txt --d 5` keeps working.
+    pub fn parse(args: &[String]) -> Result<Command, String> {
+        let args = args.get(1..).unwrap_or(&[]);
+        match args.first().map(String::as_str) {
+            None | Some("help") | Some("-h") | Some("--help") => Ok(Command::Help(general_help())),
+            Some(first) if first.starts_with('-') => Self::parse_command(&RUN, args),
+            Some(name) => COMMANDS.iter()
+                .find(|spec|spec.name == name)
+                .ok_or_else(||error::unknown_command(name))
+                .and_then(|spec|Self::parse_command(spec, &args[1..])),
+        }
+    }
+
+    fn parse_command(spec: &CommandSpec, args: &[String]) -> Result<Command, String> {
+        let options = ParsedOptions::parse(spec, args)?;
+        if options.is_set(HELP_OPTION.name) {
+            return Ok(Command::Help(command_help(spec)));
+        }
+        match spec.name {
+            "run" => {
+                options.check_conflicts("trace", &["both", "timings", "format"])?;
+                Ok(Command::Run(RunArguments {
+                    filepath: options.require("file")?.clone(),
+                    optional_day: options.parse_value("day")?,
+                    optional_year: options.parse_value("year")?,
+                    is_part_2: options.is_set("part2"),
+                    both_parts: options.is_set("both"),
+                    show_timings: options.is_set("timings"),
+                    trace: options.is_set("trace"),
+                    format: options.parse_value("format")?.unwrap_or_default(),
+                    parameters: options.parameters()?,
+                }))
+            },
+            "bench" => Ok(Command::Bench(BenchArguments {
+                filepath: options.require("file")?.clone(),
+                optional_day: options.parse_value("day")?,
+                optional_year: options.parse_value("year")?,
+                is_part_2: options.is_set("part2"),
+                runs: options.parse_value("runs")?.unwrap_or(10),
+                parameters: options.parameters()?,
+            })),
+            "watch" => Ok(Command::Watch(WatchArguments {
+                filepath: options.require("file")?.clone(),
+                optional_day: options.parse_value("day")?,
+                optional_year: options.parse_value("year")?,
+                is_part_2: options.is_set("part2"),
+                interval_ms: options.parse_value("interval")?.unwrap_or(500),
+                parameters: options.parameters()?,
+            })),
+            "repl" => Ok(Command::Repl(ReplArguments {
+                optional_filepath: options.get("file").cloned(),
+                optional_year: options.parse_value("year")?,
+                parameters: options.parameters()?,
+            })),
+            "run-all" => Ok(Command::RunAll(RunAllArguments {
+                input_directory: options.require("dir")?.clone(),
+                optional_year: options.parse_value("year")?,
+                jobs: options.parse_value("jobs")?,
+                format: options.parse_value("format")?.unwrap_or_default(),
+                parameters: options.parameters()?,
+            })),
+            "check" => Ok(Command::Check(CheckArguments {
+                answers_filepath: options.require("answers")?.clone(),
+                record: options.is_set("record"),
+                optional_year: options.parse_value("year")?,
+                jobs: options.parse_value("jobs")?,
+            })),
+            _ => Ok(Command::List),
         }
     }
+}
 
-}
\ No newline at end of file
+fn general_help() -> String {
+    let commands = COMMANDS.iter()
+        .map(|spec|format!("  {:<10} {}", spec.name, spec.summary))
+        .collect::<Vec<_>>();
+    vector_display(&vec![
+        String::from("Advent of Code 2024 solutions"),
+        String::new(),
+        format!("Usage: {} <COMMAND> [OPTIONS]", PROGRAM),
+        String::new(),
+        String::from("Commands:"),
+        vector_display(&commands, "\n"),
+        format!("  {:<10} {}", "help", "Print this message"),
+        String::new(),
+        format!("Run '{} <COMMAND> --help' for the options of a command.", PROGRAM),
+    ], "\n")
+}
+
+fn command_help(spec: &CommandSpec) -> String {
+    let options = spec.options.iter().map(OptionSpec::usage).collect::<Vec<_>>();
+    vector_display(&vec![
+        spec.summary.to_string(),
+        String::new(),
+        format!("Usage: {} {} [OPTIONS]", PROGRAM, spec.name),
+        String::new(),
+        String::from("Options:"),
+        vector_display(&options, "\n"),
+    ], "\n")
+}
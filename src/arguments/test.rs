@@ -0,0 +1,155 @@
+#[cfg(test)]
+pub mod suite {
+    use aoc_2024::parameters::Parameters;
+
+    use crate::arguments::{Command, OutputFormat, RunArguments};
+
+    // shorthand to turn Vec<&str> into parsed command
+    fn parse(v: Vec<&str>) -> Result<Command, String> {
+        Command::parse(&v.iter().map(|&s|s.to_string()).collect::<Vec<_>>())
+    }
+
+    // shorthand to make run command by giving explicit values (for expected results)
+    fn make_run(file: &str, day: Option<u8>, is_part_2: bool) -> Command {
+        Command::Run(RunArguments { filepath: file.to_string(), optional_day: day, optional_year: None, is_part_2, both_parts: false, show_timings: false, trace: false, format: OutputFormat::Text, parameters: Parameters::new() })
+    }
+
+    #[test]
+    pub fn test_run_command_processing() {
+        for (act, exp) in [
+            (parse(vec!["Program", "run", "--file", "input.txt"]), make_run("input.txt", None, false)),
+            (parse(vec!["Program", "run", "-f", "input.txt", "-d", "10", "-2"]), make_run("input.txt", Some(10), true)),
+            (parse(vec!["Program", "run", "--day", "63", "--part2", "--file", "A.txt"]), make_run("A.txt", Some(63), true)),
+            (parse(vec!["Program", "run", "--i", "A.txt", "--f", "B.txt"]), make_run("B.txt", None, false)),
+        ] {
+            assert_eq!(act, Ok(exp));
+        }
+    }
+
+    #[test]
+    pub fn test_legacy_invocation_defaults_to_run() {
+        for (act, exp) in [
+            (parse(vec!["Program", "--d", "10", "--f", "input.txt"]), make_run("input.txt", Some(10), false)),
+            (parse(vec!["Program", "--alt", "--p", "7", "--i", "input.txt"]), make_run("input.txt", Some(7), true)),
+            (parse(vec!["Program", "--f", "--p2"]), make_run("--p2", None, false)),
+        ] {
+            assert_eq!(act, Ok(exp));
+        }
+    }
+
+    #[test]
+    pub fn test_bench_command_processing() {
+        use crate::arguments::BenchArguments;
+        let make_bench = |runs|Command::Bench(BenchArguments { filepath: "A.txt".to_string(), optional_day: Some(6), optional_year: None, is_part_2: true, runs, parameters: Parameters::new() });
+        assert_eq!(parse(vec!["Program", "bench", "-f", "A.txt", "-d", "6", "-2"]), Ok(make_bench(10)));
+        assert_eq!(parse(vec!["Program", "bench", "-f", "A.txt", "-d", "6", "-2", "--runs", "3"]), Ok(make_bench(3)));
+        assert!(parse(vec!["Program", "bench", "-f", "A.txt", "-n", "-3"]).is_err());
+    }
+
+    #[test]
+    pub fn test_both_parts_option() {
+        assert!(matches!(parse(vec!["Program", "run", "-f", "A.txt", "--both"]),
+            Ok(Command::Run(RunArguments { both_parts: true, is_part_2: false, .. }))));
+        assert!(matches!(parse(vec!["Program", "run", "-b", "-f", "A.txt", "-d", "6"]),
+            Ok(Command::Run(RunArguments { both_parts: true, optional_day: Some(6), .. }))));
+    }
+
+    #[test]
+    pub fn test_trace_option() {
+        assert!(matches!(parse(vec!["Program", "run", "-f", "A.txt", "--trace", "-2"]),
+            Ok(Command::Run(RunArguments { trace: true, is_part_2: true, .. }))));
+    }
+
+    #[test]
+    pub fn test_format_option() {
+        use crate::arguments::RunAllArguments;
+        assert!(matches!(parse(vec!["Program", "run", "-f", "A.txt", "--format", "json"]),
+            Ok(Command::Run(RunArguments { format: OutputFormat::Json, .. }))));
+        assert_eq!(parse(vec!["Program", "run-all", "-D", "inputs", "--format", "text"]),
+            Ok(Command::RunAll(RunAllArguments { input_directory: "inputs".to_string(), optional_year: None, jobs: None, format: OutputFormat::Text, parameters: Parameters::new() })));
+    }
+
+    #[test]
+    pub fn test_jobs_option() {
+        use crate::arguments::{CheckArguments, RunAllArguments};
+        assert_eq!(parse(vec!["Program", "run-all", "-D", "inputs", "-j", "4"]),
+            Ok(Command::RunAll(RunAllArguments { input_directory: "inputs".to_string(), optional_year: None, jobs: Some(4), format: OutputFormat::Text, parameters: Parameters::new() })));
+        assert_eq!(parse(vec!["Program", "check", "-a", "answers.txt", "--jobs", "2"]),
+            Ok(Command::Check(CheckArguments { answers_filepath: "answers.txt".to_string(), record: false, optional_year: None, jobs: Some(2) })));
+        assert!(parse(vec!["Program", "run-all", "-D", "inputs", "-j", "many"]).is_err());
+    }
+
+    #[test]
+    pub fn test_param_option() {
+        let parsed = parse(vec!["Program", "run", "-f", "A.txt", "--param", "chain_part_2=30", "-P", "cutoff=10", "-P", "cutoff=20"]);
+        let Ok(Command::Run(RunArguments { parameters, .. })) = parsed else { panic!("unexpected {:?}", parsed) };
+        assert_eq!(parameters.get("chain_part_2"), Some("30"));
+        assert_eq!(parameters.get("cutoff"), Some("20"));
+        assert!(matches!(parse(vec!["Program", "bench", "-f", "A.txt", "-P", "max_runtime=500"]),
+            Ok(Command::Bench(ref arguments)) if arguments.parameters.get("max_runtime") == Some("500")));
+    }
+
+    #[test]
+    pub fn test_year_option() {
+        use crate::arguments::{CheckArguments, RunAllArguments};
+        assert!(matches!(parse(vec!["Program", "run", "-f", "A.txt", "--year", "2023", "-d", "5"]),
+            Ok(Command::Run(RunArguments { optional_year: Some(2023), optional_day: Some(5), .. }))));
+        assert!(matches!(parse(vec!["Program", "run-all", "-D", "inputs", "-y", "2024"]),
+            Ok(Command::RunAll(RunAllArguments { optional_year: Some(2024), .. }))));
+        assert!(matches!(parse(vec!["Program", "check", "-a", "answers.txt", "-y", "2022"]),
+            Ok(Command::Check(CheckArguments { optional_year: Some(2022), .. }))));
+        assert!(parse(vec!["Program", "run", "-f", "A.txt", "--year", "twenty"]).is_err());
+    }
+
+    #[test]
+    pub fn test_watch_command_processing() {
+        use crate::arguments::WatchArguments;
+        let make_watch = |interval_ms|Command::Watch(WatchArguments { filepath: "A.txt".to_string(), optional_day: Some(6), optional_year: None, is_part_2: true, interval_ms, parameters: Parameters::new() });
+        assert_eq!(parse(vec!["Program", "watch", "-f", "A.txt", "-d", "6", "-2"]), Ok(make_watch(500)));
+        assert_eq!(parse(vec!["Program", "watch", "-f", "A.txt", "-d", "6", "-2", "--interval", "100"]), Ok(make_watch(100)));
+        assert!(parse(vec!["Program", "watch", "-d", "6"]).is_err());
+    }
+
+    #[test]
+    pub fn test_repl_command_processing() {
+        use crate::arguments::ReplArguments;
+        assert_eq!(parse(vec!["Program", "repl"]),
+            Ok(Command::Repl(ReplArguments { optional_filepath: None, optional_year: None, parameters: Parameters::new() })));
+        assert!(matches!(parse(vec!["Program", "repl", "-f", "A.txt", "-P", "cutoff=10"]),
+            Ok(Command::Repl(ReplArguments { optional_filepath: Some(ref file), ref parameters, .. })) if file == "A.txt" && parameters.get("cutoff") == Some("10")));
+    }
+
+    #[test]
+    pub fn test_list_and_help_commands() {
+        assert_eq!(parse(vec!["Program", "list"]), Ok(Command::List));
+        for args in [
+            vec!["Program"],
+            vec!["Program", "help"],
+            vec!["Program", "--help"],
+            vec!["Program", "run", "-h"],
+            vec!["Program", "list", "--help"],
+        ] {
+            assert!(matches!(parse(args), Ok(Command::Help(_))));
+        }
+    }
+
+    #[test]
+    pub fn test_invalid_arguments() {
+        for (args, expected_error) in [
+            (vec!["Program", "ok", "--p2"], "unknown command"),
+            (vec!["Program", "run", "--p2"], "requires option '--file'"),
+            (vec!["Program", "run", "--file"], "requires a value"),
+            (vec!["Program", "run", "--file", "input.txt", "--d", "ok"], "invalid value 'ok'"),
+            (vec!["Program", "run", "--file", "input.txt", "--part-2"], "unknown option '--part-2'"),
+            (vec!["Program", "list", "--file", "input.txt"], "unknown option '--file'"),
+            (vec!["Program", "run", "--file", "input.txt", "--format", "xml"], "expected 'text' or 'json'"),
+            (vec!["Program", "run", "--file", "input.txt", "--param", "chain"], "expected KEY=VALUE"),
+            (vec!["Program", "check", "--answers", "answers.txt", "-P", "chain=3"], "unknown option '-P'"),
+            (vec!["Program", "run", "--file", "input.txt", "--trace", "--both"], "'--trace' cannot be combined with '--both'"),
+            (vec!["Program", "run", "--file", "input.txt", "-t", "--trace"], "'--trace' cannot be combined with '--timings'"),
+        ] {
+            let error = parse(args).unwrap_err();
+            assert!(error.contains(expected_error), "'{}' does not contain '{}'", error, expected_error);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use crate::{executer::{Stage, StageTimings}, executer_manager::ExecuterManager, helper::display::text_table};
+
+mod error {
+    const PREFIX: &str = "[Benchmark]";
+
+    pub fn zero_runs() -> String {
+        format!("{} number of runs must be at least 1", PREFIX)
+    }
+
+    pub fn failed_run(run: usize, error: String) -> String {
+        format!("{} run #{} failed: {}", PREFIX, run, error)
+    }
+}
+
+/// Minimum, median and maximum of a sequence of durations
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct DurationStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl DurationStats {
+    /// Returns `None` for empty sequence of durations. For even number of durations,
+    /// the median is the mean of the two middle ones.
+    pub fn new(mut durations: Vec<Duration>) -> Option<DurationStats> {
+        if durations.is_empty() { return None; }
+        durations.sort();
+        let n = durations.len();
+        let median = if n % 2 == 1 { durations[n / 2] } else { (durations[n / 2 - 1] + durations[n / 2]) / 2 };
+        Some(DurationStats { min: durations[0], median, max: durations[n - 1] })
+    }
+}
+
+/// Stage timings of repeated runs of the same executer against the same input
+#[derive(Debug)]
+pub struct BenchmarkReport {
+    pub samples: Vec<StageTimings>,
+}
+
+impl BenchmarkReport {
+    /// Statistics of a stage over all runs; `None` if the stage was never run (e.g. executer is not staged)
+    pub fn stage_stats(&self, stage: Stage) -> Option<DurationStats> {
+        DurationStats::new(self.samples.iter().filter_map(|sample|sample.get(stage)).collect())
+    }
+
+    pub fn total_stats(&self) -> Option<DurationStats> {
+        DurationStats::new(self.samples.iter().map(StageTimings::total).collect())
+    }
+
+    /// Renders a table with min/median/max per stage and for the total
+    pub fn report(&self) -> String {
+        let row = |name: String, stats: Option<DurationStats>| match stats {
+            Some(DurationStats { min, median, max }) =>
+                vec![name, format!("{:.3?}", min), format!("{:.3?}", median), format!("{:.3?}", max)],
+            None => vec![name, "-".to_string(), "-".to_string(), "-".to_string()],
+        };
+        let mut rows = Stage::all().into_iter()
+            .map(|stage|row(stage.to_string(), self.stage_stats(stage)))
+            .collect::<Vec<_>>();
+        rows.push(row("total".to_string(), self.total_stats()));
+        text_table(&["Stage", "Min", "Median", "Max"], &rows)
+    }
+}
+
+/// Runs the executer `runs` times against the input file, measuring each stage.
+/// Fails if any of the runs fails, as timings of a failed run are not comparable.
+pub fn run_benchmark(manager: &ExecuterManager, input_filepath: &str, day: Option<u8>, is_part_2: bool, runs: usize)
+-> Result<BenchmarkReport, String> {
+    if runs == 0 { return Err(error::zero_runs()); }
+    let mut samples = vec![];
+    for run in 1..=runs {
+        match manager.try_execute_timed(input_filepath, day, is_part_2)? {
+            (Ok(_), timings) => samples.push(timings),
+            (Err(e), _) => return Err(error::failed_run(run, e.to_string())),
+        }
+    }
+    Ok(BenchmarkReport { samples })
+}
@@ -0,0 +1,250 @@
+//! Command line interface: executes the commands parsed by `arguments::Command` and prints their outcome
+
+use std::{io::Write, process::ExitCode, time::Duration};
+
+use aoc_2024::{benchmark, create_executer_manager, create_executer_manager_with, execution_summary, regression, Answer, DAYS};
+use crate::arguments::{BenchArguments, CheckArguments, Command, OutputFormat, ReplArguments, RunAllArguments, RunArguments, WatchArguments};
+use aoc_2024::repl::{Reply, Session};
+use aoc_2024::watch::{AnswerChange, WatchRun, Watcher};
+use aoc_2024::helper::display::vector_display;
+use aoc_2024::pipeline_error::PipelineError;
+
+/// Limits of the output of each stage printed by `run --trace`
+const TRACE_MAX_LINES: usize = 50;
+const TRACE_MAX_CHARS: usize = 4_000;
+
+fn report_outcome(outcome: Result<Answer, PipelineError>) -> ExitCode {
+    match outcome {
+        Ok(report) => {
+            print!("{}", report.report());
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("An error occurred during processing.");
+            eprintln!("{}", error.diagnostic());
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn get_outcome(arguments: RunArguments) -> Result<Answer, PipelineError> {
+    let RunArguments { filepath, optional_day, optional_year, is_part_2, show_timings, trace, parameters, .. } = arguments;
+    let manager = create_executer_manager_with(parameters)?.with_year(optional_year);
+    if trace {
+        let (outcome, trace) = manager.try_execute_traced(&filepath, optional_day, is_part_2)?;
+        println!("{}", trace.report(TRACE_MAX_LINES, TRACE_MAX_CHARS));
+        println!();
+        return outcome;
+    }
+    if !show_timings {
+        return manager.try_execute_executer(&filepath, optional_day, is_part_2);
+    }
+    let (outcome, timings) = manager.try_execute_timed(&filepath, optional_day, is_part_2)?;
+    println!("Timings: {}", timings);
+    outcome
+}
+
+/// Runs a single executer and prints its execution as a JSON object
+fn run_json(arguments: RunArguments) -> ExitCode {
+    let RunArguments { filepath, optional_day, optional_year, is_part_2, parameters, .. } = arguments;
+    let record = create_executer_manager_with(parameters)
+        .and_then(|manager|manager.with_year(optional_year).try_execute_record(&filepath, optional_day, is_part_2));
+    match record {
+        Ok(record) => {
+            println!("{}", execution_summary::record_json(&record));
+            if record.outcome.is_ok() { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+        },
+        Err(error) => {
+            println!("{}", execution_summary::failure_json(optional_year, optional_day, is_part_2, &filepath, &error.into()));
+            ExitCode::FAILURE
+        },
+    }
+}
+
+/// Solves both parts of the day and prints the answer of each part
+fn run_both(arguments: RunArguments) -> ExitCode {
+    let RunArguments { filepath, optional_day, optional_year, show_timings, format, parameters, .. } = arguments;
+    let records = match create_executer_manager_with(parameters).and_then(|manager|manager.with_year(optional_year).try_execute_both(&filepath, optional_day)) {
+        Ok(records) => records,
+        Err(error) if format == OutputFormat::Json => {
+            println!("{}", execution_summary::failure_json(optional_year, optional_day, false, &filepath, &error.into()));
+            return ExitCode::FAILURE;
+        },
+        Err(error) => return report_outcome(Err(error.into())),
+    };
+    let mut exit_code = ExitCode::SUCCESS;
+    for record in records {
+        if record.outcome.is_err() { exit_code = ExitCode::FAILURE; }
+        match format {
+            OutputFormat::Json => println!("{}", execution_summary::record_json(&record)),
+            OutputFormat::Text => {
+                println!("Part {}:", if record.key.is_part_2 { 2 } else { 1 });
+                if show_timings { println!("Timings: {}", record.timings); }
+                report_outcome(record.outcome);
+            },
+        }
+    }
+    exit_code
+}
+
+/// Runs the benchmark and prints the per-stage statistics
+fn bench(arguments: BenchArguments) -> ExitCode {
+    let BenchArguments { filepath, optional_day, optional_year, is_part_2, runs, parameters } = arguments;
+    let report = create_executer_manager_with(parameters)
+        .and_then(|manager|benchmark::run_benchmark(&manager.with_year(optional_year), &filepath, optional_day, is_part_2, runs));
+    match report {
+        Ok(report) => {
+            println!("Benchmark of {} run(s) against '{}'", runs, filepath);
+            println!("{}", report.report());
+            ExitCode::SUCCESS
+        },
+        Err(error) => report_outcome(Err(error.into())),
+    }
+}
+
+/// Prints the answer and timings of the run, highlighting an answer that differs from the previous one
+fn report_watch_run(run: WatchRun) {
+    println!();
+    println!("Run #{}:", run.number);
+    println!("Timings: {}", run.timings);
+    let change = run.change;
+    report_outcome(run.outcome);
+    if let Some(AnswerChange::Changed(previous)) = change {
+        println!("*** The answer changed, previously: {}", previous.lines().next().unwrap_or_default());
+    }
+}
+
+/// Solves the day and part whenever the input file is modified, until the program is interrupted.
+/// An error of reading the modification time is printed only once until the next run.
+fn watch(arguments: WatchArguments) -> ExitCode {
+    let WatchArguments { filepath, optional_day, optional_year, is_part_2, interval_ms, parameters } = arguments;
+    let manager = match create_executer_manager_with(parameters) {
+        Ok(manager) => manager.with_year(optional_year),
+        Err(error) => return report_outcome(Err(error.into())),
+    };
+    let mut watcher = match Watcher::new(&manager, &filepath, optional_day, is_part_2, Duration::from_millis(interval_ms)) {
+        Ok(watcher) => watcher,
+        Err(error) => return report_outcome(Err(error.into())),
+    };
+    println!("Watching '{}' for changes, press Ctrl+C to stop", filepath);
+    let mut last_error = None;
+    loop {
+        match watcher.poll() {
+            Ok(Some(run)) => {
+                last_error = None;
+                report_watch_run(run);
+            },
+            Ok(None) => {},
+            Err(error) => {
+                if last_error.as_ref() != Some(&error) { eprintln!("{}", error); }
+                last_error = Some(error);
+            },
+        }
+        watcher.wait();
+    }
+}
+
+/// Runs the interactive shell, reading commands from the standard input until `quit` or the end of the input
+fn repl(arguments: ReplArguments) -> ExitCode {
+    let ReplArguments { optional_filepath, optional_year, parameters } = arguments;
+    let mut session = match Session::new(parameters, optional_year) {
+        Ok(session) => session.with_input(optional_filepath),
+        Err(error) => return report_outcome(Err(error.into())),
+    };
+    println!("Type 'help' for the list of commands.");
+    let mut line = String::new();
+    loop {
+        print!("aoc> ");
+        let _ = std::io::stdout().flush();
+        line.clear();
+        match std::io::stdin().read_line(&mut line) {
+            Ok(0) => return ExitCode::SUCCESS,
+            Ok(_) => {},
+            Err(error) => {
+                eprintln!("Error when reading the standard input: {}", error);
+                return ExitCode::FAILURE;
+            },
+        }
+        match session.handle(&line) {
+            Reply::Output(output) if output.is_empty() => {},
+            Reply::Output(output) => println!("{}", output),
+            Reply::Quit => return ExitCode::SUCCESS,
+        }
+    }
+}
+
+/// Lists the days with their titles and implemented parts, each followed by the parameters the day declares
+fn list_days() -> String {
+    let mut lines = vec![];
+    for day in DAYS {
+        let parts = if day.has_part_2 { "part 1, part 2" } else { "part 1" };
+        lines.push(format!("{} Day {:02}: {} ({})", day.year, day.number, day.title, parts));
+        lines.extend(day.parameters.iter().map(|spec|format!("    {}", spec)));
+    }
+    vector_display(&lines, "\n")
+}
+
+/// Number of worker threads to use: the requested number of jobs, or the available parallelism by default
+fn workers(jobs: Option<usize>) -> usize {
+    jobs.unwrap_or_else(||std::thread::available_parallelism().map_or(1, |n|n.get()))
+}
+
+/// Runs all registered executers against input files in the directory and prints the summary table,
+/// or one JSON object per execution
+fn run_all(arguments: RunAllArguments) -> ExitCode {
+    let RunAllArguments { input_directory, optional_year, jobs, format, parameters } = arguments;
+    let records = match create_executer_manager_with(parameters).and_then(|manager|manager.with_workers(workers(jobs)).with_year(optional_year).try_execute_directory(&input_directory)) {
+        Ok(records) => records,
+        Err(error) => return report_outcome(Err(error.into())),
+    };
+    match format {
+        OutputFormat::Text => {
+            println!("{}", execution_summary::summary_table(&records));
+            println!();
+            println!("{}", execution_summary::summary_totals(&records));
+        },
+        OutputFormat::Json => records.iter().for_each(|record|println!("{}", execution_summary::record_json(record))),
+    }
+    match records.iter().all(|record|record.outcome.is_ok()) {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+/// Checks the current answers against the expected-answers file and prints the outcome
+fn check(arguments: CheckArguments) -> ExitCode {
+    let CheckArguments { answers_filepath, record, optional_year, jobs } = arguments;
+    let outcome = match create_executer_manager().and_then(|manager|regression::check_answers(&manager.with_workers(workers(jobs)).with_year(optional_year), &answers_filepath, record)) {
+        Ok(outcome) => outcome,
+        Err(error) => return report_outcome(Err(error.into())),
+    };
+    println!("{}", outcome.report());
+    match outcome.has_failures() {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+/// Executes the command and returns the exit code of the program
+pub fn execute_command(command: Command) -> ExitCode {
+    match command {
+        Command::Help(help) => {
+            println!("{}", help);
+            ExitCode::SUCCESS
+        },
+        Command::Run(arguments) if arguments.both_parts => run_both(arguments),
+        Command::Run(arguments) => match arguments.format {
+            OutputFormat::Text => report_outcome(get_outcome(arguments)),
+            OutputFormat::Json => run_json(arguments),
+        },
+        Command::RunAll(arguments) => run_all(arguments),
+        Command::Check(arguments) => check(arguments),
+        Command::Bench(arguments) => bench(arguments),
+        Command::Watch(arguments) => watch(arguments),
+        Command::Repl(arguments) => repl(arguments),
+        Command::List => {
+            println!("{}", list_days());
+            ExitCode::SUCCESS
+        },
+    }
+}
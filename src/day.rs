@@ -0,0 +1,88 @@
+use crate::{executer_manager::ExecuterManager, parameters::ParameterSpec};
+
+/// Year of the event the days declared by `define_day!` belong to; their executers are registered under it
+pub const YEAR: u16 = 2024;
+
+/// Metadata of a day, declared by `define_day!`, together with the function registering its executers
+pub struct Day {
+    pub year: u16,
+    pub number: u8,
+    pub title: &'static str,
+    pub has_part_2: bool,
+    pub parameters: &'static [ParameterSpec],
+    pub register: fn(ExecuterManager) -> Result<ExecuterManager, String>,
+}
+
+/// Declares a day in one place: its number and title, the type of its parsed input, the reader, parser
+/// and verifier shared by both parts, and the solver of each part. The components are given in their
+/// `Result<_, String>` form, as in `try_make_pipeline`; part 2 may be left out.
+///
+/// A day with tunable parameters declares them as `parameters: SPECS`. Its verifier and solvers read them with
+/// `ParameterisedVerifier` and `ParameterisedSolver`, from the parameters resolved when reading the input (see
+/// `PipelinedExecuter::with_parameters`). It generates:
+/// - `PARAMETERS`, the declared parameters,
+/// - `make_pipeline(is_part_2)` making the pipeline of either part,
+/// - `DAY`, the metadata of the day used to register it.
+macro_rules! define_day {
+    (
+        number: $number:literal,
+        title: $title:literal,
+        input: $input:ty,
+        reader: $reader:expr,
+        parser: $parser:expr,
+        verifier: $verifier:expr,
+        parameters: $specs:expr,
+        part_1: $part_1:expr,
+        $(part_2: $part_2:expr,)?
+    ) => {
+        $crate::day::define_day!(@day $number, $title, $input, $reader, $parser, $verifier, $specs, $part_1, $($part_2)?);
+    };
+    (
+        number: $number:literal,
+        title: $title:literal,
+        input: $input:ty,
+        reader: $reader:expr,
+        parser: $parser:expr,
+        verifier: $verifier:expr,
+        part_1: $part_1:expr,
+        $(part_2: $part_2:expr,)?
+    ) => {
+        $crate::day::define_day!(@day $number, $title, $input, $reader, $parser, $verifier, &[], $part_1, $($part_2)?);
+    };
+    (@day $number:literal, $title:literal, $input:ty, $reader:expr, $parser:expr, $verifier:expr,
+        $specs:expr, $part_1:expr, $($part_2:expr)?) => {
+        pub const PARAMETERS: &[$crate::parameters::ParameterSpec] = $specs;
+
+        pub const DAY: $crate::day::Day = $crate::day::Day {
+            year: $crate::day::YEAR,
+            number: $number,
+            title: $title,
+            has_part_2: $crate::day::define_day!(@has_part_2 $($part_2)?),
+            parameters: PARAMETERS,
+            register,
+        };
+
+        pub fn make_pipeline(is_part_2: bool) -> Result<$crate::pipelined_executer::PipelinedExecuter<$input>, String> {
+            use $crate::pipelined_executer::try_make_pipeline;
+            let pipeline = match is_part_2 {
+                false => try_make_pipeline($reader, $parser, $verifier, $part_1),
+                true => $crate::day::define_day!(@part_2 $number, $reader, $parser, $verifier, $($part_2)?),
+            };
+            pipeline.map(|pipeline|pipeline.with_parameters(PARAMETERS, $crate::parameters::Parameters::new()))
+        }
+
+        fn register(manager: $crate::executer_manager::ExecuterManager) -> Result<$crate::executer_manager::ExecuterManager, String> {
+            manager.try_register_parameterised_day(DAY.year, $number, DAY.has_part_2, PARAMETERS, make_pipeline, false)
+        }
+    };
+    (@has_part_2 $part_2:expr) => { true };
+    (@has_part_2) => { false };
+    (@part_2 $number:literal, $reader:expr, $parser:expr, $verifier:expr, $part_2:expr) => {
+        try_make_pipeline($reader, $parser, $verifier, $part_2)
+    };
+    (@part_2 $number:literal, $reader:expr, $parser:expr, $verifier:expr, ) => {
+        Err(format!("[Day {:02}] part 2 is not implemented", $number))
+    };
+}
+
+pub(crate) use define_day;
@@ -1,9 +1,10 @@
 
-use crate::{answer::DisplayableAnswer, solver::Solve};
+use crate::{answer::DisplayableAnswer, pipeline_error::PipelineError, solver::Solve};
 
 use super::models::{NumberPair, NumberPairList};
 
 
+#[derive(Default)]
 pub struct DistanceApartCalculator;
 
 mod error {
@@ -32,10 +33,11 @@ impl DistanceApartCalculator {
 }
 
 impl Solve<NumberPairList> for DistanceApartCalculator {
-    fn solve(&self, input: NumberPairList) -> Result<crate::answer::Answer, String> {
+    fn solve(&self, input: NumberPairList) -> Result<crate::answer::Answer, PipelineError> {
         Self::extract_sort(&input, NumberPair::fst).into_iter()
             .zip(Self::extract_sort(&input, NumberPair::snd).into_iter())
             .try_fold(0u32, Self::add_pair_distance)
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
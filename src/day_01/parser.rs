@@ -1,6 +1,4 @@
-
-
-use crate::{helper::{re::get_captures, result}, parser::Parse, reader::{Line, VecLine}};
+use crate::{helper::result, parser::Parse, pipeline_error::PipelineError, reader::{Line, VecLine}};
 
 use super::models::{NumberPair, NumberPairList};
 
@@ -16,14 +14,13 @@ mod error {
         format!("{} could not compile line regex {}", PREFIX, e)
     }
 
-    pub fn parse_num_error(number_str: &String, is_first_num: bool, e: std::num::ParseIntError, line_num: usize) -> String {
+    pub fn parse_num_error(number_str: &str, is_first_num: bool, e: std::num::ParseIntError) -> String {
         let name = if is_first_num { "1st number" } else { "2nd number" };
-        format!("{} could not parse {} '{}' on line #{} because parsing error occurred '{}'",
-            PREFIX, name, number_str, line_num, e)
+        format!("{} could not parse {} '{}' because parsing error occurred '{}'", PREFIX, name, number_str, e)
     }
 
-    pub fn parse_line_error(line_num: usize) -> String {
-        format!("{} line #{} failed to match line-regex", PREFIX, line_num)
+    pub fn parse_line_error() -> String {
+        format!("{} line failed to match line-regex", PREFIX)
     }
 }
 
@@ -34,32 +31,26 @@ impl NumberPairListParser {
             .map(|re|NumberPairListParser{re})
     }
 
-    fn parse_num(number_str: &String, is_first_num: bool, line_num: usize) -> Result<u32, String> {
-        number_str.parse::<u32>()
-            .map_err(|e|error::parse_num_error(number_str, is_first_num, e, line_num))
+    fn parse_num(line: &Line, number: regex::Match, is_first_num: bool) -> Result<u32, PipelineError> {
+        number.as_str().parse::<u32>()
+            .map_err(|e|PipelineError::at(line, line.column_at(number.start()), error::parse_num_error(number.as_str(), is_first_num, e)))
     }
 
-    fn parse_number_pair(fst: &String, snd: &String, line_num: usize) -> Result<NumberPair, String> {
-        let fst = Self::parse_num(fst, true, line_num);
-        let snd = Self::parse_num(snd, false, line_num);
-        result::zip(fst, snd, NumberPair)
-    }
-    
-    fn parse_single_line(&self, line: &Line) -> Result<NumberPair, String>
+    fn parse_single_line(&self, line: &Line) -> Result<NumberPair, PipelineError>
     {
-        match get_captures(&self.re, &line.textf()) {
-            Some(v) if v.len() == 3 => Self::parse_number_pair(&v[1], &v[2], line.numberf()),
-            _ => Err(error::parse_line_error(line.numberf())),
+        match self.re.captures(&line.text).and_then(|c|c.get(1).zip(c.get(2))) {
+            Some((fst, snd)) => result::zip(Self::parse_num(line, fst, true), Self::parse_num(line, snd, false), NumberPair),
+            None => Err(PipelineError::on_line(line, error::parse_line_error())),
         }
     }
 }
 
 
 impl Parse<NumberPairList> for NumberPairListParser {
-    fn parse(&self, vec_line: VecLine) -> Result<NumberPairList, String> {
+    fn parse(&self, vec_line: VecLine) -> Result<NumberPairList, PipelineError> {
         let vector_of_results = vec_line.lines.iter()
             .map(|line|self.parse_single_line(line))
             .collect();
         result::collect(vector_of_results).map(NumberPairList::new)
     }
-}
\ No newline at end of file
+}
@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 
-use crate::{answer::DisplayableAnswer, solver::Solve};
+use crate::{answer::DisplayableAnswer, pipeline_error::PipelineError, solver::Solve};
 
 use super::models::{NumberPair, NumberPairList};
 
+#[derive(Default)]
 pub struct SimilarityScoreCalculator;
 
 mod error {
@@ -37,12 +38,13 @@ impl SimilarityScoreCalculator {
 }
 
 impl Solve<NumberPairList> for SimilarityScoreCalculator {
-    fn solve(&self, input: NumberPairList) -> Result<crate::answer::Answer, String> {
+    fn solve(&self, input: NumberPairList) -> Result<crate::answer::Answer, PipelineError> {
         let fmap = Self::create_frequency_map(&input, NumberPair::snd);
         input.list.into_iter()
             .map(|np|np.fst())
             .map(|number| (number,fmap.get(&number).map_or(0, |v|*v)))
             .try_fold(0u32,Self::safe_multiply_add)
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
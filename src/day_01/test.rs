@@ -32,6 +32,14 @@ pub mod suite {
         assert!(parsing_err.contains("4294967296")) // should report this number as failed to parse in u32
     }
 
+    #[test]
+    pub fn test_parser_error_position() {
+        let error = testing::get_parsed_text_result(&make_pipeline(false).unwrap(), "10 15\n0 4294967296").unwrap_err();
+        assert_eq!((error.line_number(), error.column), (Some(2), Some(3)));
+        let error = testing::get_parsed_text_result(&make_pipeline(false).unwrap(), "10 15\n10,15").unwrap_err();
+        assert_eq!((error.line_number(), error.column), (Some(2), None));
+    }
+
     #[test]
     pub fn test_whole_flow_pt1_example() {
         testing::test_whole_flow(
@@ -3,12 +3,12 @@ use crate::day_02::level_report_analyser::LevelReportAnalyser;
 use super::{level_report_counter::LevelReportCounter, models::LevelReport};
 
 pub struct DampenedSafeLevelReportCounter {
-    safety_fn: Box<dyn Fn(u32, u32) -> bool>
+    safety_fn: Box<dyn Fn(u32, u32) -> bool + Send + Sync>
 }
 
 impl DampenedSafeLevelReportCounter {
     pub fn new<SF>(safety_fn: SF) -> DampenedSafeLevelReportCounter
-    where SF : Fn(u32, u32) -> bool + 'static {
+    where SF : Fn(u32, u32) -> bool + Send + Sync + 'static {
         DampenedSafeLevelReportCounter { safety_fn: Box::new(safety_fn) }
     }
 
@@ -1,13 +1,13 @@
-use crate::{answer::{Answer, DisplayableAnswer}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, pipeline_error::PipelineError, solver::Solve};
 
 use super::models::{LevelReport, LevelReports};
 
-pub trait LevelReportCounter {
+pub trait LevelReportCounter: Send + Sync {
     fn predicate(&self, report: &LevelReport) -> bool;
 }
 
 impl<LRC: LevelReportCounter> Solve<LevelReports> for LRC {
-    fn solve(&self, input: LevelReports) -> Result<Answer, String> {
+    fn solve(&self, input: LevelReports) -> Result<Answer, PipelineError> {
         let count = input.reports.into_iter().filter(|report|self.predicate(report)).count();
         Ok(DisplayableAnswer::new(count))
     }
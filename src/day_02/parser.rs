@@ -1,10 +1,11 @@
-use crate::{helper::result, parser::Parse, reader::Line};
+use crate::{helper::result, parser::Parse, pipeline_error::PipelineError, reader::Line};
 
 use super::models::{LevelReport, LevelReports};
 
 
 pub struct LevelReportsParser {
-    line_re: regex::Regex
+    line_re: regex::Regex,
+    number_re: regex::Regex,
 }
 
 mod error {
@@ -16,35 +17,33 @@ mod error {
         format!("{} could not compile line regex {}", PREFIX, e)
     }
 
-    pub fn parse_line_error(line_num: usize) -> String {
-        format!("{} line #{} does not match the regex", PREFIX, line_num)
+    pub fn parse_line_error() -> String {
+        format!("{} line does not match the regex", PREFIX)
     }
 
-    pub fn parse_number_error(num: &str, line_num: usize, e: ParseIntError) -> String {
-        format!("{} failed to parse number '{}' on line #{} because of parsing error '{}'", PREFIX, num, line_num, e)
+    pub fn parse_number_error(num: &str, e: ParseIntError) -> String {
+        format!("{} failed to parse number '{}' because of parsing error '{}'", PREFIX, num, e)
     }
 }
 
 impl LevelReportsParser {
     pub fn new() -> Result<LevelReportsParser, String> {
-        regex::Regex::new(r"^(?:\d+ *)+$")
-            .map_err(error::regex_error)
-            .map(|line_re|LevelReportsParser{line_re})
+        let line_re = regex::Regex::new(r"^(?:\d+ *)+$").map_err(error::regex_error);
+        let number_re = regex::Regex::new(r"\d+").map_err(error::regex_error);
+        result::zip(line_re, number_re, |line_re, number_re|LevelReportsParser{line_re, number_re})
     }
 
-    fn parse_num(num: &str, line_num: usize) -> Result<u32, String> {
-        num.parse::<u32>()
-            .map_err(|e|error::parse_number_error(num, line_num, e))
+    fn parse_num(line: &Line, num: regex::Match) -> Result<u32, PipelineError> {
+        num.as_str().parse::<u32>()
+            .map_err(|e|PipelineError::at(line, line.column_at(num.start()), error::parse_number_error(num.as_str(), e)))
     }
 
-    fn parse_line(&self, line: Line) -> Result<LevelReport, String> {
+    fn parse_line(&self, line: Line) -> Result<LevelReport, PipelineError> {
         if !self.line_re.is_match(&line.textf()) {
-            Err(error::parse_line_error(line.numberf()))
+            Err(PipelineError::on_line(&line, error::parse_line_error()))
         } else {
-            let parsed_numbers = line.textf()
-                .split(" ")
-                .filter(|word| word.len() > 0)
-                .map(|word|Self::parse_num(word, line.numberf()))
+            let parsed_numbers = self.number_re.find_iter(&line.text)
+                .map(|number|Self::parse_num(&line, number))
                 .collect();
 
             result::collect(parsed_numbers)
@@ -54,7 +53,7 @@ impl LevelReportsParser {
 }
 
 impl Parse<LevelReports> for LevelReportsParser {
-    fn parse(&self, vec_line: crate::reader::VecLine) -> Result<LevelReports, String> {
+    fn parse(&self, vec_line: crate::reader::VecLine) -> Result<LevelReports, PipelineError> {
         result::collect(vec_line.lines.into_iter().map(|line|self.parse_line(line)).collect())
             .map(LevelReports::new)
     }
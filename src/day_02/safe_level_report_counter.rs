@@ -4,12 +4,12 @@ use super::{level_report_counter::LevelReportCounter, models::LevelReport};
 
 
 pub struct SafeLevelReportCounter {
-    safety_fn: Box<dyn Fn(u32, u32) -> bool>
+    safety_fn: Box<dyn Fn(u32, u32) -> bool + Send + Sync>
 }
 
 impl SafeLevelReportCounter {
     pub fn new<SF>(safety_fn: SF) -> SafeLevelReportCounter
-    where SF : Fn(u32, u32) -> bool + 'static {
+    where SF : Fn(u32, u32) -> bool + Send + Sync + 'static {
         SafeLevelReportCounter { safety_fn: Box::new(safety_fn) }
     }
 }
@@ -1,4 +1,4 @@
-use crate::{answer::{Answer, DisplayableAnswer}, helper::result::{collect, zip}, reader::{Line, VecLine}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::result::{collect, zip}, pipeline_error::PipelineError, reader::{Line, VecLine}, solver::Solve};
 
 use super::instruction::Instruction;
 
@@ -14,14 +14,13 @@ mod error {
         format!("{} regex creation failed because '{}'", PREFIX, e)
     }
 
-    pub fn pattern_mismatch_error(whole_match: &str, line_num: usize) -> String {
-        format!("{} fatal pattern mismatch happened when trying to resolve mul at line {} from '{}'",
-            PREFIX, line_num, whole_match)
+    pub fn pattern_mismatch_error(whole_match: &str) -> String {
+        format!("{} fatal pattern mismatch happened when trying to resolve mul from '{}'", PREFIX, whole_match)
     }
     
-    pub fn number_parse_error(e: std::num::ParseIntError, number: &str, whole_match: &str, line_num: usize) -> String {
-        format!("{} failed to parse number '{}' from mul expression '{}' on line {} because of parsing error '{}'",
-            PREFIX, number, whole_match, line_num, e)
+    pub fn number_parse_error(e: std::num::ParseIntError, number: &str, whole_match: &str) -> String {
+        format!("{} failed to parse number '{}' from mul expression '{}' because of parsing error '{}'",
+            PREFIX, number, whole_match, e)
     }
 
     pub fn overflow_error(acc: u32, x: u32, y: u32) -> String {
@@ -36,26 +35,27 @@ impl MulExtractor {
             .map_err(error::regex_error)
     }
 
-    fn resolve_num(number: &str, whole_match: &str, line_num: usize) -> Result<u32, String> {
-        number.parse::<u32>().map_err(|e|error::number_parse_error(e, number, whole_match, line_num))
+    fn resolve_num(line: &Line, number: regex::Match, whole_match: &str) -> Result<u32, PipelineError> {
+        number.as_str().parse::<u32>()
+            .map_err(|e|PipelineError::at(line, line.column_at(number.start()), error::number_parse_error(e, number.as_str(), whole_match)))
     }
 
-    fn resolve_instruction(captures: regex::Captures, line_num: usize) -> Result<Instruction, String> {
+    fn resolve_instruction(line: &Line, captures: regex::Captures) -> Result<Instruction, PipelineError> {
         let whole_match = &captures[0];
-        match whole_match {
-            "do()" => Ok(Instruction::Do),
-            "don't()" => Ok(Instruction::DoNot),
-            mul if mul.starts_with("mul") && captures.get(1).is_some() && captures.get(2).is_some() => {
-                let resolve = |number|Self::resolve_num(number, whole_match, line_num);
-                zip(resolve(&captures[1]), resolve(&captures[2]), Instruction::Mul)
+        match (whole_match, captures.get(1).zip(captures.get(2))) {
+            ("do()", _) => Ok(Instruction::Do),
+            ("don't()", _) => Ok(Instruction::DoNot),
+            (mul, Some((x, y))) if mul.starts_with("mul") => {
+                let resolve = |number|Self::resolve_num(line, number, whole_match);
+                zip(resolve(x), resolve(y), Instruction::Mul)
             }
-            _ => Err(error::pattern_mismatch_error(whole_match, line_num))
+            _ => Err(PipelineError::at(line, line.column_at(captures.get(0).map_or(0, |m|m.start())), error::pattern_mismatch_error(whole_match)))
         }
     }
 
-    fn extract_instructions(&self, line: &Line) -> Vec<Result<Instruction, String>> {
-        self.mul_re.captures_iter(&line.textf())
-            .map(|captures|Self::resolve_instruction(captures, line.numberf()))
+    fn extract_instructions(&self, line: &Line) -> Vec<Result<Instruction, PipelineError>> {
+        self.mul_re.captures_iter(&line.text)
+            .map(|captures|Self::resolve_instruction(line, captures))
             .collect()
     }
 
@@ -77,10 +77,11 @@ impl MulExtractor {
 }
 
 impl Solve<VecLine> for MulExtractor {
-    fn solve(&self, input: VecLine) -> Result<Answer, String> {
+    fn solve(&self, input: VecLine) -> Result<Answer, PipelineError> {
         collect(input.lines.iter().flat_map(|line|self.extract_instructions(line)).collect())
             ?.into_iter()
             .try_fold((0u32, false), |acc,inst|self.process_instruction(acc, inst))
             .map(|(acc,_)|DisplayableAnswer::new(acc))
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
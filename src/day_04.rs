@@ -3,13 +3,13 @@ use self::xmas_finder::XMasFinder;
 use self::word_search_parser::WordSearchParser;
 use self::word_searcher::WordSearcher;
 
-use crate::{executer_manager::ExecuterManager, helper::table::Table, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, solver::Solve, verifier::TrivialVerifier, SanitisedFileReader, SimpleFileReader};
+use crate::{day::define_day, helper::table::Table, verifier::TrivialVerifier, SanitisedFileReader, SimpleFileReader};
 
-mod word_search_parser;
-mod word_searcher;
-mod xmas_finder;
-mod cross_mas_finder;
-mod find;
+pub mod word_search_parser;
+pub mod word_searcher;
+pub mod xmas_finder;
+pub mod cross_mas_finder;
+pub mod find;
 mod test;
 
 fn reader() -> SanitisedFileReader {
@@ -23,23 +23,13 @@ fn reader() -> SanitisedFileReader {
         EmptyLineTrimming::Both)
 }
 
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<Table<char>>, String>
-where S: Solve<Table<char>> + 'static {
-    try_make_pipeline(
-        Ok(reader()),
-        WordSearchParser::new(), 
-        Ok(TrivialVerifier::new::<Table<char>>()),
-        Ok(solver))
+define_day! {
+    number: 4,
+    title: "Ceres Search",
+    input: Table<char>,
+    reader: Ok(reader()),
+    parser: WordSearchParser::new(),
+    verifier: Ok(TrivialVerifier::new::<Table<char>>()),
+    part_1: Ok(WordSearcher::new(XMasFinder::new)),
+    part_2: Ok(WordSearcher::new(CrossMasFinder::new)),
 }
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<Table<char>>, String> {
-    match is_part_2 {
-        false => make_pipeline_with(WordSearcher::new(XMasFinder::new)),
-        true => make_pipeline_with(WordSearcher::new(CrossMasFinder::new)),
-    }
-}
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(4, false, make_pipeline(false), false)
-        ?.try_register(4, true, make_pipeline(true), false)
-}
\ No newline at end of file
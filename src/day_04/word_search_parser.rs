@@ -1,4 +1,4 @@
-use crate::{helper::table::Table, parser::Parse, reader::VecLine};
+use crate::{helper::table::Table, parser::Parse, pipeline_error::PipelineError, reader::VecLine};
 
 
 mod error {
@@ -8,8 +8,8 @@ mod error {
         format!("{} error when creating line regex '{}'", PREFIX, e)
     }
 
-    pub fn match_line_error(line_num: usize) -> String {
-        format!("{} line #{} does not match expected pattern", PREFIX, line_num)
+    pub fn match_line_error(c: char) -> String {
+        format!("{} line does not match expected pattern, character '{}' is not one of 'XMAS'", PREFIX, c)
     }
 }
 
@@ -26,14 +26,15 @@ impl WordSearchParser {
 }
 
 impl Parse<Table<char>> for WordSearchParser {
-    fn parse(&self, vec_line: VecLine) -> Result<Table<char>, String> {
+    fn parse(&self, vec_line: VecLine) -> Result<Table<char>, PipelineError> {
         let mut rows = vec![];
         for line in vec_line.lines {
             if !self.line_re.is_match(&line.text) {
-                return Err(error::match_line_error(line.number))
+                let (offset, c) = line.text.char_indices().find(|(_, c)|!"XMAS".contains(*c)).unwrap_or_default();
+                return Err(PipelineError::at(&line, line.column_at(offset), error::match_line_error(c)))
             }
             rows.push(line.text.chars().collect());
         }
-        Table::new(rows)
+        Table::new(rows).map_err(PipelineError::from)
     }
 }
\ No newline at end of file
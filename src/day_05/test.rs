@@ -34,7 +34,7 @@ pub mod suite {
         let pipeline = make_pipeline(true).unwrap();
         let res = pipeline.execute(REL_FILEPATHS[1]);
         assert!(res.is_err());
-        let err = res.err().unwrap();
+        let err = res.err().unwrap().to_string();
         assert!(err.contains("[1,2,3,4,5]"))
     }
 
@@ -49,7 +49,7 @@ pub mod suite {
         let pipeline = make_pipeline(true).unwrap();
         let res = pipeline.execute(REL_FILEPATHS[2]);
         assert!(res.is_err());
-        let err = res.err().unwrap();
+        let err = res.err().unwrap().to_string();
         assert!(err.contains("[3,2,1]"))
     }
 
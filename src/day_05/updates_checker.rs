@@ -1,10 +1,11 @@
 use std::collections::HashSet;
 
-use crate::{answer::DisplayableAnswer, solver::Solve};
+use crate::{answer::DisplayableAnswer, pipeline_error::PipelineError, solver::Solve};
 
 use super::models::{PageOrderingRule, RulesWithUpdates, UpdatePages};
 
 
+#[derive(Default)]
 pub struct UpdatesChecker;
 
 mod error {
@@ -67,11 +68,12 @@ impl UpdatesChecker {
 }
 
 impl Solve<RulesWithUpdates> for UpdatesChecker {
-    fn solve(&self, input: RulesWithUpdates) -> Result<crate::answer::Answer, String> {
+    fn solve(&self, input: RulesWithUpdates) -> Result<crate::answer::Answer, PipelineError> {
         Self::get_updates(&input, true)
             .into_iter()
             .map(Self::middle)
             .try_fold(0u32, Self::safe_add)
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::{answer::Answer, day_05::{models::{PageOrderingRule, UpdatePages}, updates_checker::UpdatesChecker}, helper::result::collect, solver::Solve};
+use crate::{answer::Answer, day_05::{models::{PageOrderingRule, UpdatePages}, updates_checker::UpdatesChecker}, helper::result::collect, pipeline_error::PipelineError, solver::Solve};
 
 use super::models::RulesWithUpdates;
 
@@ -30,6 +30,7 @@ mod error {
     } 
 }
 
+#[derive(Default)]
 pub struct UpdatesCorrector;
 
 
@@ -121,10 +122,11 @@ impl UpdatesCorrector {
 }
 
 impl Solve<RulesWithUpdates> for UpdatesCorrector {
-    fn solve(&self, input: RulesWithUpdates) -> Result<Answer, String> {
+    fn solve(&self, input: RulesWithUpdates) -> Result<Answer, PipelineError> {
         collect(UpdatesChecker::get_updates(&input, false).iter()
             .map(|&update|Self::determine_order(&input.rules, update)).collect())
             .map(|updates|RulesWithUpdates { updates, ..input })
+            .map_err(PipelineError::from)
             .and_then(|corrected|UpdatesChecker::new().solve(corrected))
     }
 }
\ No newline at end of file
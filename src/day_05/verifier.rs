@@ -1,8 +1,9 @@
 use std::collections::HashSet;
 
-use crate::{day_05::models::UpdatePages, verifier::Verify};
+use crate::{day_05::models::UpdatePages, pipeline_error::PipelineError, verifier::Verify};
 use super::models::RulesWithUpdates;
 
+#[derive(Default)]
 pub struct RulesWithUpdatesVerifier;
 
 mod error {
@@ -45,9 +46,10 @@ impl RulesWithUpdatesVerifier {
 }
 
 impl Verify<RulesWithUpdates> for RulesWithUpdatesVerifier {
-    fn verify(&self, input: RulesWithUpdates) -> Result<RulesWithUpdates, String> {
+    fn verify(&self, input: RulesWithUpdates) -> Result<RulesWithUpdates, PipelineError> {
         Ok(input)
             .and_then(Self::verify_no_duplicates)
             .and_then(Self::verify_updates_contain_odd_number_of_pages)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
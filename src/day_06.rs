@@ -1,21 +1,21 @@
 use self::models::LaboratoryMapField;
-use self::parser::LaboratoryMapParser;
+use self::parser::laboratory_map_parser;
 use self::distinct_visiting_positions_counter::DistinctVisitingPositionsCounter;
 use self::loop_candidate_counter::LoopCandidateCounter;
 
-use crate::{executer_manager::ExecuterManager, helper::table::Table, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, settings::{EmptyLineTrimming, InputEndComment, LineComment, LineTrim}, solver::Solve, verifier::TrivialVerifier, SanitisedFileReader, SimpleFileReader};
+use crate::{day::define_day, helper::table::Table, settings::{EmptyLineTrimming, InputEndComment, LineComment, LineTrim}, verifier::TrivialVerifier, SanitisedFileReader, SimpleFileReader};
 
-mod parser;
-mod models;
-mod distinct_visiting_positions_counter;
-mod loop_candidate_counter;
+pub mod parser;
+pub mod models;
+pub mod distinct_visiting_positions_counter;
+pub mod loop_candidate_counter;
 mod test;
-mod map_analyser;
-mod guard_state;
-mod loop_detector;
-mod next_state;
-mod optimised_caching_loop_detector;
-mod adjusted_loop_detector;
+pub mod map_analyser;
+pub mod guard_state;
+pub mod loop_detector;
+pub mod next_state;
+pub mod optimised_caching_loop_detector;
+pub mod adjusted_loop_detector;
 
 fn reader() -> SanitisedFileReader {
     SanitisedFileReader::new(
@@ -27,20 +27,13 @@ fn reader() -> SanitisedFileReader {
     )
 }
 
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<Table<LaboratoryMapField>>, String>
-where S: Solve<Table<LaboratoryMapField>> + 'static {
-    try_make_pipeline(Ok(reader()), LaboratoryMapParser::new(), Ok(TrivialVerifier::new::<Table<LaboratoryMapField>>()), Ok(solver))
+define_day! {
+    number: 6,
+    title: "Guard Gallivant",
+    input: Table<LaboratoryMapField>,
+    reader: Ok(reader()),
+    parser: Ok(laboratory_map_parser()),
+    verifier: Ok(TrivialVerifier::new::<Table<LaboratoryMapField>>()),
+    part_1: Ok(DistinctVisitingPositionsCounter),
+    part_2: Ok(LoopCandidateCounter),
 }
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<Table<LaboratoryMapField>>, String> {
-    match is_part_2 {
-        false => make_pipeline_with(DistinctVisitingPositionsCounter),
-        true  => make_pipeline_with(LoopCandidateCounter)
-    }
-}
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager
-        .try_register(6, false, make_pipeline(false), false)
-        ?.try_register(6, true, make_pipeline(true), false)
-}
\ No newline at end of file
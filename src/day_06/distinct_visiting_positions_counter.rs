@@ -1,13 +1,13 @@
 use std::collections::HashSet;
 
-use crate::{answer::{Answer, DisplayableAnswer}, helper::table::Table, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::table::Table, pipeline_error::PipelineError, solver::Solve};
 
 use super::{map_analyser::MapAnalyser, models::LaboratoryMapField};
 
 pub struct DistinctVisitingPositionsCounter;
 
 impl Solve<Table<LaboratoryMapField>> for DistinctVisitingPositionsCounter {
-    fn solve(&self, input: Table<LaboratoryMapField>) -> Result<Answer, String> {
+    fn solve(&self, input: Table<LaboratoryMapField>) -> Result<Answer, PipelineError> {
         MapAnalyser::new(input)
             .and_then(|mut analyser|analyser.perform_analysis())
             .and_then(|path|match path.is_empty() {
@@ -17,6 +17,6 @@ impl Solve<Table<LaboratoryMapField>> for DistinctVisitingPositionsCounter {
                     for state in path { distinct_positions.insert(state.position); }
                     Ok(distinct_positions.len())
                 },
-            }).map(DisplayableAnswer::new)
+            }).map(DisplayableAnswer::new).map_err(PipelineError::from)
     }
 }
\ No newline at end of file
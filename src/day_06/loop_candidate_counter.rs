@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::{answer::{Answer, DisplayableAnswer}, day_06::map_analyser::MapAnalyser, helper::table::Table, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, day_06::map_analyser::MapAnalyser, helper::table::Table, pipeline_error::PipelineError, solver::Solve};
 
 use super::{adjusted_loop_detector::AdjustedLoopDetector, guard_state::GuardState, loop_detector::{loops, LoopDetector}, models::LaboratoryMapField, optimised_caching_loop_detector::OptimisedCachingLoopDetector};
 
@@ -34,10 +34,11 @@ impl LoopCandidateCounter {
 }
 
 impl Solve<Table<LaboratoryMapField>> for LoopCandidateCounter {
-    fn solve(&self, input: Table<LaboratoryMapField>) -> Result<Answer, String> {
+    fn solve(&self, input: Table<LaboratoryMapField>) -> Result<Answer, PipelineError> {
         MapAnalyser::new(input)
             .and_then(|mut analyser|analyser.perform_analysis()
             .and_then(|path|Self::calculate_loop_count(analyser, path)))
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 use crate::helper::{boundary::apply, direction::Direction, movement::Movement, position::UPosition, table::{Table, TableBound}};
 
@@ -8,8 +8,8 @@ pub struct MapAnalyser {
     guard_start_position: UPosition,
     boundary: TableBound,
 
-    /// A mapping from position -> whether it is free to step into
-    free_position_map: HashMap<UPosition, bool>, 
+    /// A table of whether each position is free to step into
+    free_position_map: Table<bool>,
 }
 
 mod error {
@@ -28,15 +28,11 @@ mod error {
 
 impl MapAnalyser {
     pub fn new(input: Table<LaboratoryMapField>) -> Result<MapAnalyser, String> {
-        let mut guard_positions = vec![];
-        let mut free_position_map = HashMap::new();
-        
-        for (pos, &field) in input.iter() {
-            free_position_map.insert(pos, field != LaboratoryMapField::Block);
-            if field == LaboratoryMapField::Guard {
-                guard_positions.push(pos);
-            }
-        }
+        let guard_positions = input.iter()
+            .filter(|&(_, &field)|field == LaboratoryMapField::Guard)
+            .map(|(pos, _)|pos)
+            .collect::<Vec<_>>();
+        let free_position_map = input.map(|&field|field != LaboratoryMapField::Block);
         
         let boundary = input.boundary();
         match guard_positions.len() {
@@ -75,7 +71,7 @@ impl LoopDetector for MapAnalyser {
     fn next_state(&mut self, current_state: GuardState) -> Result<NextState, String> {
         // Do a check on the current state position
         match apply(self.boundary, Movement::zero(), current_state.position) {
-            Some(_) if !self.free_position_map[&current_state.position]
+            Some(_) if !self.free_position_map.get_pos(current_state.position).unwrap()
                 => return Err(error::guard_on_blocked_position_error(current_state.position)),
             None
                 => return Ok(NextState::Out), // position is already out
@@ -85,7 +81,7 @@ impl LoopDetector for MapAnalyser {
 
         // work out next guard state
         let next_guard_state = apply(self.boundary, current_state.movement(), current_state.position)
-            .map(|position|match self.free_position_map[&position] {
+            .map(|position|match self.free_position_map.get_pos(position).unwrap() {
                 true  => GuardState::new(position, current_state.direction),
                 false => current_state.rotate(),
             }).map_or(NextState::Out, NextState::Next);
@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use crate::helper::grid::CharMapping;
+
 #[derive(Clone, Debug, Eq, PartialEq, Copy)]
 pub enum LaboratoryMapField {
     Block,
@@ -7,12 +9,14 @@ pub enum LaboratoryMapField {
     Guard,
 }
 
+pub const FIELD_MAPPING: CharMapping<LaboratoryMapField> = CharMapping::new(&[
+    ('#', LaboratoryMapField::Block),
+    ('.', LaboratoryMapField::Free),
+    ('^', LaboratoryMapField::Guard),
+]);
+
 impl Display for LaboratoryMapField {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", match self {
-            LaboratoryMapField::Block => '#',
-            LaboratoryMapField::Free => '.',
-            LaboratoryMapField::Guard => '^',
-        })
+        FIELD_MAPPING.fmt(*self, f)
     }
 }
\ No newline at end of file
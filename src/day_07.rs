@@ -1,34 +1,29 @@
 use self::operation::{Operation, Addition, Multiplication, Concatenation};
 use self::calibration_results_checker::CalibrationResultsChecker;
 use self::equation::EquationList;
-use self::parser::EquationListParser;
+use self::parser::equation_list_parser;
 
-use crate::{executer_manager::ExecuterManager, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, solver::Solve, verifier::TrivialVerifier, SanitisedFileReader};
+use crate::{day::define_day, verifier::TrivialVerifier, SanitisedFileReader};
 
-mod equation;
-mod parser;
-mod operation;
+pub mod equation;
+pub mod parser;
+pub mod operation;
 mod test;
-mod calibration_results_checker;
+pub mod calibration_results_checker;
 
-
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<EquationList>, String>
-where S: Solve<EquationList> + 'static {
-    try_make_pipeline(
-        Ok(SanitisedFileReader::default()),
-        EquationListParser::new(),
-        Ok(TrivialVerifier::new::<EquationList>()),
-        Ok(solver))
-}
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<EquationList>, String> {
+fn operations(with_concatenation: bool) -> Vec<Box<dyn Operation>> {
     let mut ops : Vec<Box<dyn Operation>> = vec![Box::new(Addition), Box::new(Multiplication)];
-    if is_part_2 { ops.push(Box::new(Concatenation)); }
-    make_pipeline_with(CalibrationResultsChecker::new(ops))
+    if with_concatenation { ops.push(Box::new(Concatenation)); }
+    ops
 }
 
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager
-        .try_register(7, false, make_pipeline(false), false)
-        ?.try_register(7, true, make_pipeline(true), false)
-}
\ No newline at end of file
+define_day! {
+    number: 7,
+    title: "Bridge Repair",
+    input: EquationList,
+    reader: Ok(SanitisedFileReader::default()),
+    parser: Ok(equation_list_parser()),
+    verifier: Ok(TrivialVerifier::new::<EquationList>()),
+    part_1: Ok(CalibrationResultsChecker::new(operations(false))),
+    part_2: Ok(CalibrationResultsChecker::new(operations(true))),
+}
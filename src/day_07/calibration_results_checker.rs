@@ -1,4 +1,4 @@
-use crate::{answer::{Answer, DisplayableAnswer}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, pipeline_error::PipelineError, solver::Solve};
 
 use super::{equation::{Equation, EquationList}, operation::Operation};
 
@@ -46,12 +46,13 @@ impl CalibrationResultsChecker {
 }
 
 impl Solve<EquationList> for CalibrationResultsChecker {
-    fn solve(&self, input: EquationList) -> Result<Answer, String> {
+    fn solve(&self, input: EquationList) -> Result<Answer, PipelineError> {
         let EquationList(equations) = input;
         equations.into_iter()
             .filter(|equation|self.check(equation))
             .map(|equation|equation.left_value)
             .try_fold(0, Self::safe_sum)
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 
-pub trait Operation {
+pub trait Operation: Send + Sync {
     fn get_left_component(&self, result: u64, right_component: u64) -> Option<u64>;
 }
 
@@ -4,14 +4,14 @@ use self::resonant_harmonics_antinode_calculator::ResonantHarmonicsAntinodeCalcu
 use self::model::AntennaMapField;
 use self::parser::AntennaMapParser;
 
-use crate::{executer_manager::ExecuterManager, helper::table::Table, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, solver::Solve, verifier::TrivialVerifier, SanitisedFileReader, SimpleFileReader};
+use crate::{day::define_day, helper::table::Table, verifier::TrivialVerifier, SanitisedFileReader, SimpleFileReader};
 
-mod parser;
-mod antinode_calculator;
-mod simple_antinode_calculator;
-mod resonant_harmonics_antinode_calculator;
-mod model;
-mod antinode_counter;
+pub mod parser;
+pub mod antinode_calculator;
+pub mod simple_antinode_calculator;
+pub mod resonant_harmonics_antinode_calculator;
+pub mod model;
+pub mod antinode_counter;
 mod test;
 
 fn reader() -> SanitisedFileReader {
@@ -24,24 +24,13 @@ fn reader() -> SanitisedFileReader {
         EmptyLineTrimming::Both)
 }
 
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<Table<AntennaMapField>>, String>
-where S: Solve<Table<AntennaMapField>> + 'static {
-    try_make_pipeline(
-        Ok(reader()),
-        Ok(AntennaMapParser),
-        Ok(TrivialVerifier::new::<Table<AntennaMapField>>()),
-        Ok(solver),
-    )
+define_day! {
+    number: 8,
+    title: "Resonant Collinearity",
+    input: Table<AntennaMapField>,
+    reader: Ok(reader()),
+    parser: Ok(AntennaMapParser),
+    verifier: Ok(TrivialVerifier::new::<Table<AntennaMapField>>()),
+    part_1: Ok(AntinodeCounter::new(SimpleAntinodeCalculator::new)),
+    part_2: Ok(AntinodeCounter::new(ResonantHarmonicsAntinodeCalculator::new)),
 }
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<Table<AntennaMapField>>, String> {
-    match is_part_2 {
-        false => make_pipeline_with(AntinodeCounter::new(SimpleAntinodeCalculator::new)),
-        true  => make_pipeline_with(AntinodeCounter::new(ResonantHarmonicsAntinodeCalculator::new)),
-    }
-}
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(8, false, make_pipeline(false), false)
-        ?.try_register(8, true, make_pipeline(true), false)
-}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::{answer::{Answer, DisplayableAnswer}, helper::{position::UPosition, table::Table}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::{position::UPosition, table::Table}, pipeline_error::PipelineError, solver::Solve};
 
 use super::{antinode_calculator::AntinodeCalculator, model::AntennaMapField};
 
@@ -9,12 +9,12 @@ use super::{antinode_calculator::AntinodeCalculator, model::AntennaMapField};
 /// 
 /// It has a private member function that given a map of antennas produces an `AntinodeCalculator`.
 pub struct AntinodeCounter<AC> where AC: AntinodeCalculator + 'static {
-    antinode_calculator_fn: Box<dyn Fn(Table<AntennaMapField>) -> AC>,
+    antinode_calculator_fn: Box<dyn Fn(Table<AntennaMapField>) -> AC + Send + Sync>,
 }
 
 impl <AC> AntinodeCounter<AC> where AC: AntinodeCalculator + 'static {
     pub fn new<ACF>(antinode_calculator_fn: ACF) -> AntinodeCounter<AC>
-    where ACF: Fn(Table<AntennaMapField>) -> AC + 'static {
+    where ACF: Fn(Table<AntennaMapField>) -> AC + Send + Sync + 'static {
         AntinodeCounter { antinode_calculator_fn: Box::new(antinode_calculator_fn) }
     }
 
@@ -36,7 +36,7 @@ impl <AC> AntinodeCounter<AC> where AC: AntinodeCalculator + 'static {
 }
 
 impl <AC> Solve<Table<AntennaMapField>> for AntinodeCounter<AC> where AC: AntinodeCalculator + 'static {
-    fn solve(&self, input: Table<AntennaMapField>) -> Result<Answer, String> {
+    fn solve(&self, input: Table<AntennaMapField>) -> Result<Answer, PipelineError> {
 
         let frequency_map  = Self::make_frequency_map(&input);
         let antinode_calculator = (self.antinode_calculator_fn)(input);
@@ -1,7 +1,7 @@
 use super::memory_block::MemoryBlock;
 
 /// Memory compacting procedure
-pub trait Compact {
+pub trait Compact: Send + Sync {
     // Returns compacted memory of `mem`. 
     fn compact(&self, mem: Vec<MemoryBlock>) -> Vec<MemoryBlock>;
 }
\ No newline at end of file
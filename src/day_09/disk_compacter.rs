@@ -1,4 +1,4 @@
-use crate::{answer::{Answer, DisplayableAnswer}, helper::option::pair_merge, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::option::pair_merge, pipeline_error::PipelineError, solver::Solve};
 
 use super::{compact::Compact, memory_block::MemoryBlock, model::DiskMap};
 
@@ -76,11 +76,12 @@ impl DiskCompacter {
 }
 
 impl Solve<DiskMap> for DiskCompacter {
-    fn solve(&self, input: DiskMap) -> Result<Answer, String> {
+    fn solve(&self, input: DiskMap) -> Result<Answer, PipelineError> {
         self.compacter
             .compact(Self::as_memory_block_vector(input))
             .into_iter().try_fold(Accumulator{ value: 0, last_position: 0 }, Self::check_sum_step)
             .map(|acc|DisplayableAnswer::new(acc.value))
+            .map_err(PipelineError::from)
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::{helper::result::collect, parser::Parse, reader::VecLine};
+use crate::{helper::result::collect, parser::Parse, pipeline_error::PipelineError, reader::{Line, VecLine}};
 
 use super::model::DiskMap;
 
@@ -26,19 +26,20 @@ mod error {
 pub struct DiskMapParser;
 
 impl DiskMapParser {
-    fn parse_character(c: char) -> Result<usize, String> {
+    fn parse_character(line: &Line, offset: usize, c: char) -> Result<usize, PipelineError> {
         c.to_digit(10)
             .map(|digit|digit as usize)
-            .ok_or_else(||error::unsupported_character_error(c))
+            .ok_or_else(||PipelineError::at(line, line.column_at(offset), error::unsupported_character_error(c)))
     }
 }
 
 impl Parse<DiskMap> for DiskMapParser {
-    fn parse(&self, vec_line: VecLine) -> Result<DiskMap, String> {
+    fn parse(&self, vec_line: VecLine) -> Result<DiskMap, PipelineError> {
         if vec_line.lines.len() != 1 {
-            Err(error::input_shape_error(vec_line.lines.len()))
+            Err(error::input_shape_error(vec_line.lines.len()).into())
         } else {
-            collect(vec_line.lines[0].text.chars().map(Self::parse_character).collect()).map(DiskMap)
+            let line = &vec_line.lines[0];
+            collect(line.text.char_indices().map(|(offset, c)|Self::parse_character(line, offset, c)).collect()).map(DiskMap)
         }
     }
 }
\ No newline at end of file
@@ -1,15 +1,15 @@
 use self::trailhead_rating::TrailheadRating;
 use self::trailhead_score::TrailheadScore;
-use self::parser::TopographicMapParser;
+use self::parser::topographic_map_parser;
 use self::trailhead_review_analyser::TrailheadReviewAnalyser;
 
-use crate::{executer_manager::ExecuterManager, helper::table::Table, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, solver::Solve, verifier::TrivialVerifier, SanitisedFileReader, SimpleFileReader};
+use crate::{day::define_day, helper::table::Table, verifier::TrivialVerifier, SanitisedFileReader, SimpleFileReader};
 
-mod parser;
-mod trailhead_review_analyser;
-mod review;
-mod trailhead_score;
-mod trailhead_rating;
+pub mod parser;
+pub mod trailhead_review_analyser;
+pub mod review;
+pub mod trailhead_score;
+pub mod trailhead_rating;
 mod test;
 
 fn reader() -> SanitisedFileReader {
@@ -21,24 +21,13 @@ fn reader() -> SanitisedFileReader {
         crate::settings::EmptyLineTrimming::Both)
 }
 
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<Table<usize>>, String>
-where S: Solve<Table<usize>> + 'static {
-    try_make_pipeline(
-        Ok(reader()),
-        Ok(TopographicMapParser),
-        Ok(TrivialVerifier::new::<Table<usize>>()),
-        Ok(solver),
-    )
+define_day! {
+    number: 10,
+    title: "Hoof It",
+    input: Table<usize>,
+    reader: Ok(reader()),
+    parser: Ok(topographic_map_parser()),
+    verifier: Ok(TrivialVerifier::new::<Table<usize>>()),
+    part_1: Ok(TrailheadReviewAnalyser::new(TrailheadScore::new)),
+    part_2: Ok(TrailheadReviewAnalyser::new(TrailheadRating::new)),
 }
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<Table<usize>>, String> {
-    match is_part_2 {
-        false => make_pipeline_with(TrailheadReviewAnalyser::new(TrailheadScore::new)),
-        true  => make_pipeline_with(TrailheadReviewAnalyser::new(TrailheadRating::new)),
-    }
-}
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(10, false, make_pipeline(false), false)
-        ?.try_register(10, true, make_pipeline(true), false)
-}
\ No newline at end of file
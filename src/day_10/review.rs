@@ -2,8 +2,8 @@ use crate::helper::position::UPosition;
 
 /// Trailhead review abstraction
 pub trait Review {
-    /// Registers position for the purposes of creating review value
-    fn register(&mut self, position: UPosition);
+    /// Registers position, reached by the given number of distinct paths, for the purposes of creating review value
+    fn register(&mut self, position: UPosition, paths: usize);
 
     /// Provides review value based on position data provided
     fn review(&self) -> usize;
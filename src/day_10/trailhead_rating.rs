@@ -5,6 +5,7 @@ use super::review::Review;
 
 /// A trailhead rating is a number of different paths ending at position of height 9. 
 /// It is a glorified counter implementing `Review` interface.
+#[derive(Default)]
 pub struct TrailheadRating {
     counter: usize,
 }
@@ -14,9 +15,9 @@ impl TrailheadRating {
 }
 
 impl Review for TrailheadRating {
-    /// For every distinct path, just invoke the method (position supplied is not important)
-    fn register(&mut self, _: UPosition) {
-        self.counter += 1;
+    /// Counts the distinct paths ending at the position (position supplied is not important)
+    fn register(&mut self, _: UPosition, paths: usize) {
+        self.counter += paths;
     }
 
     fn review(&self) -> usize { self.counter }
@@ -1,14 +1,14 @@
 
-use crate::{answer::{Answer, DisplayableAnswer}, helper::{boundary::Boundary, movement, position::UPosition, table::Table}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::{position::UPosition, search::bfs, table::Table}, pipeline_error::PipelineError, solver::Solve};
 
 use super::review::Review;
 
 pub struct TrailheadReviewAnalyser<R> where R: Review {
-    review_provider: Box<dyn Fn() -> R>
+    review_provider: Box<dyn Fn() -> R + Send + Sync>
 }
 
 impl <R: Review> TrailheadReviewAnalyser<R> {
-    pub fn new<RF>(review_provider: RF) -> TrailheadReviewAnalyser<R> where RF: 'static + Fn() -> R {
+    pub fn new<RF>(review_provider: RF) -> TrailheadReviewAnalyser<R> where RF: 'static + Fn() -> R + Send + Sync {
         TrailheadReviewAnalyser { review_provider: Box::new(review_provider) }
     }
 
@@ -18,33 +18,24 @@ impl <R: Review> TrailheadReviewAnalyser<R> {
         // Review only when start position is of height 0
         if map.get_pos(start_position).is_none_or(|&height|height != 0) { return 0; }
         
+        // Walk all even, gradual, uphill slopes; every such path to a position is shortest, as heights rise by 1 per step
+        let uphill = |current: UPosition|{
+            let height = map.get_pos(current).map_or(0, |&height|height + 1);
+            map.neighbours(current).filter(move |&next|map.get_pos(next) == Some(&height))
+        };
+        let search = bfs([start_position], uphill);
+
+        // Register the ends of paths with height 9, along with the number of paths to them
         let mut reviewer = (self.review_provider)();
-
-        let mut positions = vec![start_position];
-        while !positions.is_empty() {
-            let current = positions.pop().unwrap();
-            let height = *map.get_pos(current).unwrap();
-
-            // End of path with height 9, register it and skip processing further    
-            if height == 9 {
-                reviewer.register(current);
-                continue;
-            }
-
-            // Analyse all 4 directions and see if the  it is an even, gradual, uphill slope
-            for movement in movement::unit::all_partial() {
-                let next = map.boundary().apply(movement, current);
-                if next.is_some_and(|p|*map.get_pos(p).unwrap() == height + 1) {
-                    positions.push(next.unwrap());
-                }
-            }
+        for &position in search.distances.keys().filter(|&&position|map.get_pos(position) == Some(&9)) {
+            reviewer.register(position, search.path_count(position) as usize);
         }
         reviewer.review()
     }
 }
 
 impl <R> Solve<Table<usize>> for TrailheadReviewAnalyser<R> where R: Review {
-    fn solve(&self, input: Table<usize>) -> Result<Answer, String> {
+    fn solve(&self, input: Table<usize>) -> Result<Answer, PipelineError> {
         Ok(DisplayableAnswer::new(input.iter().map(|(pos, _)|self.review(&input, pos)).sum::<usize>()))
     }
 }
\ No newline at end of file
@@ -5,6 +5,7 @@ use crate::helper::position::UPosition;
 use super::review::Review;
 
 /// A trailhead score is a number of different positions reachable that are of height 9. 
+#[derive(Default)]
 pub struct TrailheadScore {
     reachable: HashSet<UPosition>,
 }
@@ -15,7 +16,7 @@ impl TrailheadScore {
 
 impl Review for TrailheadScore {
     /// To calculate trailhead score, one needs to register all positions of height 9 reachable from the trailhead.
-    fn register(&mut self, position: UPosition) {
+    fn register(&mut self, position: UPosition, _: usize) {
         self.reachable.insert(position);
     }
 
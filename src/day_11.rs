@@ -1,34 +1,30 @@
 use self::stone_prediction_model::StonePredictionModel;
 use self::model::Stones;
-use self::parser::StonesParser;
+use self::parser::stones_parser;
 
-use crate::{executer_manager::ExecuterManager, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, solver::Solve, verifier::TrivialVerifier, SanitisedFileReader};
+use crate::{day::define_day, parameters::ParameterSpec, solver::ParameterisedSolver, verifier::TrivialVerifier, SanitisedFileReader};
 
-mod model;
-mod parser;
-mod stone_prediction_model;
+pub mod model;
+pub mod parser;
+pub mod stone_prediction_model;
 mod test;
 
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<Stones>, String>
-where S: Solve<Stones> + 'static {
-    try_make_pipeline(
-        Ok(SanitisedFileReader::default()),
-        StonesParser::new(),
-        Ok(TrivialVerifier::new::<Stones>()),
-        Ok(solver),
-    )
+/// The number of blinks can be declared in the input file as `//! blinks=6`, or given as parameter
+fn blinking(default_blinks: usize) -> ParameterisedSolver<Stones> {
+    ParameterisedSolver::new(move |parameters|Ok(StonePredictionModel::new(parameters.get_or("blinks", default_blinks)?)))
 }
 
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<Stones>, String> {
-    match is_part_2 {
-        false => make_pipeline_with(StonePredictionModel::new(25)),
-        true  => make_pipeline_with(StonePredictionModel::new(75)),
-    }
+define_day! {
+    number: 11,
+    title: "Plutonian Pebbles",
+    input: Stones,
+    reader: Ok(SanitisedFileReader::default()),
+    parser: Ok(stones_parser()),
+    verifier: Ok(TrivialVerifier::new::<Stones>()),
+    parameters: &[
+        ParameterSpec::unsigned_by_part("blinks", "Number of blinks, 25 in part 1 and 75 in part 2 by default")
+            .at_most(usize::MAX as u64),
+    ],
+    part_1: Ok(blinking(25)),
+    part_2: Ok(blinking(75)),
 }
-
-
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(11, false, make_pipeline(false), false)
-        ?.try_register(11, true, make_pipeline(true), false)
-}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::{answer::{Answer, DisplayableAnswer}, helper::result::zip, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::result::zip, pipeline_error::PipelineError, solver::Solve};
 
 use super::model::Stones;
 
@@ -84,16 +84,16 @@ impl StonePredictionModel {
 }
 
 impl Solve<Stones> for StonePredictionModel {
-    fn solve(&self, input: Stones) -> Result<Answer, String> {
+    fn solve(&self, input: Stones) -> Result<Answer, PipelineError> {
         let mut i = 0;
         let mut state = Self::make_start_state(input);
         while i < self.blinks {
             state = match state {
                 Ok(current_state) => Self::next(current_state),
-                Err(e) => return Err(e),
+                Err(e) => return Err(e.into()),
             };
             i += 1;
         }
-        state.and_then(Self::stone_count).map(DisplayableAnswer::new)
+        state.and_then(Self::stone_count).map(DisplayableAnswer::new).map_err(PipelineError::from)
     }
 }
\ No newline at end of file
@@ -6,6 +6,7 @@ pub mod suite {
 
     const REL_FILEPATHS: &[&str] = &[
         "src/day_11/test/example.txt", // Example given on AOC24
+        "src/day_11/test/example_6_blinks.txt", // Example given on AOC24, declaring 6 blinks
     ];
     
     #[test]
@@ -14,6 +15,12 @@ pub mod suite {
         test_whole_flow(&pipeline, REL_FILEPATHS[0], DisplayableAnswer::new(55_312));
     }
 
+    #[test]
+    pub fn test_whole_flow_with_declared_blinks() {
+        let pipeline = make_pipeline(false).unwrap();
+        test_whole_flow(&pipeline, REL_FILEPATHS[1], DisplayableAnswer::new(22));
+    }
+
     #[test]
     pub fn test_with_various_number_of_blinks() {
         let pipeline = make_pipeline(false).unwrap();
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::{answer::{Answer, DisplayableAnswer}, helper::{boundary::Boundary, direction, position::UPosition, table::Table}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::{boundary::Boundary, direction, position::UPosition, table::Table}, pipeline_error::PipelineError, solver::Solve};
 
 use super::{fence_unit::FenceUnit, perimiter_calculate::PerimiterCalculate};
 
@@ -84,7 +84,7 @@ impl FencePriceCalculator {
 }
 
 impl Solve<Table<char>> for FencePriceCalculator {
-    fn solve(&self, input: Table<char>) -> Result<Answer, String> {
-        self.price(input).map(DisplayableAnswer::new)
+    fn solve(&self, input: Table<char>) -> Result<Answer, PipelineError> {
+        self.price(input).map(DisplayableAnswer::new).map_err(PipelineError::from)
     }
 }
\ No newline at end of file
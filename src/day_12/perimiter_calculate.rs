@@ -3,6 +3,6 @@ use std::collections::HashSet;
 use super::fence_unit::FenceUnit;
 
 /// Calculates the perimiter price multiplier coefficient 
-pub trait PerimiterCalculate {
+pub trait PerimiterCalculate: Send + Sync {
     fn calculate(&self, fence: HashSet<FenceUnit>) -> u64;
 }
\ No newline at end of file
@@ -1,34 +1,27 @@
 use claw_machines_analyser::ClawMachineAnalyser;
 use model::ClawMachines;
-use parser::ClawMachinesParser;
+use parser::claw_machines_parser;
 
-use crate::{executer_manager::ExecuterManager, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, solver::Solve, verifier::TrivialVerifier, SanitisedFileReader};
+use crate::{day::define_day, parameters::ParameterSpec, solver::ParameterisedSolver, verifier::TrivialVerifier, SanitisedFileReader};
 
 
-mod model;
-mod parser;
-mod claw_machines_analyser;
-mod single_solution_solver;
+pub mod model;
+pub mod parser;
+pub mod claw_machines_analyser;
+pub mod single_solution_solver;
 mod test;
 
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<ClawMachines>, String>
-where S: Solve<ClawMachines> + 'static {
-    try_make_pipeline(
-        Ok(SanitisedFileReader::default()),
-        ClawMachinesParser::new(), 
-        Ok(TrivialVerifier::new::<ClawMachines>()),
-        Ok(solver))
+define_day! {
+    number: 13,
+    title: "Claw Contraption",
+    input: ClawMachines,
+    reader: Ok(SanitisedFileReader::default()),
+    parser: Ok(claw_machines_parser()),
+    verifier: Ok(TrivialVerifier::new::<ClawMachines>()),
+    parameters: &[
+        ParameterSpec::unsigned("tweak", "10000000000000", "Offset added to both prize coordinates in part 2"),
+    ],
+    part_1: Ok(ClawMachineAnalyser::new_with_tweak(0, 0)),
+    part_2: Ok(ParameterisedSolver::new(|parameters|
+        parameters.require("tweak").map(|tweak|ClawMachineAnalyser::new_with_tweak(tweak, tweak)))),
 }
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<ClawMachines>, String> {
-    let tweak = match is_part_2 {
-        false => 0,
-        true  => 10_000_000_000_000,
-    };
-    make_pipeline_with(ClawMachineAnalyser::new_with_tweak(tweak, tweak))
-}
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(13, false, make_pipeline(false), false)
-        ?.try_register(13, true, make_pipeline(true), false)
-}
\ No newline at end of file
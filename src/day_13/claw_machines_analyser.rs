@@ -1,4 +1,4 @@
-use crate::{answer::{Answer, DisplayableAnswer}, helper::result::{self, collect}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::result::{self, collect}, pipeline_error::PipelineError, solver::Solve};
 
 use super::{model::{ClawMachine, ClawMachines, Position}, single_solution_solver::SingleSolutionSolver};
 
@@ -59,17 +59,18 @@ impl ClawMachineAnalyser {
 }
 
 impl Solve<ClawMachines> for ClawMachineAnalyser {
-    fn solve(&self, input: ClawMachines) -> Result<Answer, String> {
+    fn solve(&self, input: ClawMachines) -> Result<Answer, PipelineError> {
         let ClawMachines(claw_machines) = input;
         
         let tweaked_claw_machines = collect(claw_machines.into_iter().map(|machine|self.tweak(machine)).collect());
-        if let Err(tweak_err) = tweaked_claw_machines { return Err(tweak_err); }
+        if let Err(tweak_err) = tweaked_claw_machines { return Err(tweak_err.into()); }
 
         let solutions = collect(tweaked_claw_machines.unwrap().into_iter().map(SingleSolutionSolver::solve).collect());
-        if let Err(e) = solutions { return Err(e); }
+        if let Err(e) = solutions { return Err(e.into()); }
 
         solutions.unwrap().into_iter().filter_map(|x|x)
             .try_fold(0, Self::accumulate_token_sum)
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
@@ -1,17 +1,10 @@
 use std::fmt::Display;
 
-use crate::helper::display::vector_display;
+use crate::helper::{display::vector_display, geometry::Point};
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub struct Position { pub x: u64, pub y: u64 }
-
-impl Display for Position {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({},{})", self.x, self.y)
-    }
-}
+pub type Position = Point<u64>;
 
-/// Claw machine's specification represented with three `UPosition`.
+/// Claw machine's specification represented with three `Position`s.
 /// - `button_a` is a position reached from `(0,0)` after pressing it once
 /// - `button_b` same as `button_a`
 /// - `prize` actual position of the prize
@@ -1,24 +1,37 @@
 #[cfg(test)]
 pub mod suite {
-    use crate::{answer::DisplayableAnswer, day_13::{make_pipeline, model::{ClawMachine, Position}, single_solution_solver::SingleSolutionSolver}, testing::test_whole_flow};
+    use crate::{answer::DisplayableAnswer, day_13::{make_pipeline, model::{ClawMachine, ClawMachines, Position}, single_solution_solver::SingleSolutionSolver, PARAMETERS}, parameters::Parameters, pipelined_executer::PipelinedExecuter, testing::test_whole_flow};
 
-    
     const REL_FILEPATHS: &[&str] = &[
         "src/day_13/test/example.txt", // Example given on AOC24
     ];
 
+    fn pipeline_with(is_part_2: bool, overrides: &Parameters) -> PipelinedExecuter<ClawMachines> {
+        make_pipeline(is_part_2).unwrap().with_parameters(PARAMETERS, overrides.clone())
+    }
+
+    fn pipeline(is_part_2: bool) -> PipelinedExecuter<ClawMachines> {
+        make_pipeline(is_part_2).unwrap()
+    }
+
     #[test]
     pub fn test_whole_flow_part_1_example() {
-        let pipeline = make_pipeline(false).unwrap();
+        let pipeline = pipeline(false);
         test_whole_flow(&pipeline, REL_FILEPATHS[0], DisplayableAnswer::new(480));
     }
 
     #[test]
     pub fn test_whole_flow_part_2_example() {
         let tokens_needed: u64 = 875_318_608_908; // Calculated using Google sheets. See example.txt for more steps with numbers
-        let pipeline = make_pipeline(true).unwrap();
+        let pipeline = pipeline(true);
         test_whole_flow(&pipeline, REL_FILEPATHS[0], DisplayableAnswer::new(tokens_needed));
+    }
 
+    #[test]
+    pub fn test_whole_flow_part_2_without_tweak() {
+        let mut overrides = Parameters::new();
+        overrides.insert("tweak", "0");
+        test_whole_flow(&pipeline_with(true, &overrides), REL_FILEPATHS[0], DisplayableAnswer::new(480));
     }
 
     #[test]
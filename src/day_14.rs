@@ -1,35 +1,47 @@
 use models::RobotList;
-use parser::RobotListParser;
+use parser::robot_list_parser;
 use safety_factor_calculator::SafetyFactorCalculator;
 use snapshot_capturer::SnapshotCapturer;
+use verifier::robots_verifier;
 
-use crate::{executer_manager::ExecuterManager, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, reading::SanitisedFileReader, solver::Solve, verifier::TrivialVerifier};
+use crate::{day::define_day, parameters::{ParameterSpec, Parameters}, reading::SanitisedFileReader, solver::ParameterisedSolver, verifier::ParameterisedVerifier};
 
-mod parser;
-mod models;
+pub mod parser;
+pub mod models;
+pub mod verifier;
 mod test;
-mod safety_factor_calculator;
-mod robots_prediction_model;
-mod snapshot_capturer;
-mod snapshots_answer;
+pub mod safety_factor_calculator;
+pub mod robots_prediction_model;
+pub mod snapshot_capturer;
+pub mod snapshots_answer;
 
-fn make_pipeline_with<S>(solver: Result<S, String>) -> Result<PipelinedExecuter<RobotList>, String>
-where S: Solve<RobotList> + 'static {
-    try_make_pipeline(
-        Ok(SanitisedFileReader::default()),
-        RobotListParser::new(),
-        Ok(TrivialVerifier::new::<RobotList>()),
-        solver)
+/// The area and the number of seconds can be declared in the input file as `//! width=11 height=7 seconds=100`, or given as parameters
+fn area(parameters: &Parameters) -> Result<(u16, u16), String> {
+    Ok((parameters.require("width")?, parameters.require("height")?))
 }
 
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<RobotList>, String> {
-    match is_part_2 {
-        false => make_pipeline_with(SafetyFactorCalculator::new(100, 101, 103)),
-        true  => make_pipeline_with(SnapshotCapturer::new(101*103, 101, 103)),
-    }
+define_day! {
+    number: 14,
+    title: "Restroom Redoubt",
+    input: RobotList,
+    reader: Ok(SanitisedFileReader::default()),
+    parser: Ok(robot_list_parser()),
+    verifier: Ok(ParameterisedVerifier::new(|parameters|{
+        let (width, height) = area(parameters)?;
+        Ok(robots_verifier(width, height))
+    })),
+    parameters: &[
+        ParameterSpec::unsigned("width", "101", "Width of the area").at_least(1).at_most(u16::MAX as u64),
+        ParameterSpec::unsigned("height", "103", "Height of the area").at_least(1).at_most(u16::MAX as u64),
+        ParameterSpec::unsigned_by_part("seconds", "Seconds the robots move, 100 in part 1 and at most width x height in part 2 by default")
+            .at_most(u16::MAX as u64),
+    ],
+    part_1: Ok(ParameterisedSolver::new(|parameters|{
+        let (width, height) = area(parameters)?;
+        SafetyFactorCalculator::new(parameters.get_or("seconds", 100)?, width, height)
+    })),
+    part_2: Ok(ParameterisedSolver::new(|parameters|{
+        let (width, height) = area(parameters)?;
+        SnapshotCapturer::new(parameters.get_or("seconds", width.saturating_mul(height))?, width, height)
+    })),
 }
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(14, false, make_pipeline(false), false)
-        ?.try_register(14, true, make_pipeline(true), false)
-}
\ No newline at end of file
@@ -1,23 +1,11 @@
 use std::fmt::Display;
 
-use crate::helper::display::vector_display;
-
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub struct XY {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl Display for XY {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{},{}", self.x, self.y)
-    }
-}
+use crate::helper::{display::vector_display, geometry::{Point, Vector}};
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub struct Robot {
-    pub position: XY,
-    pub velocity: XY,
+    pub position: Point<i32>,
+    pub velocity: Vector<i32>,
 }
 
 impl Display for Robot {
@@ -29,6 +17,13 @@ impl Display for Robot {
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct RobotList(pub Vec<Robot>);
 
+impl RobotList {
+    pub fn robots(&self) -> &[Robot] {
+        let RobotList(robots) = self;
+        robots
+    }
+}
+
 impl Display for RobotList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let RobotList(robots) = self;
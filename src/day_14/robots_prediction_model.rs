@@ -1,30 +1,26 @@
-use crate::helper::result::collect;
+use crate::helper::geometry::{Point, Vector};
 
-use super::models::{Robot, RobotList, XY};
+use super::models::{Robot, RobotList};
 
 
-pub struct RobotsPredictionModel { area: XY }
+pub struct RobotsPredictionModel { area: Vector<i32> }
 
 mod error {
-    use crate::{day_14::models::{Robot, XY}, helper::display::vector_display};
+    use crate::helper::{display::vector_display, geometry::Vector};
 
     const PREFIX: &str = "[Robots Prediction Model D-14]";
 
-    pub fn inappropriate_area(area: XY) -> String {
+    pub fn inappropriate_area(area: Vector<i32>) -> String {
         vector_display(&vec![
             format!("{} cannot create the model. Inappropriate area provided: {}.", PREFIX, area),
             format!("An appropriate area has odd width and height.")
         ], " ")
     }
-
-    pub fn robot_position_out_of_area(robot: Robot) -> String {
-        format!("{} robot's initial position is out of area defined for the solver. Robot: {}.", PREFIX, robot)
-    }
 }
 
 impl RobotsPredictionModel {
     pub fn new(width: u16, height: u16) -> Result<RobotsPredictionModel, String> {
-        let area = XY { x: width as i32, y: height as i32 };
+        let area = Vector::new(width as i32, height as i32);
         if width % 2 == 1 && height % 2 == 1 {
             Ok(RobotsPredictionModel { area })
         } else {
@@ -32,34 +28,18 @@ impl RobotsPredictionModel {
         }
     }
     
-    pub fn get_area(&self) -> XY { self.area }
+    pub fn get_area(&self) -> Vector<i32> { self.area }
 
 
-    fn check_initial_positions(&self, input: &RobotList) -> Result<Vec<Robot>, String> {
-        let RobotList(robots) = input;
-        collect(robots.iter().map(|&robot|{
-            let XY { x, y } = robot.position;
-            if 0 <= x && x < self.area.x && 0 <= y && y < self.area.y { Ok(robot) }
-            else { Err(error::robot_position_out_of_area(robot)) }
-        }).collect())
-    }
-
-    fn predict_position(&self, robot: Robot, seconds: u16) -> XY {
-        let Robot {
-            position: XY { x: px, y: py },
-            velocity: XY { x: vx, y: vy },
-        } = robot;
-        let time = seconds as i32;
-        
-        XY {
-            x: (((px + vx * time) % self.area.x) + self.area.x) % self.area.x,
-            y: (((py + vy * time) % self.area.y) + self.area.y) % self.area.y,
-        }
+    /// Computed in `i64`, as the velocity times the seconds may not fit into `i32`
+    fn predict_position(&self, robot: Robot, seconds: u16) -> Point<i32> {
+        let Robot { position, velocity } = robot;
+        let axis = |start: i32, speed: i32, size: i32|(start as i64 + speed as i64 * seconds as i64).rem_euclid(size as i64) as i32;
+        Point::new(axis(position.x, velocity.x, self.area.x), axis(position.y, velocity.y, self.area.y))
     }
 
-    pub fn predict(&self, input: &RobotList, seconds: u16) -> Result<Vec<XY>, String> {
-        self.check_initial_positions(input).map(
-            |robots|robots.into_iter().map(|robot|self.predict_position(robot, seconds)).collect()
-        )
+    /// Predicts the positions of the robots, whose initial positions were verified to lie within the area
+    pub fn predict(&self, input: &RobotList, seconds: u16) -> Vec<Point<i32>> {
+        input.robots().iter().map(|&robot|self.predict_position(robot, seconds)).collect()
     }
 }
\ No newline at end of file
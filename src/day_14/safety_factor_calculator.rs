@@ -1,6 +1,6 @@
-use crate::{answer::{Answer, DisplayableAnswer}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::geometry::{Point, Vector}, pipeline_error::PipelineError, solver::Solve};
 
-use super::{models::{RobotList, XY}, robots_prediction_model::RobotsPredictionModel};
+use super::{models::RobotList, robots_prediction_model::RobotsPredictionModel};
 
 pub struct SafetyFactorCalculator {
     seconds: u16,
@@ -28,14 +28,14 @@ impl SafetyFactorCalculator {
             .ok_or_else(||error::product_overflow(quadrants))
     }
 
-    fn safety_score(&self, positions: Vec<XY>) -> Result<u64, String> {
+    fn safety_score(&self, positions: Vec<Point<i32>>) -> Result<u64, String> {
         let mut qcounts: [u64; 4] = [0, 0, 0, 0];
         let (mid_x, mid_y) = {
-            let XY { x, y } = self.prediction_model.get_area();
+            let Vector { x, y } = self.prediction_model.get_area();
             (x / 2, y / 2)
         };
 
-        for XY { x, y } in positions {
+        for Point { x, y } in positions {
             if x == mid_x || y == mid_y { continue; }
             let x_index = if x < mid_x { 0 } else { 1 };
             let y_index = if y < mid_y { 0 } else { 1 };
@@ -48,9 +48,9 @@ impl SafetyFactorCalculator {
 }
 
 impl Solve<RobotList> for SafetyFactorCalculator {
-    fn solve(&self, input: RobotList) -> Result<Answer, String> {
-        self.prediction_model.predict(&input, self.seconds)
-            .and_then(|positions|self.safety_score(positions))
+    fn solve(&self, input: RobotList) -> Result<Answer, PipelineError> {
+        self.safety_score(self.prediction_model.predict(&input, self.seconds))
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
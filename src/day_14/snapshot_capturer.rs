@@ -1,6 +1,6 @@
-use crate::{answer::Answer, solver::Solve};
+use crate::{answer::Answer, helper::geometry::Vector, pipeline_error::PipelineError, solver::Solve};
 
-use super::{models::{RobotList, XY}, robots_prediction_model::RobotsPredictionModel, snapshots_answer::SnapshotsAnswer};
+use super::{models::RobotList, robots_prediction_model::RobotsPredictionModel, snapshots_answer::SnapshotsAnswer};
 
 /// Captures snapshots of robots positions at `i`th second where `0 <= i <= seconds`.
 pub struct SnapshotCapturer {
@@ -17,17 +17,11 @@ impl SnapshotCapturer {
 }
 
 impl Solve<RobotList> for SnapshotCapturer {
-    fn solve(&self, input: RobotList) -> Result<Answer, String> {
-        let mut i = 0;
-        let mut snapshots = vec![];
-        while i <= self.seconds {
-            match self.prediction_model.predict(&input, i) {
-                Ok(positions) => { snapshots.push(positions); },
-                Err(err) => return Err(err),
-            }
-            i += 1;
-        }
-        let XY { x, y } = self.prediction_model.get_area();
+    fn solve(&self, input: RobotList) -> Result<Answer, PipelineError> {
+        let snapshots = (0..=self.seconds)
+            .map(|i|self.prediction_model.predict(&input, i))
+            .collect();
+        let Vector { x, y } = self.prediction_model.get_area();
         Ok(Box::new(SnapshotsAnswer::new(snapshots, x as u16, y as u16)))
     }
 }
\ No newline at end of file
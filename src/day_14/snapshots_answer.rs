@@ -1,29 +1,26 @@
 use std::{collections::HashSet, vec};
 
-use crate::{answer::Report, helper::display::vector_display};
-
-use super::models::XY;
+use crate::{answer::Report, helper::{display::vector_display, geometry::{Point, Vector}, json::JsonValue}};
 
 #[derive(Debug)]
 pub struct SnapshotsAnswer {
-    snapshots: Vec<Vec<XY>>,
-    area: XY,
+    snapshots: Vec<Vec<Point<i32>>>,
+    area: Vector<i32>,
 }
 
 impl SnapshotsAnswer {
-    pub fn new(snapshots: Vec<Vec<XY>>, width: u16, height: u16) -> SnapshotsAnswer {
-        SnapshotsAnswer { snapshots, area: XY { x: width as i32, y: height as i32 } }
+    pub fn new(snapshots: Vec<Vec<Point<i32>>>, width: u16, height: u16) -> SnapshotsAnswer {
+        SnapshotsAnswer { snapshots, area: Vector::new(width as i32, height as i32) }
     }
 
-    fn snapshot_to_string(&self, snapshot: &Vec<XY>) -> String {
-        let mut hashed_positions = HashSet::new();
-        snapshot.iter().for_each(|&XY { x, y }|{ hashed_positions.insert((x, y)); });
+    fn snapshot_to_string(&self, snapshot: &Vec<Point<i32>>) -> String {
+        let hashed_positions = snapshot.iter().copied().collect::<HashSet<_>>();
 
         let mut snapshot_print = vec![];
         for y in 0..self.area.y {
             let mut row = vec![];
             for x in 0..self.area.x {
-                row.push(if hashed_positions.contains(&(x, y)) { '#' } else { ' ' });
+                row.push(if hashed_positions.contains(&Point::new(x, y)) { '#' } else { ' ' });
             }
             snapshot_print.push(vector_display(&row, ""));
         }
@@ -40,4 +37,24 @@ impl Report for SnapshotsAnswer {
         }
         vector_display(&builder, "\n")
     }
+
+    fn summary(&self) -> String {
+        format!("{} snapshot(s) of {}x{} area", self.snapshots.len(), self.area.x, self.area.y)
+    }
+
+    fn to_json(&self) -> JsonValue {
+        let snapshots = self.snapshots.iter().enumerate()
+            .map(|(i, snapshot)|JsonValue::object(vec![
+                ("iteration", JsonValue::number(i)),
+                ("robots", JsonValue::Array(snapshot.iter()
+                    .map(|&Point { x, y }|JsonValue::Array(vec![JsonValue::number(x), JsonValue::number(y)]))
+                    .collect())),
+            ]))
+            .collect();
+        JsonValue::object(vec![
+            ("width", JsonValue::number(self.area.x)),
+            ("height", JsonValue::number(self.area.y)),
+            ("snapshots", JsonValue::Array(snapshots)),
+        ])
+    }
 }
\ No newline at end of file
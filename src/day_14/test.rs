@@ -1,9 +1,10 @@
 #[cfg(test)]
 pub mod suite {
-    use crate::{answer::DisplayableAnswer, day_14::{make_pipeline, safety_factor_calculator::SafetyFactorCalculator}, solver::Solve, testing::get_verified_result_ok};
+    use crate::{answer::DisplayableAnswer, executer::Execute, parameters::Parameters, day_14::{make_pipeline, PARAMETERS, safety_factor_calculator::SafetyFactorCalculator, snapshot_capturer::SnapshotCapturer}, solver::Solve, testing::{get_verification_error, resolve_filepath, get_verified_result_ok, test_whole_flow}};
 
     const REL_FILEPATHS: &[&str] = &[
         "src/day_14/test/example.txt", // Example given on AOC24
+        "src/day_14/test/out_of_area_example.txt", // Robot outside of the declared area
     ];
 
     
@@ -15,4 +16,36 @@ pub mod suite {
         let solver = SafetyFactorCalculator::new(100, 11, 7).unwrap();
         assert_eq!(solver.solve(input).unwrap().report(), DisplayableAnswer::new(12).report())
     }
+
+    #[test]
+    pub fn test_whole_flow_part_1_example_with_declared_area() {
+        let pipeline = make_pipeline(false).unwrap();
+        test_whole_flow(&pipeline, REL_FILEPATHS[0], DisplayableAnswer::new(12));
+    }
+
+    #[test]
+    pub fn test_snapshots_up_to_largest_seconds() {
+        // The largest number of seconds the parameter accepts does not overflow the snapshot count
+        let mut overrides = Parameters::new();
+        overrides.insert("seconds", &u16::MAX.to_string());
+        let pipeline = make_pipeline(true).unwrap().with_parameters(PARAMETERS, overrides);
+        assert!(pipeline.execute(&resolve_filepath(REL_FILEPATHS[0])).is_ok());
+    }
+
+    #[test]
+    pub fn test_snapshots_json() {
+        let pipeline = make_pipeline(true).unwrap();
+        let input = get_verified_result_ok(&pipeline, REL_FILEPATHS[0]);
+        let answer = SnapshotCapturer::new(1, 11, 7).unwrap().solve(input).unwrap();
+        let json = answer.to_json().to_string();
+        assert!(json.starts_with(r#"{"width":11,"height":7,"snapshots":[{"iteration":0,"robots":[[0,4],"#), "{}", json);
+        assert!(json.contains(r#"{"iteration":1,"robots":["#));
+    }
+
+    #[test]
+    pub fn test_verifier_out_of_area_error() {
+        let pipeline = make_pipeline(false).unwrap();
+        let error = get_verification_error(&pipeline, REL_FILEPATHS[1]);
+        assert!(error.contains("out of area <11,7>") && error.ends_with("(item with index 1)"), "{}", error);
+    }
 }
\ No newline at end of file
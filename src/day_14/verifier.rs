@@ -0,0 +1,22 @@
+use crate::{helper::geometry::Vector, verifier::{each, Verify}};
+
+use super::models::{Robot, RobotList};
+
+mod error {
+    use crate::{day_14::models::Robot, helper::geometry::Vector};
+
+    const PREFIX: &str = "[Verifier D-14]";
+
+    pub fn robot_position_out_of_area(robot: &Robot, area: Vector<i32>) -> String {
+        format!("{} robot's initial position is out of area {}. Robot: {}", PREFIX, area, robot)
+    }
+}
+
+/// Verifies that the initial position of every robot lies within the area of the given width and height
+pub fn robots_verifier(width: u16, height: u16) -> impl Verify<RobotList> {
+    let area = Vector::new(width as i32, height as i32);
+    each(RobotList::robots, move |robot: &Robot|match robot.position.is_within(area) {
+        true => Ok(()),
+        false => Err(error::robot_position_out_of_area(robot, area)),
+    })
+}
@@ -2,13 +2,13 @@ use box_prediction_model::BoxPredictionModel;
 use models::MapAndMoves;
 use parser::MapAndMovesParser;
 
-use crate::{executer_manager::ExecuterManager, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, reading::{SanitisedFileReader, SimpleFileReader}, solver::Solve, verifier::TrivialVerifier};
+use crate::{day::define_day, reading::{SanitisedFileReader, SimpleFileReader}, verifier::TrivialVerifier};
 
-mod models;
-mod parser;
+pub mod models;
+pub mod parser;
 mod test;
-mod map_state;
-mod box_prediction_model;
+pub mod map_state;
+pub mod box_prediction_model;
 
 fn reader() -> SanitisedFileReader {
     use crate::reading::settings::*;
@@ -21,25 +21,13 @@ fn reader() -> SanitisedFileReader {
     )
 }
 
-fn make_pipeline_with<S>(solver: Result<S, String>) -> Result<PipelinedExecuter<MapAndMoves>, String>
-where S: Solve<MapAndMoves> + 'static {
-    try_make_pipeline(
-        Ok(reader()),
-        Ok(MapAndMovesParser),
-        Ok(TrivialVerifier::new::<MapAndMoves>()),
-        solver
-    )
-}
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<MapAndMoves>, String> {
-    let scale = match is_part_2 {
-        false => 1,
-        true  => 2,
-    };
-    make_pipeline_with(BoxPredictionModel::new(scale))
+define_day! {
+    number: 15,
+    title: "Warehouse Woes",
+    input: MapAndMoves,
+    reader: Ok(reader()),
+    parser: Ok(MapAndMovesParser::new()),
+    verifier: Ok(TrivialVerifier::new::<MapAndMoves>()),
+    part_1: BoxPredictionModel::new(1),
+    part_2: BoxPredictionModel::new(2),
 }
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(15, false, make_pipeline(false), false)
-        ?.try_register(15, true, make_pipeline(true), false)
-}
\ No newline at end of file
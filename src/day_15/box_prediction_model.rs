@@ -1,8 +1,8 @@
-use std::{collections::{HashMap, HashSet}, vec};
+use std::{collections::HashSet, vec};
 
-use crate::{answer::{Answer, DisplayableAnswer}, day_15::models::RobotMoves, helper::{direction::Direction, position::UPosition, table::Table}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, day_15::models::RobotMoves, helper::{direction::Direction, position::UPosition, table::Table}, pipeline_error::PipelineError, solver::Solve};
 
-use super::{map_state::MapState, models::{Field, MapAndMoves}};
+use super::{map_state::{Cell, MapState}, models::{Field, MapAndMoves}};
 
 mod error {
     use crate::helper::display::vector_display;
@@ -56,33 +56,33 @@ impl BoxPredictionModel {
     }
 
     fn initial_state(&self, map: Table<Field>) -> Result<MapState, String> {
-        let mut walkable_map = HashMap::new();
         let mut robot_positions = vec![];
         let mut crates = vec![];
-
-        // Process the input map
-        for (pos, &field) in map.iter() {
-            // if it is a wall, nothing to do
-            if field == Field::Wall { continue; }
-
-            // if it is non-wall, box/robot can be on these positions, so we wish to add it
-            // to the map of "walkable" positions
-            let expanded_positions = self.apply_scale(self.get_leftmost_scaled_up_position(pos));
-
-            // initially, some crates can be on these positions, in which case we associate them with crate id
-            let value = if field == Field::Crate { Some(crates.len()) } else { None };
-            
-            // if it is a crate or robot here, register it in respective vector
-            if field == Field::Crate {
-                crates.push(expanded_positions[0]);
-            } else if field == Field::Robot {
-                robot_positions.push(expanded_positions[0]);
-            }
-            
-            // populate the walkable map
-            for epos in expanded_positions {
-                walkable_map.insert(epos, value);
+        let mut rows = vec![];
+
+        // Process the input map, scaling each field up to `self.scale` cells to the right
+        for (row, fields) in map.rows().enumerate() {
+            let mut cells = vec![];
+            for (col, &field) in fields.iter().enumerate() {
+                let position = self.get_leftmost_scaled_up_position(UPosition::new((row, col)));
+
+                // if it is a crate or robot here, register it in respective vector,
+                // crates are additionally associated with their id on the map
+                let cell = match field {
+                    Field::Wall => Cell::Wall,
+                    Field::Empty => Cell::Free,
+                    Field::Robot => {
+                        robot_positions.push(position);
+                        Cell::Free
+                    },
+                    Field::Crate => {
+                        crates.push(position);
+                        Cell::Crate(crates.len() - 1)
+                    },
+                };
+                cells.extend(vec![cell; self.scale]);
             }
+            rows.push(cells);
         }
 
         // exactly one robot expected
@@ -92,15 +92,14 @@ impl BoxPredictionModel {
         
         // successfully create initial state
         Ok(MapState{
-            map: walkable_map, crates,
+            map: Table::new(rows)?, crates,
             robot: robot_positions[0],
         })
     }
 
-    fn try_move(
-        position: UPosition, direction: Direction, map: &HashMap<UPosition, Option<usize>>
-    ) -> Option<UPosition> {
-        direction.movement().apply(position).filter(|moved_position|map.contains_key(moved_position))
+    fn try_move(position: UPosition, direction: Direction, map: &Table<Cell>) -> Option<UPosition> {
+        direction.movement().apply(position)
+            .filter(|&moved_position|map.get_pos(moved_position).is_some_and(|&cell|cell != Cell::Wall))
     }
 
     fn get_crates_affected(&self, state: &MapState, direction: Direction) -> HashSet<usize> {
@@ -117,14 +116,15 @@ impl BoxPredictionModel {
             if next_position.is_none() { continue; }
 
             // If the next position is not occupied by crate, stop processing
-            let crate_index = *state.map.get(&next_position.unwrap()).unwrap();
-            if crate_index.is_none() { continue; }
-            
+            let crate_index = match state.map.get_pos(next_position.unwrap()) {
+                Some(&Cell::Crate(crate_index)) => crate_index,
+                _ => continue,
+            };
             
-            crates_affected.insert(crate_index.unwrap());
+            crates_affected.insert(crate_index);
             
             // Otherwise, another crate is moved, which can move further crates: queue those positions for inspection
-            for crate_position in self.apply_scale(state.crates[crate_index.unwrap()]) {
+            for crate_position in self.apply_scale(state.crates[crate_index]) {
                 if already_queued.insert(crate_position) {
                     queue.push(crate_position); // queue the position for inspection iff not queued before
                 }
@@ -150,14 +150,14 @@ impl BoxPredictionModel {
 
         // "remove" all affected crates
         for pos in affected_crates.iter().flat_map(|&crate_index|self.apply_scale(state.crates[crate_index])) {
-            state.map.insert(pos, None);
+            state.map.set(pos, Cell::Free);
         }
 
         // "add" all affected crates after their unit directional move
         for crate_id in affected_crates {
             for crate_position in self.apply_scale(state.crates[crate_id]) {
                 let next_position = Self::try_move(crate_position, direction, &state.map).unwrap();
-                state.map.insert(next_position, Some(crate_id));
+                state.map.set(next_position, Cell::Crate(crate_id));
             }
             let next_crate_pos = Self::try_move(state.crates[crate_id], direction, &state.map);
             state.crates[crate_id] = next_crate_pos.unwrap();
@@ -176,7 +176,7 @@ impl BoxPredictionModel {
 }
 
 impl Solve<MapAndMoves> for BoxPredictionModel {
-    fn solve(&self, input: MapAndMoves) -> Result<Answer, String> {
+    fn solve(&self, input: MapAndMoves) -> Result<Answer, PipelineError> {
         let MapAndMoves(map, moves) = input;
         match self.initial_state(map) {
             Ok(initial_state) => {
@@ -186,7 +186,7 @@ impl Solve<MapAndMoves> for BoxPredictionModel {
 
                 Ok(DisplayableAnswer::new(Self::calculate_gps_sum(end_state)))
             }
-            Err(e) => Err(e),
+            Err(e) => Err(e.into()),
         }
     }
 }
\ No newline at end of file
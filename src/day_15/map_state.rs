@@ -1,17 +1,22 @@
-use std::collections::HashMap;
+use crate::helper::{position::UPosition, table::Table};
 
-use crate::helper::position::UPosition;
+/// Content of a position of the (scaled up) map
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Cell {
+    Wall,
+    Free,
+    /// Position occupied by the crate with the given id
+    Crate(usize),
+}
 
 pub struct MapState {
     /// Crates existing on the map, a crate with ID `i` is on position `i` (`crates[i]`)
     pub crates: Vec<UPosition>,
 
-    /// Map is a hash map from position to id of crate occupying it (if any).
-    /// If a position `P` is in the map, it means it is non-wall position. The associated value can be:
-    /// - `Some(id)` representing the `id` of the crate occupying it.
-    /// - `None` if the position is free of crates.
-    pub map: HashMap<UPosition, Option<usize>>,
+    /// Map is a table of cells. Any position that is not a `Cell::Wall` can be walked on,
+    /// by the robot or the crates, and is either free or occupied by a crate with some id.
+    pub map: Table<Cell>,
 
     /// Position of the robot
     pub robot: UPosition,
-}
\ No newline at end of file
+}
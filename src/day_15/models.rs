@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::helper::{direction::Direction, display::vector_display, table::Table};
+use crate::helper::{direction::Direction, display::vector_display, grid::CharMapping, table::Table};
 
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
@@ -11,14 +11,16 @@ pub enum Field {
     Robot,
 }
 
+pub const FIELD_MAPPING: CharMapping<Field> = CharMapping::new(&[
+    ('.', Field::Empty),
+    ('#', Field::Wall),
+    ('O', Field::Crate),
+    ('@', Field::Robot),
+]);
+
 impl Display for Field {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", match self {
-            Field::Empty => '.',
-            Field::Wall => '#',
-            Field::Crate => 'O',
-            Field::Robot => '@',
-        })
+        FIELD_MAPPING.fmt(*self, f)
     }
 }
 
@@ -1,58 +1,41 @@
-use crate::{helper::{direction::Direction, result::collect, table::Table}, parser::Parse, reader::{Line, VecLine}};
+use crate::{helper::{direction::Direction, grid::GridParser, result::collect}, parser::Parse, pipeline_error::PipelineError, reader::{Line, VecLine}};
 
-use super::models::{Field, MapAndMoves, RobotMoves};
+use super::models::{Field, MapAndMoves, RobotMoves, FIELD_MAPPING};
 
-pub struct MapAndMovesParser;
+pub struct MapAndMovesParser {
+    map_parser: GridParser<Field>,
+}
+
+const PREFIX: &str = "[Parser D-15]";
 
 mod error {
-    const PREFIX: &str = "[Parser D-15]";
+    use super::PREFIX;
 
-    pub fn unsupported_char(c: char, line_num: usize) -> String {
-        format!("{} unsupported character '{}' on line #{}", PREFIX, c, line_num)
+    pub fn unsupported_char(c: char) -> String {
+        format!("{} unsupported character '{}'", PREFIX, c)
     }
 }
 
 impl MapAndMovesParser {
-    fn is_empty(line: &Line) -> bool {
-        line.text.trim().len() == 0
-    }
-
-    fn try_parse_map_row(line: &Line) -> Option<Vec<Field>> {
-        if Self::is_empty(line) { return None; }
-        let parsed = line.text.chars().map(|c|match c {
-            '.' => Ok(Field::Empty),
-            '#' => Ok(Field::Wall),
-            '@' => Ok(Field::Robot),
-            'O' => Ok(Field::Crate),
-            _   => Err(()),
-        }).collect();
-        collect(parsed).map(Some).unwrap_or(None)
+    pub fn new() -> MapAndMovesParser {
+        MapAndMovesParser { map_parser: GridParser::new(PREFIX, FIELD_MAPPING).exactly_one(Field::Robot) }
     }
 
-    fn parse_map(lines: Vec<Line>) -> Result<(Table<Field>, Vec<Line>), String> {
-        let mut i = 0;
-        let mut parsed_rows = vec![];
-        while i < lines.len() {
-            if let Some(parsed_row) = Self::try_parse_map_row(&lines[i]) {
-                parsed_rows.push(parsed_row);
-                i += 1;
-            }
-            else { break; }
-        }
-        
-        Table::new(parsed_rows).map(|table|(table, lines.into_iter().skip(i).collect()))
+    fn is_empty(line: &Line) -> bool {
+        line.text.trim().len() == 0
     }
 
-    fn parse_moves(lines: Vec<Line>) -> Result<Vec<RobotMoves>, String> {
+    fn parse_moves(lines: Vec<Line>) -> Result<Vec<RobotMoves>, PipelineError> {
         let mut moves = vec![];
         for line in lines {
             if Self::is_empty(&line) { continue; }
-            let parsed_moves = line.text.trim().chars().map(|c|match c {
+            let indent = line.text.len() - line.text.trim_start().len();
+            let parsed_moves = line.text.trim().char_indices().map(|(offset, c)|match c {
                 '^' => Ok(Direction::Up),
                 'v' => Ok(Direction::Down),
                 '>' => Ok(Direction::Right),
                 '<' => Ok(Direction::Left),
-                c   => Err(error::unsupported_char(c, line.number))
+                c   => Err(PipelineError::at(&line, line.column_at(indent + offset), error::unsupported_char(c)))
             }).collect();
             moves.push(collect(parsed_moves).map(RobotMoves));
         }
@@ -60,9 +43,19 @@ impl MapAndMovesParser {
     }
 }
 
+impl Default for MapAndMovesParser {
+    fn default() -> Self { Self::new() }
+}
+
 impl Parse<MapAndMoves> for MapAndMovesParser {
-    fn parse(&self, vec_line: VecLine) -> Result<MapAndMoves, String> {
-        Self::parse_map(vec_line.lines)
-            .and_then(|(map, lines)|Self::parse_moves(lines).map(|moves|MapAndMoves(map, moves)))
+    fn parse(&self, vec_line: VecLine) -> Result<MapAndMoves, PipelineError> {
+        // The map spans the lines up to the first empty line, the moves follow it
+        let mut lines = vec_line.lines;
+        let moves_lines = match lines.iter().position(Self::is_empty) {
+            Some(separator) => lines.split_off(separator).split_off(1),
+            None => vec![],
+        };
+        let map = self.map_parser.parse_lines(&lines)?.table;
+        Self::parse_moves(moves_lines).map(|moves|MapAndMoves(map, moves))
     }
 }
\ No newline at end of file
@@ -1,13 +1,25 @@
 #[cfg(test)]
 pub mod suite {
-    use crate::{answer::DisplayableAnswer, day_15::make_pipeline, testing::test_whole_flow};
+    use crate::{answer::DisplayableAnswer, day_15::make_pipeline, executer::Execute, testing::test_whole_flow};
     
     const REL_FILEPATHS: &[&str] = &[
         "src/day_15/test/big_example.txt", // Example given on AOC24
         "src/day_15/test/small_example.txt", // Example given on AOC24
         "src/day_15/test/third_example.txt", // Example given on AOC24
+        "src/day_15/test/bad_map_example.txt",
+        "src/day_15/test/bad_moves_example.txt",
     ];
 
+    #[test]
+    pub fn test_parser_errors() {
+        let pipeline = make_pipeline(false).unwrap();
+        let error = pipeline.execute(REL_FILEPATHS[3]).unwrap_err();
+        assert_eq!((error.line_number(), error.column), (Some(2), Some(4)));
+        assert!(error.to_string().starts_with("[Parser D-15] expected one of"), "{}", error);
+        let error = pipeline.execute(REL_FILEPATHS[4]).unwrap_err();
+        assert_eq!((error.line_number(), error.column), (Some(6), Some(2)));
+    }
+
     #[test]
     pub fn test_whole_flow_part_1_big_example() {
         let pipeline = make_pipeline(false).unwrap();
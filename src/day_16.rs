@@ -1,16 +1,18 @@
 use reindeer_maze_solver::ReindeerMazeSolver;
 use model::Field;
-use parser::ReindeerMazeParser;
+use parser::reindeer_maze_parser;
+use verifier::maze_verifier;
 use reindeer_path_analyser::{LowestScoreAnalyser, OptimalPathFieldAnalyser};
 
-use crate::{executer_manager::ExecuterManager, helper::table::Table, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, reading::{SanitisedFileReader, SimpleFileReader}, solver::Solve, verifier::TrivialVerifier};
+use crate::{day::define_day, helper::grid::Grid, reading::{SanitisedFileReader, SimpleFileReader}};
 
-mod model;
-mod parser;
+pub mod model;
+pub mod parser;
+pub mod verifier;
 mod test;
-mod state;
-mod reindeer_path_analyser;
-mod reindeer_maze_solver;
+pub mod state;
+pub mod reindeer_path_analyser;
+pub mod reindeer_maze_solver;
 
 fn reader() -> SanitisedFileReader {
     use crate::reading::settings::*;
@@ -23,25 +25,13 @@ fn reader() -> SanitisedFileReader {
     )
 }
 
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<Table<Field>>, String>
-where S: Solve<Table<Field>> + 'static {
-    try_make_pipeline(
-        Ok(reader()),
-        Ok(ReindeerMazeParser::new()),
-        Ok(TrivialVerifier::new::<Table<Field>>()),
-        Ok(solver)
-    )
-}
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<Table<Field>>, String> {
-    match is_part_2 {
-        false => make_pipeline_with(ReindeerMazeSolver::new(LowestScoreAnalyser)),
-        true  => make_pipeline_with(ReindeerMazeSolver::new(OptimalPathFieldAnalyser)),
-    }
-    
+define_day! {
+    number: 16,
+    title: "Reindeer Maze",
+    input: Grid<Field>,
+    reader: Ok(reader()),
+    parser: Ok(reindeer_maze_parser()),
+    verifier: Ok(maze_verifier()),
+    part_1: Ok(ReindeerMazeSolver::new(LowestScoreAnalyser)),
+    part_2: Ok(ReindeerMazeSolver::new(OptimalPathFieldAnalyser)),
 }
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(16, false, make_pipeline(false), false)
-        ?.try_register(16, true, make_pipeline(true), false)
-}
\ No newline at end of file
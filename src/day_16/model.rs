@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use crate::helper::grid::CharMapping;
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Field {
     Wall,
@@ -8,13 +10,15 @@ pub enum Field {
     End,
 }
 
+pub const FIELD_MAPPING: CharMapping<Field> = CharMapping::new(&[
+    ('#', Field::Wall),
+    ('.', Field::Empty),
+    ('S', Field::Start),
+    ('E', Field::End),
+]);
+
 impl Display for Field {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", match self {
-            Field::Wall  => '#',
-            Field::Empty => '.',
-            Field::Start => 'S',
-            Field::End   => 'E',
-        })
+        FIELD_MAPPING.fmt(*self, f)
     }
-}
\ No newline at end of file
+}
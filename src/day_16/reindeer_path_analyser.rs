@@ -1,103 +1,53 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
-use crate::helper::{direction::Direction, position::UPosition};
+use crate::helper::{direction::Direction, position::UPosition, search::SearchResult};
 
-use super::state::{State, StateWithScore};
+use super::state::State;
 
 
-pub trait ReindeerPathAnalyser {
-    fn analyse(&self, scores: HashMap<State, u64>, end: UPosition) -> Result<u64, String>;
+pub trait ReindeerPathAnalyser: Send + Sync {
+    fn analyse(&self, scores: &SearchResult<State>, ends: &[UPosition]) -> Result<u64, String>;
 }
 
 fn end_position_not_reachable() -> String {
     format!("[ReindeerPathAnalyser] end position is not reachable.")
 }
 
-
-fn scores_at(position: UPosition, scores: &HashMap<State, u64>) -> Vec<StateWithScore> {
-    Direction::all()
-        .into_iter()
-        .map(|direction|State { direction, position })
-        .flat_map(|state|scores.get(&state).map(|&score|StateWithScore { state, score }))
-        .collect()
+/// Returns the lowest score required to reach any of the end positions together with the end states having that score
+fn min_scores_at(positions: &[UPosition], scores: &SearchResult<State>) -> Result<(u64, Vec<State>), String> {
+    let states_with_scores = positions.iter()
+        .flat_map(|&position|Direction::all().into_iter().map(move |direction|State { direction, position }))
+        .flat_map(|state|scores.distance(state).map(|score|(state, score)))
+        .collect::<Vec<_>>();
+    let min_score = states_with_scores.iter().map(|&(_, score)|score).min().ok_or_else(end_position_not_reachable)?;
+    let min_states = states_with_scores.into_iter()
+        .filter(|&(_, score)|score == min_score)
+        .map(|(state, _)|state)
+        .collect();
+    Ok((min_score, min_states))
 }
 
 /// Analyses the scores and returns the smallest score required to reach end position
 pub struct LowestScoreAnalyser;
 
 impl ReindeerPathAnalyser for LowestScoreAnalyser {
-    fn analyse(&self, scores: HashMap<State, u64>, end: UPosition) -> Result<u64, String> {
-        // we do `.max()` here because we are returned a vector of `StateWithScore`
-        // whose `Ord` relation is defined inversely on the `.score`
-        scores_at(end, &scores)
-            .into_iter()
-            .max()
-            .map(|state_with_score|state_with_score.score)
-            .ok_or_else(end_position_not_reachable)
+    fn analyse(&self, scores: &SearchResult<State>, ends: &[UPosition]) -> Result<u64, String> {
+        min_scores_at(ends, scores).map(|(score, _)|score)
     }
 }
 
 /// Analyses the scores and returns the number of different fields that belong to an optimal path
 pub struct OptimalPathFieldAnalyser;
 
-impl OptimalPathFieldAnalyser {
-    fn min_scores_at(position: UPosition, scores: &HashMap<State, u64>) -> Vec<StateWithScore> {
-        let states_with_scores = scores_at(position, scores);
-        let min_score = states_with_scores.clone().into_iter().max().map(|sws|sws.score);
-
-        states_with_scores.into_iter()
-            .filter(|sws|min_score.is_some_and(|score| score == sws.score))
-            .collect()
-    }
-
-    fn previous_states(scores: &HashMap<State, u64>, state_score: StateWithScore) -> Vec<StateWithScore> {
-        let StateWithScore { state: State { position, direction }, score } = state_score;
-        let mut previous = vec![];
-
-        // Undo rotation
-        for previous_direction in [direction.rotate(), direction.rotate().rotate().rotate()] {
-            if score < 1_000 { continue; }
-            let previous_state = State { direction: previous_direction, position };
-            if scores.get(&previous_state).is_some_and(|&act_score| act_score == score - 1_000) {
-                previous.push(StateWithScore { state: previous_state, score: score - 1_000 });
-            }
-        }
-
-        // Undo moving forward
-        direction.rotate().rotate().movement().apply(position)
-            .map(|previous_position| State { position: previous_position, direction })
-            .filter(|&previous_state|scores.get(&previous_state).is_some_and(|&act_score| act_score + 1 == score))
-            .map(|previous_state| StateWithScore { state: previous_state, score: score - 1 })
-            .into_iter().for_each(|previous_state|{ previous.push(previous_state); });
-        
-        previous
-    }
-}
-
 impl ReindeerPathAnalyser for OptimalPathFieldAnalyser {
-    fn analyse(&self, scores: HashMap<State, u64>, end: UPosition) -> Result<u64, String> {
-        let mut queue = Self::min_scores_at(end, &scores);
-        if queue.len() == 0 {
-            return Err(end_position_not_reachable());
-        }
-
-        let mut optimal = HashSet::new();
-        queue.iter().for_each(|state_with_score|{ optimal.insert(state_with_score.state); });
-
-        // Work out backway what states participated in the optimal paths
-        while !queue.is_empty() {
-            let state_with_score = queue.pop().unwrap();
-            for previous_state_with_score in Self::previous_states(&scores, state_with_score) {
-                if optimal.insert(previous_state_with_score.state) {
-                    queue.push(previous_state_with_score);
-                }
-            }
-        }
+    fn analyse(&self, scores: &SearchResult<State>, ends: &[UPosition]) -> Result<u64, String> {
+        let (_, end_states) = min_scores_at(ends, scores)?;
 
         // Work out only the number of positions used by the optimal paths
-        let mut optimal_positions = HashSet::new();
-        optimal.into_iter().for_each(|state|{ optimal_positions.insert(state.position); });
+        let optimal_positions = scores.states_on_paths_to(&end_states).into_iter()
+            .map(|state|state.position)
+            .collect::<HashSet<_>>();
         
         Ok(optimal_positions.len() as u64)
     }
-}
\ No newline at end of file
+}
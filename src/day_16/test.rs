@@ -1,11 +1,12 @@
 #[cfg(test)]
 pub mod suite {
-    use crate::{answer::DisplayableAnswer, day_16::make_pipeline, testing::test_whole_flow};
+    use crate::{answer::DisplayableAnswer, day_16::{make_pipeline, reindeer_maze_solver::ReindeerMazeSolver, reindeer_path_analyser::LowestScoreAnalyser}, solver::Solve, testing::{get_parsed_result_ok, get_verification_error, test_whole_flow}};
 
     
     const REL_FILEPATHS: &[&str] = &[
         "src/day_16/test/first_example.txt", // Example given on AOC24
         "src/day_16/test/second_example.txt", // Example given on AOC24
+        "src/day_16/test/no_end_example.txt", // Maze without an end
     ];
 
     #[test]
@@ -33,4 +34,18 @@ pub mod suite {
         test_whole_flow(&pipeline, REL_FILEPATHS[1], DisplayableAnswer::new(64));
 
     }
-}
\ No newline at end of file
+
+    #[test]
+    pub fn test_verifier_missing_end_error() {
+        let pipeline = make_pipeline(false).unwrap();
+        assert!(get_verification_error(&pipeline, REL_FILEPATHS[2]).starts_with("[Verifier D-16] expected exactly one End"));
+    }
+
+    #[test]
+    pub fn test_solver_missing_end_error() {
+        // The solver fails on an unverified maze, rather than panicking
+        let input = get_parsed_result_ok(&make_pipeline(false).unwrap(), REL_FILEPATHS[2]);
+        let error = ReindeerMazeSolver::new(LowestScoreAnalyser).solve(input).unwrap_err();
+        assert!(error.to_string().starts_with("[ReindeerPathAnalyser] end position is not reachable"), "{}", error);
+    }
+}
@@ -0,0 +1,10 @@
+use crate::{helper::grid::{single_field, Grid}, verifier::Verify};
+
+use super::model::Field;
+
+const PREFIX: &str = "[Verifier D-16]";
+
+/// Verifies the maze has exactly one start and one end
+pub fn maze_verifier() -> impl Verify<Grid<Field>> {
+    single_field(PREFIX, Field::Start).and_then(single_field(PREFIX, Field::End))
+}
@@ -1,36 +1,26 @@
 use model::ProgramInformation;
-use parser::ProgramInformationParser;
+use parser::program_information_parser;
 use program_copy_resolver::ProgramCopyResolver;
 use program_simulator::ProgramSimulator;
 
-use crate::{executer_manager::ExecuterManager, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, reading::SanitisedFileReader, solver::Solve, verifier::TrivialVerifier};
+use crate::{day::define_day, parameters::ParameterSpec, reading::SanitisedFileReader, solver::ParameterisedSolver, verifier::TrivialVerifier};
 
-mod model;
-mod parser;
-mod program_simulator;
-mod program_copy_resolver;
+pub mod model;
+pub mod parser;
+pub mod program_simulator;
+pub mod program_copy_resolver;
 mod test;
 
-
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<ProgramInformation>, String>
-where S: Solve<ProgramInformation> + 'static {
-    try_make_pipeline(
-        Ok(SanitisedFileReader::default()),
-        ProgramInformationParser::new(),
-        Ok(TrivialVerifier::new::<ProgramInformation>()),
-        Ok(solver)
-    )
-}
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<ProgramInformation>, String> {
-    match is_part_2 {
-        false => make_pipeline_with(ProgramSimulator::new(200)),
-        true  => make_pipeline_with(ProgramCopyResolver::new(200)),
-    }
-    
+define_day! {
+    number: 17,
+    title: "Chronospatial Computer",
+    input: ProgramInformation,
+    reader: Ok(SanitisedFileReader::default()),
+    parser: Ok(program_information_parser()),
+    verifier: Ok(TrivialVerifier::new::<ProgramInformation>()),
+    parameters: &[
+        ParameterSpec::unsigned("max_runtime", "200", "Maximal number of instructions a program may execute").at_most(usize::MAX as u64),
+    ],
+    part_1: Ok(ParameterisedSolver::new(|parameters|parameters.require("max_runtime").map(ProgramSimulator::new))),
+    part_2: Ok(ParameterisedSolver::new(|parameters|parameters.require("max_runtime").map(ProgramCopyResolver::new))),
 }
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(17, false, make_pipeline(false), false)
-        ?.try_register(17, true, make_pipeline(true), false)
-}
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{answer::{Answer, DisplayableAnswer}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, pipeline_error::PipelineError, solver::Solve};
 
 use super::{model::ProgramInformation, program_simulator::ProgramSimulator};
 
@@ -213,9 +213,9 @@ impl ProgramCopyResolver {
 }
 
 impl Solve<ProgramInformation> for ProgramCopyResolver {
-    fn solve(&self, input: ProgramInformation) -> Result<Answer, String> {
+    fn solve(&self, input: ProgramInformation) -> Result<Answer, PipelineError> {
         let mut program_info = match Self::satisfies_solving_assumptions(input) {
-            Err(e) => return Err(e),
+            Err(e) => return Err(e.into()),
             Ok(pi) => pi,
         };
 
@@ -233,7 +233,7 @@ impl Solve<ProgramInformation> for ProgramCopyResolver {
             program_info.register_a = candidate;
 
             let output = match self.simulator.run_program(&program_info) {
-                Err(e) => return Err(e),
+                Err(e) => return Err(e.into()),
                 Ok(out) => out,
             };
             
@@ -252,6 +252,6 @@ impl Solve<ProgramInformation> for ProgramCopyResolver {
             }
         }
 
-        solutions.into_iter().min().ok_or_else(error::no_solution).map(DisplayableAnswer::new)
+        solutions.into_iter().min().ok_or_else(error::no_solution).map(DisplayableAnswer::new).map_err(PipelineError::from)
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{answer::{Answer, DisplayableAnswer}, helper::display::vector_display, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::display::vector_display, pipeline_error::PipelineError, solver::Solve};
 
 use super::model::ProgramInformation;
 
@@ -153,10 +153,11 @@ impl ProgramSimulator {
 }
 
 impl Solve<ProgramInformation> for ProgramSimulator {
-    fn solve(&self, input: ProgramInformation) -> Result<Answer, String> {
+    fn solve(&self, input: ProgramInformation) -> Result<Answer, PipelineError> {
         self.run_program(&input)
             .map(|output|vector_display(&output, ","))
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
 
@@ -1,12 +1,28 @@
 #[cfg(test)]
 pub mod suite {
-    use crate::{answer::DisplayableAnswer, day_17::make_pipeline, helper::display::vector_display, testing::test_whole_flow};
+    use crate::{answer::DisplayableAnswer, day_17::{make_pipeline, model::ProgramInformation}, helper::display::vector_display, testing::{get_parsed_text_result, test_parsing, test_whole_flow}};
 
     const REL_FILEPATHS: &[&str] = &[
         "src/day_17/test/example.txt", // Example given on AOC24
         "src/day_17/test/example_part_two.txt", // Example given on AOC24
     ];
 
+    #[test]
+    pub fn test_parser() {
+        let expected = ProgramInformation { register_a: 729, register_b: 0, register_c: 0, program: vec![0, 1, 5, 4, 3, 0] };
+        test_parsing(&make_pipeline(false).unwrap(), REL_FILEPATHS[0], expected);
+    }
+
+    #[test]
+    pub fn test_parser_errors() {
+        let pipeline = make_pipeline(false).unwrap();
+        let error = get_parsed_text_result(&pipeline, "Register A: 1\nRegister C: 2\nRegister C: 3\nProgram: 0,1").unwrap_err();
+        assert_eq!((error.line_number(), error.column), (Some(2), Some(10)));
+        let error = get_parsed_text_result(&pipeline, "Register A: 1\nRegister B: 2\nRegister C: 3\nProgram: 0,1,").unwrap_err();
+        assert_eq!((error.line_number(), error.column), (Some(4), Some(13)));
+        assert!(get_parsed_text_result(&pipeline, "Register A: 1\nProgram: 0,1").unwrap_err().to_string().contains("exactly 4 lines"));
+    }
+
     #[test]
     pub fn test_whole_flow_part_1_first_example() {
         let output = vec![4,6,3,5,6,3,5,2,1,0];
@@ -3,34 +3,38 @@ use first_byte_blocker_finder::FirstByteBlockerFinder;
 use memory_space_path_finder::MemorySpacePathFinder;
 use model::FallingBytes;
 
-use crate::{executer_manager::ExecuterManager, helper::position::UPosition, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, reading::SanitisedFileReader, solver::Solve, verifier::TrivialVerifier};
+use crate::{day::define_day, helper::position::UPosition, parameters::{ParameterSpec, Parameters}, reading::SanitisedFileReader, solver::ParameterisedSolver, verifier::TrivialVerifier};
 
-mod model;
-mod falling_bytes_parser;
-mod memory_space_path_finder;
-mod first_byte_blocker_finder;
+pub mod model;
+pub mod falling_bytes_parser;
+pub mod memory_space_path_finder;
+pub mod first_byte_blocker_finder;
 mod test;
 
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<FallingBytes>, String>
-where S: Solve<FallingBytes> + 'static {
-    try_make_pipeline(
-        Ok(SanitisedFileReader::default()),
-        FallingBytesParser::new(),
-        Ok(TrivialVerifier::new::<FallingBytes>()),
-        Ok(solver)
-    )
-}
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<FallingBytes>, String> {
-    let bottom_right_corner = UPosition::new((70, 70));
-    
-    match is_part_2 {
-        false => make_pipeline_with(MemorySpacePathFinder::new(bottom_right_corner, 1024)),
-        true  => make_pipeline_with(FirstByteBlockerFinder::new(bottom_right_corner)),
+/// The memory space and the number of fallen bytes can be declared in the input file as `//! width=7 height=7 bytes=12`, or given as parameters
+fn bottom_right_corner(parameters: &Parameters) -> Result<UPosition, String> {
+    let width: usize = parameters.require("width")?;
+    let height: usize = parameters.require("height")?;
+    match (height.checked_sub(1), width.checked_sub(1)) {
+        (Some(row), Some(col)) => Ok(UPosition::new((row, col))),
+        _ => Err(format!("[Parameters D-18] the memory space must not be empty, but it is {}x{}", width, height)),
     }
 }
 
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(18, false, make_pipeline(false), false)
-        ?.try_register(18, true, make_pipeline(true), false)
-}
\ No newline at end of file
+define_day! {
+    number: 18,
+    title: "RAM Run",
+    input: FallingBytes,
+    reader: Ok(SanitisedFileReader::default()),
+    parser: FallingBytesParser::new(),
+    verifier: Ok(TrivialVerifier::new::<FallingBytes>()),
+    parameters: &[
+        ParameterSpec::unsigned("width", "71", "Width of the memory space").at_least(1).at_most(usize::MAX as u64),
+        ParameterSpec::unsigned("height", "71", "Height of the memory space").at_least(1).at_most(usize::MAX as u64),
+        ParameterSpec::unsigned("bytes", "1024", "Number of bytes fallen before searching the path in part 1").at_most(usize::MAX as u64),
+    ],
+    part_1: Ok(ParameterisedSolver::new(|parameters|
+        Ok(MemorySpacePathFinder::new(bottom_right_corner(parameters)?, parameters.require("bytes")?)))),
+    part_2: Ok(ParameterisedSolver::new(|parameters|
+        Ok(FirstByteBlockerFinder::new(bottom_right_corner(parameters)?)))),
+}
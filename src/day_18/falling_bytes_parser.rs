@@ -1,4 +1,4 @@
-use crate::{helper::result::{self, collect}, parser::Parse, reader::{Line, VecLine}};
+use crate::{helper::result::{self, collect}, parser::Parse, pipeline_error::PipelineError, reader::{Line, VecLine}};
 
 use super::model::{BytePosition, FallingBytes};
 
@@ -14,12 +14,12 @@ mod error {
         format!("{} failed to create regex for byte position due to {}", PREFIX, e)
     }
 
-    pub fn line_parse(line_num: usize) -> String {
-        format!("{} line #{} does not match regex", PREFIX, line_num)
+    pub fn line_parse() -> String {
+        format!("{} line does not match regex", PREFIX)
     }
 
-    pub fn number_parse(line_num: usize, name: &str, e: ParseIntError) -> String {
-        format!("{} line #{} could not parse number {} due to error: {}", PREFIX, line_num, name, e)
+    pub fn number_parse(name: &str, e: ParseIntError) -> String {
+        format!("{} could not parse number {} due to error: {}", PREFIX, name, e)
     }
 }
 
@@ -30,26 +30,26 @@ impl FallingBytesParser {
             .map(|re|FallingBytesParser { byte_position_re: re })
     }
 
-    fn parse_number(number: &str, line_num: usize, name: &str) -> Result<usize, String> {
-        number.parse().map_err(|e|error::number_parse(line_num, name, e))
+    fn parse_number(line: &Line, number: regex::Match, name: &str) -> Result<usize, PipelineError> {
+        number.as_str().parse().map_err(|e|PipelineError::at(line, line.column_at(number.start()), error::number_parse(name, e)))
     }
 
-    fn parse_line(&self, line: Line) -> Result<BytePosition, String> {
-        let (x, y) = match self.byte_position_re.captures(&line.text).map(|c|c.extract()) {
-            Some((_, [x, y])) => (x, y),
-            None => return Err(error::line_parse(line.number)),
+    fn parse_line(&self, line: Line) -> Result<BytePosition, PipelineError> {
+        let (x, y) = match self.byte_position_re.captures(&line.text).and_then(|c|c.get(1).zip(c.get(2))) {
+            Some((x, y)) => (x, y),
+            None => return Err(PipelineError::on_line(&line, error::line_parse())),
         };
 
         result::zip(
-            Self::parse_number(x, line.number, "X"),
-            Self::parse_number(y, line.number, "Y"),
+            Self::parse_number(&line, x, "X"),
+            Self::parse_number(&line, y, "Y"),
             BytePosition::new,
         )
     }
 }
 
 impl Parse<FallingBytes> for FallingBytesParser {
-    fn parse(&self, vec_line: VecLine) -> Result<FallingBytes, String> {
+    fn parse(&self, vec_line: VecLine) -> Result<FallingBytes, PipelineError> {
         collect(vec_line.lines.into_iter().map(|line|self.parse_line(line)).collect()).map(FallingBytes)
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{answer::{Answer, DisplayableAnswer}, day_18::memory_space_path_finder::MemorySpacePathFinder, helper::position::UPosition, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, day_18::memory_space_path_finder::MemorySpacePathFinder, helper::position::UPosition, pipeline_error::PipelineError, solver::Solve};
 
 use super::model::FallingBytes;
 
@@ -22,7 +22,7 @@ impl FirstByteBlockerFinder {
 }
 
 impl Solve<FallingBytes> for FirstByteBlockerFinder {
-    fn solve(&self, input: FallingBytes) -> Result<Answer, String> {
+    fn solve(&self, input: FallingBytes) -> Result<Answer, PipelineError> {
         let FallingBytes(bytes) = &input;
         let start = UPosition::new((0, 0));
         let mut left = 0;
@@ -35,12 +35,7 @@ impl Solve<FallingBytes> for FirstByteBlockerFinder {
         while left + 1 < right {
             let mid = (left + right) / 2;
             let solver = MemorySpacePathFinder::new(self.bottom_right_corner, mid);
-            let steps_mapping = match solver.run_bfs(start, &input) {
-                Ok(mapping) => mapping,
-                Err(e) => return Err(e),
-            };
-
-            if steps_mapping.contains_key(&self.bottom_right_corner) {
+            if solver.find_path(start, &input)?.is_some() {
                 left = mid;
             } else {
                 right = mid;
@@ -50,5 +45,6 @@ impl Solve<FallingBytes> for FirstByteBlockerFinder {
         bytes.get(right - 1)
             .ok_or_else(error::no_blocking_byte)
             .map(|&blocking_byte|DisplayableAnswer::new(blocking_byte))
+            .map_err(PipelineError::from)
     }
 }
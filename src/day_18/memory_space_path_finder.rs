@@ -1,8 +1,8 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::HashSet;
 
-use crate::{answer::{Answer, DisplayableAnswer}, helper::{movement::{self}, position::UPosition}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::{geometry::Point, movement::{self}, position::UPosition, search::{self, Path}}, pipeline_error::PipelineError, solver::Solve};
 
-use super::model::{BytePosition, FallingBytes};
+use super::model::FallingBytes;
 
 mod error {
     use crate::{day_18::model::BytePosition, helper::position::UPosition};
@@ -26,13 +26,6 @@ pub struct MemorySpacePathFinder {
     simulate_first: usize,
 }
 
-/// State used for Breadth-First-Search
-#[derive(Clone, Copy)]
-struct State {
-    pos: UPosition,
-    steps: usize,
-}
-
 impl MemorySpacePathFinder {
     pub fn new(bottom_right_corner: UPosition, simulate_first: usize) -> MemorySpacePathFinder {
         MemorySpacePathFinder { bottom_right_corner, simulate_first }
@@ -49,8 +42,7 @@ impl MemorySpacePathFinder {
         let FallingBytes(bytes) = input;
         let mut corrupted = HashSet::new();
         for i in 0..self.simulate_first {
-            let BytePosition { x, y } = bytes[i];
-            let position = UPosition { row: y, col: x };
+            let position = UPosition::from(bytes[i]);
             if !self.contains(position) {
                 return Err(error::byte_out_of_bounds(i, bytes[i], self.bottom_right_corner));
             }
@@ -59,24 +51,17 @@ impl MemorySpacePathFinder {
         Ok(corrupted)
     }
 
-    fn next_states(&self, state: State, corrupted: &HashSet<UPosition>) -> Vec<State> {
-        let State { pos, steps } = state;
-        let mut next = vec![];
-        for movement in movement::unit::all_partial() {
-            let next_position = movement.apply(pos);
-            if next_position.is_none() { continue; }
-
-            let next_position = next_position.unwrap();
-            if self.contains(next_position) && !corrupted.contains(&next_position) {
-                next.push(State { pos: next_position, steps: steps + 1 });
-            }
-        }
-        next
+    fn next_positions(&self, pos: UPosition, corrupted: &HashSet<UPosition>) -> Vec<(UPosition, u64)> {
+        movement::unit::all_partial().into_iter()
+            .filter_map(|movement|movement.apply(pos))
+            .filter(|&next_position|self.contains(next_position) && !corrupted.contains(&next_position))
+            .map(|next_position|(next_position, 1))
+            .collect()
     }
 
-    /// Runs breadth first search. Returns mapping of the form: Position -> Number of steps to reach it.
-    /// If the position is not included, it is not reachable
-    pub fn run_bfs(&self, start_position: UPosition, input: &FallingBytes) -> Result<HashMap<UPosition, usize>, String> {
+    /// Finds a shortest path from the start position to the bottom right corner using A* search
+    /// with the Manhattan distance as the heuristic. Returns `None` if the corner is not reachable.
+    pub fn find_path(&self, start_position: UPosition, input: &FallingBytes) -> Result<Option<Path<UPosition>>, String> {
 
         // check that memory map contains start position
         if !self.contains(start_position) {
@@ -84,44 +69,22 @@ impl MemorySpacePathFinder {
         }
 
         // try create map of corrupted memory bytes
-        let corrupted_map = match self.initialise_corrupted_byte_map(input) {
-            Ok(corrupted_map) => corrupted_map,
-            Err(e) => return Err(e),
-        };
-        
-        // initialise start position
-        let mut steps_mapping = HashMap::new();
-        let mut queue = VecDeque::new();
-        if !corrupted_map.contains(&start_position) {
-            let starting_state = State { pos: start_position, steps: 0 };
-            queue.push_front(starting_state);
-            steps_mapping.insert(start_position, starting_state.steps);
+        let corrupted_map = self.initialise_corrupted_byte_map(input)?;
+        if corrupted_map.contains(&start_position) {
+            return Ok(None);
         }
 
-        // do BFS
-        while !queue.is_empty() {
-            let current = queue.pop_front().unwrap();
-            for next_state in self.next_states(current, &corrupted_map) {
-                if !steps_mapping.contains_key(&next_state.pos) {
-                    steps_mapping.insert(next_state.pos, next_state.steps);
-                    queue.push_back(next_state);
-                }
-            }
-        }
-        
-        Ok(steps_mapping)
+        let goal = self.bottom_right_corner;
+        let manhattan = |pos: UPosition|Point::from(pos).manhattan(Point::from(goal)) as u64;
+        Ok(search::a_star(start_position, |pos|self.next_positions(pos, &corrupted_map), manhattan, |pos|pos == goal))
     }
 }
 
 impl Solve<FallingBytes> for MemorySpacePathFinder {
-    fn solve(&self, input: FallingBytes) -> Result<Answer, String> {
-        let step_mapping = match self.run_bfs(UPosition::new((0, 0)), &input) {
-            Ok(step_mapping) => step_mapping,
-            Err(e) => return Err(e),
-        };
-
-        step_mapping.get(&self.bottom_right_corner)
-            .map(|&steps|DisplayableAnswer::new(steps))
+    fn solve(&self, input: FallingBytes) -> Result<Answer, PipelineError> {
+        self.find_path(UPosition::new((0, 0)), &input)?
+            .map(|path|DisplayableAnswer::new(path.cost))
             .ok_or_else(||error::unreachable(self.bottom_right_corner))
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
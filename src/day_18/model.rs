@@ -1,20 +1,9 @@
 use std::fmt::Display;
 
-use crate::helper::display::vector_display;
+use crate::helper::{display::vector_display, geometry::Point};
 
 
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
-pub struct BytePosition { pub x: usize, pub y: usize }
-
-impl BytePosition {
-    pub fn new(x: usize, y: usize) -> BytePosition { BytePosition { x, y } }
-}
-
-impl Display for BytePosition {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "(X:{},Y:{})", self.x, self.y)
-    }
-}
+pub type BytePosition = Point<usize>;
 
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub struct FallingBytes(pub Vec<BytePosition>);
@@ -23,4 +23,12 @@ pub mod suite {
         assert!(solution.is_ok());
         assert_eq!(solution.unwrap().report(), DisplayableAnswer::new(BytePosition::new(6, 1)).report());
     }
+
+    #[test]
+    pub fn test_whole_flow_with_declared_memory_space() {
+        let pipeline = make_pipeline(false).unwrap();
+        testing::test_whole_flow(&pipeline, REL_FILEPATHS[0], DisplayableAnswer::new(22));
+        let pipeline = make_pipeline(true).unwrap();
+        testing::test_whole_flow(&pipeline, REL_FILEPATHS[0], DisplayableAnswer::new(BytePosition::new(6, 1)));
+    }
 }
\ No newline at end of file
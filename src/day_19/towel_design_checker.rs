@@ -1,6 +1,6 @@
 use std::{cmp::Ordering, collections::{BinaryHeap, HashMap, HashSet}};
 
-use crate::{answer::{Answer, DisplayableAnswer}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, pipeline_error::PipelineError, solver::Solve};
 
 use super::model::TowelPatternsAndDesigns;
 
@@ -114,12 +114,12 @@ impl <I: Interpret> TowelDesignChecker<I> {
 }
 
 impl <I: Interpret> Solve<TowelPatternsAndDesigns> for TowelDesignChecker<I> {
-    fn solve(&self, input: TowelPatternsAndDesigns) -> Result<Answer, String> {
+    fn solve(&self, input: TowelPatternsAndDesigns) -> Result<Answer, PipelineError> {
         let pattern_set = Self::make_pattern_set(&input);
         let mut result_sum: u64 = 0;
         for design in input.designs {
             let suffix_visit_count = match Self::analyse_design(&design, &pattern_set) {
-                Err(e) => return Err(e),
+                Err(e) => return Err(e.into()),
                 Ok(analysis_result) => analysis_result,
             };
 
@@ -127,14 +127,14 @@ impl <I: Interpret> Solve<TowelPatternsAndDesigns> for TowelDesignChecker<I> {
             
             match result_sum.checked_add(design_result) {
                 Some(value) => result_sum = value,
-                None => return Err(error::overflow(result_sum, design_result, "resulting sum calculation")),
+                None => return Err(error::overflow(result_sum, design_result, "resulting sum calculation").into()),
             };
         }
         Ok(DisplayableAnswer::new(result_sum))
     }
 }
 
-pub trait Interpret {
+pub trait Interpret: Send + Sync {
     fn interpret(&self, suffix_visit_count: HashMap<String, u64>) -> u64;
 }
 
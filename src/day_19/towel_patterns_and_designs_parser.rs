@@ -1,5 +1,5 @@
 
-use crate::{parser::Parse, reader::Line};
+use crate::{parser::Parse, pipeline_error::PipelineError, reader::Line};
 
 use super::model::TowelPatternsAndDesigns;
 
@@ -14,8 +14,8 @@ mod error {
         format!("{} invalid towel pattern at index {}: '{}'", PREFIX, index, towel)
     }
 
-    pub fn invalid_towel_design(line_num: usize, towel: &String) -> String {
-        format!("{} invalid towel design on line #{}: '{}'", PREFIX, line_num, towel)
+    pub fn invalid_towel_design(towel: &String) -> String {
+        format!("{} invalid towel design: '{}'", PREFIX, towel)
     }
 }
 
@@ -30,41 +30,39 @@ impl TowelPatternsAndDesignsParser {
             .map(|re|TowelPatternsAndDesignsParser { towel_re: re })
     }
 
-    fn parse_patterns(&self, line: Line) -> Result<Vec<String>, String> {
+    fn parse_patterns(&self, line: Line) -> Result<Vec<String>, PipelineError> {
         let mut parsed_patterns = vec![];
-        for (index, pattern) in line.text.split(",").map(|bit|bit.trim()).enumerate() {
+        let mut offset = 0;
+        for (index, bit) in line.text.split(",").enumerate() {
+            let pattern = bit.trim();
             if !self.towel_re.is_match(pattern) {
-                return Err(error::invalid_towel_pattern(index, pattern))
+                let column = line.column_at(offset + bit.len() - bit.trim_start().len());
+                return Err(PipelineError::at(&line, column, error::invalid_towel_pattern(index, pattern)))
             }
             parsed_patterns.push(pattern.to_string());
+            offset += bit.len() + 1;
         }
         Ok(parsed_patterns)
     }
 
-    fn parse_design(&self, line: Line) -> Result<String, String> {
+    fn parse_design(&self, line: Line) -> Result<String, PipelineError> {
         if self.towel_re.is_match(&line.text) {
             Ok(line.text)
         } else {
-            Err(error::invalid_towel_design(line.number, &line.text))
+            Err(PipelineError::on_line(&line, error::invalid_towel_design(&line.text)))
         }
     }
 }
 
 impl Parse<TowelPatternsAndDesigns> for TowelPatternsAndDesignsParser {
-    fn parse(&self, vec_line: crate::reader::VecLine) -> Result<TowelPatternsAndDesigns, String> {
+    fn parse(&self, vec_line: crate::reader::VecLine) -> Result<TowelPatternsAndDesigns, PipelineError> {
         let mut patterns = vec![];
         let mut designs = vec![];
         for (i, line) in vec_line.lines.into_iter().enumerate() {
             if i == 0 {
-                patterns = match self.parse_patterns(line) {
-                    Ok(patterns) => patterns,
-                    Err(e) => return Err(e),    
-                };
+                patterns = self.parse_patterns(line)?;
             } else {
-                match self.parse_design(line) {
-                    Ok(design) => designs.push(design),
-                    Err(e) => return Err(e),
-                };
+                designs.push(self.parse_design(line)?);
             }
         }
 
@@ -1,13 +1,15 @@
 use cheats_counter::CheatsCounter;
 use model::Field;
-use parser::RacetrackParser;
+use parser::racetrack_parser;
+use verifier::racetrack_verifier;
 
-use crate::{executer_manager::ExecuterManager, helper::table::Table, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, reading::{SanitisedFileReader, SimpleFileReader}, solver::Solve, verifier::TrivialVerifier};
+use crate::{day::define_day, helper::grid::Grid, parameters::ParameterSpec, reading::{SanitisedFileReader, SimpleFileReader}, solver::ParameterisedSolver};
 
-mod parser;
-mod model;
+pub mod parser;
+pub mod verifier;
+pub mod model;
 mod test;
-mod cheats_counter;
+pub mod cheats_counter;
 
 fn reader() -> SanitisedFileReader {
     use crate::reading::settings::*;
@@ -20,24 +22,21 @@ fn reader() -> SanitisedFileReader {
     )
 }
 
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<Table<Field>>, String>
-where S: Solve<Table<Field>> + 'static {
-    try_make_pipeline(
-        Ok(reader()),
-        Ok(RacetrackParser::new()),
-        Ok(TrivialVerifier::new::<Table<Field>>()),
-        Ok(solver)
-    )
+/// The minimal time a cheat has to save can be declared in the input file as `//! cutoff=50`, or given as parameter
+fn cheats_counter(cheat_time: usize) -> ParameterisedSolver<Grid<Field>> {
+    ParameterisedSolver::new(move |parameters|Ok(CheatsCounter::new(cheat_time, parameters.require("cutoff")?)))
 }
 
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<Table<Field>>, String> {
-    match is_part_2 {
-        false => make_pipeline_with(CheatsCounter::new(2, 100)),
-        true => make_pipeline_with(CheatsCounter::new(20, 100)),
-    }
+define_day! {
+    number: 20,
+    title: "Race Condition",
+    input: Grid<Field>,
+    reader: Ok(reader()),
+    parser: Ok(racetrack_parser()),
+    verifier: Ok(racetrack_verifier()),
+    parameters: &[
+        ParameterSpec::unsigned("cutoff", "100", "Minimal number of picoseconds a cheat has to save"),
+    ],
+    part_1: Ok(cheats_counter(2)),
+    part_2: Ok(cheats_counter(20)),
 }
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(20, false, make_pipeline(false), false)
-        ?.try_register(20, true, make_pipeline(true), false)
-}
\ No newline at end of file
@@ -1,85 +1,34 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 
-use crate::{answer::{Answer, DisplayableAnswer}, helper::{movement::{unit, Movement}, position::UPosition, table::Table}, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, helper::{movement::{unit, Movement}, grid::Grid, position::UPosition, search, table::Table}, pipeline_error::PipelineError, solver::Solve};
 
 use super::model::Field;
 
-
 pub struct CheatsCounter {
     cheat_time: usize,
     cheat_cutoff: u64,
 }
 
-mod error {
-    use crate::helper::{display::vector_display, position::UPosition};
-
-    const PREFIX: &str = "[Solver D-20]";
-
-    pub fn not_exactly_one(c: char, name: &str, count: usize) -> String {
-        vector_display(&vec![
-            format!("{} The input map has {} {}s (character: {}).", PREFIX, count, name, c),
-            format!("Exactly one is expected."),
-        ], " ")
-    }
-
-    pub fn unreachable(start: UPosition, end: UPosition) -> String {
-        format!("{} end position {} not reachable from start {}", PREFIX, end, start)
-    }
-}
-
 impl CheatsCounter {
     pub fn new(cheat_time: usize, cheat_cutoff: u64,) -> CheatsCounter {
         CheatsCounter { cheat_cutoff, cheat_time }
     }
 
     /// Process the input table / map. Returns in a tuple, in order:
-    /// 1. A hashset of all walkable positions (i.e. non-wall positions)
-    /// 2. Initial position.
-    /// 3. An end position that needs to be reached.
-    fn process_input_map(input: Table<Field>) -> Result<(HashSet<UPosition>, UPosition, UPosition), String> {
-        let mut racetrack = HashSet::new();
-        let mut starts = vec![];
-        let mut ends = vec![];
-
-        for (position, &field) in input.iter() {
-            if field != Field::Wall { racetrack.insert(position); }
-            if field == Field::Start { starts.push(position); }
-            else if field == Field::End { ends.push(position); }
-        }
-
-        if starts.len() != 1 {
-            return Err(error::not_exactly_one('S', "starting position", starts.len()));
-        }
-        
-        if ends.len() != 1 {
-            return Err(error::not_exactly_one('E', "end position", ends.len()));
-        }
-
-        Ok((racetrack, starts[0], ends[0]))
+    /// 1. A table of whether each position is walkable (i.e. a non-wall position)
+    /// 2. End positions that need to be reached.
+    ///
+    /// The verifier ensures there is exactly one start and one end.
+    fn process_input_map(input: Grid<Field>) -> (Table<bool>, Vec<UPosition>) {
+        let racetrack = input.table.map(|&field|field != Field::Wall);
+        let ends = input.positions(Field::End).to_vec();
+        (racetrack, ends)
     }
 
-    /// Returns a mapping: position => distance from goal
-    fn do_bfs(racetrack: &HashSet<UPosition>, goal: UPosition) -> HashMap<UPosition, u64> {
-        let mut distances = HashMap::new();
-        let mut queue = VecDeque::new();
-        if racetrack.contains(&goal) {
-            queue.push_front(goal);
-            distances.insert(goal, 0);
-        }
-
-        while !queue.is_empty() {
-            let current_pos = queue.pop_front().unwrap();
-            let distance = *distances.get(&current_pos).unwrap();
-            for movement in unit::all_partial() {
-                let next = movement.apply(current_pos);
-                if next.is_some() && racetrack.contains(&next.unwrap()) && !distances.contains_key(&next.unwrap()) {
-                    distances.insert(next.unwrap(), distance + 1);
-                    queue.push_back(next.unwrap());
-                }
-            }
-        }
-
-        distances
+    /// Returns a mapping: position => distance from the closest goal
+    fn do_bfs(racetrack: &Table<bool>, goals: Vec<UPosition>) -> HashMap<UPosition, u64> {
+        search::bfs(goals, |position|racetrack.neighbours(position).filter(|&next|racetrack.get_pos(next) == Some(&true)))
+            .distances
     }
 
     fn cheat_movements(&self) -> Vec<Movement> {
@@ -132,18 +81,10 @@ impl CheatsCounter {
 
 }
 
-impl Solve<Table<Field>> for CheatsCounter {
-    fn solve(&self, input: Table<Field>) -> Result<Answer, String> {
-        let (racetrack, start, end) = match Self::process_input_map(input) {
-            Ok((r, s, e)) => (r,s,e),
-            Err(e) => return Err(e),
-        };
-        
-        let distances = Self::do_bfs(&racetrack, end);
-        if !distances.contains_key(&start) {
-            return Err(error::unreachable(start, end));
-        }
-
+impl Solve<Grid<Field>> for CheatsCounter {
+    fn solve(&self, input: Grid<Field>) -> Result<Answer, PipelineError> {
+        let (racetrack, ends) = Self::process_input_map(input);
+        let distances = Self::do_bfs(&racetrack, ends);
         Ok(DisplayableAnswer::new(self.count_cheats(&distances)))
     }
 }
\ No newline at end of file
@@ -1,9 +1,10 @@
 #[cfg(test)]
 pub mod suite {
-    use crate::{answer::DisplayableAnswer, day_20::{cheats_counter::CheatsCounter, make_pipeline}, solver::Solve, testing::{self}};
+    use crate::{answer::DisplayableAnswer, day_20::{cheats_counter::CheatsCounter, make_pipeline}, executer::{Execute, Stage}, solver::Solve, testing::{self, get_verification_error}};
 
     const REL_FILEPATHS: &[&str] = &[
         "src/day_20/test/example.txt", // Example given on AOC24
+        "src/day_20/test/unreachable_example.txt", // End walled off from the start
     ];
 
     #[test]
@@ -23,4 +24,22 @@ pub mod suite {
         assert!(answer.is_ok());
         assert_eq!(answer.unwrap().report(), DisplayableAnswer::new(32 + 31 + 29 + 39 + 25 + 23 + 20 + 19 + 12 + 14 + 12 + 22 + 4 + 3).report());
     }
+
+    #[test]
+    pub fn test_whole_flow_with_declared_cutoff() {
+        let pipeline = make_pipeline(false).unwrap();
+        testing::test_whole_flow(&pipeline, REL_FILEPATHS[0], DisplayableAnswer::new(1));
+        let pipeline = make_pipeline(true).unwrap();
+        testing::test_whole_flow(&pipeline, REL_FILEPATHS[0], DisplayableAnswer::new(285));
+    }
+
+    #[test]
+    pub fn test_verifier_unreachable_error() {
+        let pipeline = make_pipeline(false).unwrap();
+        assert!(get_verification_error(&pipeline, REL_FILEPATHS[1]).contains("not reachable"));
+
+        // The error points at the end
+        let error = pipeline.execute(&testing::resolve_filepath(REL_FILEPATHS[1])).unwrap_err();
+        assert_eq!((error.stage, error.line_number(), error.column), (Some(Stage::Verify), Some(2), Some(4)));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,31 @@
+use crate::{helper::{grid::{single_field, Grid}, search}, pipeline_error::PipelineError, verifier::{check, Verify}};
+
+use super::model::Field;
+
+mod error {
+    use crate::helper::position::UPosition;
+
+    pub const PREFIX: &str = "[Verifier D-20]";
+
+    pub fn unreachable(start: UPosition, end: UPosition) -> String {
+        format!("{} end position {} not reachable from start {}", PREFIX, end, start)
+    }
+}
+
+/// Checks the end can be reached from the start, moving only through the non-wall positions
+fn end_reachable(racetrack: &Grid<Field>) -> Result<(), PipelineError> {
+    let (start, end) = (racetrack.single(Field::Start)?, racetrack.single(Field::End)?);
+    let walkable = racetrack.table.map(|&field|field != Field::Wall);
+    let search = search::bfs([start], |position|walkable.neighbours(position).filter(|&next|walkable.get_pos(next) == Some(&true)));
+    match search.distances.contains_key(&end) {
+        true => Ok(()),
+        false => Err(racetrack.error_at(end, error::unreachable(start, end))),
+    }
+}
+
+/// Verifies the racetrack has exactly one start and one end, and the end is reachable from the start
+pub fn racetrack_verifier() -> impl Verify<Grid<Field>> {
+    single_field(error::PREFIX, Field::Start)
+        .and_then(single_field(error::PREFIX, Field::End))
+        .and_then(check(end_reachable))
+}
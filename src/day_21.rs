@@ -1,34 +1,27 @@
 use code_parser::CodeParser;
 use model::Codes;
 
-use crate::{day_21::keypad_complexity_calculator::KeypadComplexityCalculator, executer_manager::ExecuterManager, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, reading::SanitisedFileReader, solver::Solve, verifier::TrivialVerifier};
+use crate::{day::define_day, day_21::keypad_complexity_calculator::KeypadComplexityCalculator, parameters::ParameterSpec, reading::SanitisedFileReader, solver::ParameterisedSolver, verifier::TrivialVerifier};
 
-mod model;
-mod code_parser;
-mod keypad;
-mod ordered_movement;
-mod caching;
-mod keypad_complexity_calculator;
+pub mod model;
+pub mod code_parser;
+pub mod keypad;
+pub mod ordered_movement;
+pub mod caching;
+pub mod keypad_complexity_calculator;
 mod test;
 
-fn make_pipeline_with<S>(solver: Result<S, String>) -> Result<PipelinedExecuter<Codes>, String>
-where S: Solve<Codes> + 'static {
-    try_make_pipeline(
-        Ok(SanitisedFileReader::default()),
-        CodeParser::new(),
-        Ok(TrivialVerifier::new::<Codes>()),
-        solver
-    )
+define_day! {
+    number: 21,
+    title: "Keypad Conundrum",
+    input: Codes,
+    reader: Ok(SanitisedFileReader::default()),
+    parser: CodeParser::new(),
+    verifier: Ok(TrivialVerifier::new::<Codes>()),
+    parameters: &[
+        ParameterSpec::unsigned("chain_part_1", "2", "Number of robots on directional keypads in part 1").at_most(usize::MAX as u64),
+        ParameterSpec::unsigned("chain_part_2", "25", "Number of robots on directional keypads in part 2").at_most(usize::MAX as u64),
+    ],
+    part_1: Ok(ParameterisedSolver::new(|parameters|parameters.require("chain_part_1").and_then(|chain|KeypadComplexityCalculator::new(6, chain)))),
+    part_2: Ok(ParameterisedSolver::new(|parameters|parameters.require("chain_part_2").and_then(|chain|KeypadComplexityCalculator::new(6, chain)))),
 }
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<Codes>, String> {
-    match is_part_2 {
-        false => make_pipeline_with(KeypadComplexityCalculator::new(6, 2)),
-        true => make_pipeline_with(KeypadComplexityCalculator::new(6, 25)),
-    }
-}
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(21, false, make_pipeline(false), false)
-        ?.try_register(21, true, make_pipeline(true), false)
-}
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{helper::result::collect, parser::Parse, reader::{Line, VecLine}};
+use crate::{helper::result::collect, parser::Parse, pipeline_error::PipelineError, reader::{Line, VecLine}};
 
 use super::model::Codes;
 
@@ -14,8 +14,8 @@ mod error {
         format!("{} could not create regex for {} due to: {}", PREFIX, purpose, e)
     }
 
-    pub fn parse_line(line_num: usize) -> String {
-        format!("{} could not parse line #{}", PREFIX, line_num)
+    pub fn parse_line() -> String {
+        format!("{} could not parse line", PREFIX)
     }
 }
 
@@ -26,16 +26,16 @@ impl CodeParser {
             .map(|re|CodeParser { code_re: re })
     }
 
-    fn parse_line(&self, line: &Line) -> Result<String, String> {
+    fn parse_line(&self, line: &Line) -> Result<String, PipelineError> {
         match self.code_re.captures(&line.text).map(|c|c.extract()) {
             Some((_, [code])) => Ok(code.to_string()),
-            None => Err(error::parse_line(line.number)),
+            None => Err(PipelineError::on_line(line, error::parse_line())),
         }
     }
 }
 
 impl Parse<Codes> for CodeParser {
-    fn parse(&self, vec_line: VecLine) -> Result<Codes, String> {
+    fn parse(&self, vec_line: VecLine) -> Result<Codes, PipelineError> {
         collect(vec_line.lines.iter().map(|line|self.parse_line(line)).collect())
             .map(Codes)
     }
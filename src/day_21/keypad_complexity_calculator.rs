@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::{answer::{Answer, DisplayableAnswer}, day_21::{caching::DirectionalKeypadChainCacher, keypad::{numerical_keypad, Keypad}, model::Codes, ordered_movement::OrderedMovement}, helper, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, day_21::{caching::DirectionalKeypadChainCacher, keypad::{numerical_keypad, Keypad}, model::Codes, ordered_movement::OrderedMovement}, helper, pipeline_error::PipelineError, solver::Solve};
 
 mod error {
     const PREFIX : &str = "[D-21 Keypad complexity calculator]";
@@ -73,15 +73,16 @@ impl KeypadComplexityCalculator {
 
 
 impl Solve<Codes> for KeypadComplexityCalculator {
-    fn solve(&self, input: Codes) -> Result<Answer, String> {
+    fn solve(&self, input: Codes) -> Result<Answer, PipelineError> {
         let cache = match DirectionalKeypadChainCacher::new(self.scope).make_caches(self.directional_chain_length) {
             Ok(cache) => cache,
-            Err(message) => return Err(message),
+            Err(message) => return Err(message.into()),
         };
 
         let Codes(codes) = input;
         helper::result::collect(codes.into_iter().map(|code|self.determine_complexity(code, &cache)).collect())
             .and_then(Self::sum_complexities)
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
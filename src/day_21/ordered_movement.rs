@@ -29,14 +29,14 @@ impl OrderedMovement {
     }
 
     /// Transform ordered movement into sequence of directional buttons
-    pub fn into_buttons(&self) -> Vec<char> {
+    pub fn into_buttons(self) -> Vec<char> {
         // determine the buttons & click counts
         let (
             first_click_count,
             first_button,
             second_click_count,
             second_button
-        ) = match *self {
+        ) = match self {
             OrderedMovement::RowCol(row, col) => (
                 row.get_absolute_change(), 
                 Self::map_delta(row, 'v', '^'),
@@ -65,7 +65,7 @@ impl OrderedMovement {
     }
 
     /// Transform ordered movement into sequence of unit movements
-    pub fn into_unit_movements(&self) -> Vec<Movement> {
+    pub fn into_unit_movements(self) -> Vec<Movement> {
         self.into_buttons().into_iter().map(|button|match button {
             '>' => movement::unit::RIGHT,
             '<' => movement::unit::LEFT,
@@ -1,23 +1,38 @@
 #[cfg(test)]
 pub mod suite {
-    use crate::{answer::DisplayableAnswer, day_21::{make_pipeline, model::Codes}, solver::Solve, testing::{self}};
+    use crate::{answer::DisplayableAnswer, day_21::{make_pipeline, model::Codes, PARAMETERS}, parameters::Parameters, pipelined_executer::PipelinedExecuter, solver::Solve, testing::{self}};
 
     const REL_FILEPATHS: &[&str] = &[
         "src/day_21/test/example.txt", // Example given on AOC24
     ];
 
+    fn pipeline_with(is_part_2: bool, overrides: &Parameters) -> PipelinedExecuter<Codes> {
+        make_pipeline(is_part_2).unwrap().with_parameters(PARAMETERS, overrides.clone())
+    }
+
+    fn pipeline(is_part_2: bool) -> PipelinedExecuter<Codes> {
+        make_pipeline(is_part_2).unwrap()
+    }
+
     #[test]
     pub fn test_whole_flow_part_1_example() {
-        let pipeline = make_pipeline(false).unwrap();
+        let pipeline = pipeline(false);
         testing::test_whole_flow(&pipeline, &REL_FILEPATHS[0], DisplayableAnswer::new(126_384));
     }
 
     #[test]
     pub fn test_individual_codes_part_1_example() {
-        let pipeline = make_pipeline(false).unwrap();
+        let pipeline = pipeline(false);
         let Codes(codes) = testing::get_parsed_result_ok(&pipeline, &REL_FILEPATHS[0]);
         let result = pipeline.solve(Codes(vec![codes[0].clone()]));
         assert!(result.is_ok());
         assert_eq!(result.unwrap().report(), DisplayableAnswer::new(68 * 29).report())
     }
+
+    #[test]
+    pub fn test_whole_flow_part_2_with_part_1_chain() {
+        let mut overrides = Parameters::new();
+        overrides.insert("chain_part_2", "2");
+        testing::test_whole_flow(&pipeline_with(true, &overrides), REL_FILEPATHS[0], DisplayableAnswer::new(126_384));
+    }
 }
\ No newline at end of file
@@ -1,31 +1,22 @@
-use crate::{day_22::{model::Numbers, numbers_parser::NumbersParser, optimal_change_sequence_finder::OptimalChangeSequenceFinder, secret_number_examiner::SecretNumberExaminer}, executer_manager::ExecuterManager, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, reading::SanitisedFileReader, solver::Solve, verifier::TrivialVerifier};
+use crate::{day::define_day, day_22::{model::Numbers, numbers_parser::NumbersParser, optimal_change_sequence_finder::OptimalChangeSequenceFinder, secret_number_examiner::SecretNumberExaminer}, parameters::ParameterSpec, reading::SanitisedFileReader, solver::ParameterisedSolver, verifier::TrivialVerifier};
 
-mod model;
-mod numbers_parser;
-mod secret_number_transform;
-mod secret_number_examiner;
-mod optimal_change_sequence_finder;
+pub mod model;
+pub mod numbers_parser;
+pub mod secret_number_transform;
+pub mod secret_number_examiner;
+pub mod optimal_change_sequence_finder;
 mod test;
 
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<Numbers>, String>
-where S: Solve<Numbers> + 'static {
-    try_make_pipeline(
-        Ok(SanitisedFileReader::default()),
-        NumbersParser::new(),
-        Ok(TrivialVerifier::new::<Numbers>()),
-        Ok(solver)
-    )
+define_day! {
+    number: 22,
+    title: "Monkey Market",
+    input: Numbers,
+    reader: Ok(SanitisedFileReader::default()),
+    parser: NumbersParser::new(),
+    verifier: Ok(TrivialVerifier::new::<Numbers>()),
+    parameters: &[
+        ParameterSpec::unsigned("cutoff", "2000", "Number of secret numbers each buyer generates").at_most(usize::MAX as u64),
+    ],
+    part_1: Ok(ParameterisedSolver::new(|parameters|parameters.require("cutoff").map(SecretNumberExaminer::new))),
+    part_2: Ok(ParameterisedSolver::new(|parameters|parameters.require("cutoff").map(|cutoff|OptimalChangeSequenceFinder::new(cutoff, 4)))),
 }
-
-fn make_pipeline(is_part_2: bool) -> Result<PipelinedExecuter<Numbers>, String> {
-    match is_part_2 {
-        false => make_pipeline_with(SecretNumberExaminer::new(2_000)),
-        true => make_pipeline_with(OptimalChangeSequenceFinder::new(2_000, 4)),
-    }
-}
-
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(22, false, make_pipeline(false), false)
-        ?.try_register(22, true, make_pipeline(true), false)
-}
\ No newline at end of file
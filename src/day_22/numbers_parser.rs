@@ -1,4 +1,4 @@
-use crate::{day_22::model::Numbers, helper::result::collect, parser::Parse, reader::{Line, VecLine}};
+use crate::{day_22::model::Numbers, helper::result::collect, parser::Parse, pipeline_error::PipelineError, reader::{Line, VecLine}};
 
 
 mod error {
@@ -10,12 +10,12 @@ mod error {
         format!("{} could not create parser due to regex error: {}", PREFIX, err)
     }
 
-    pub fn line_match(line_num: usize) -> String {
-        format!("{} line #{} does not match regex", PREFIX, line_num)
+    pub fn line_match() -> String {
+        format!("{} line does not match regex", PREFIX)
     }
 
-    pub fn parse(err: ParseIntError, number: &str, line_num: usize) -> String {
-        format!("{} line #{} failed to parse number '{}' due to parsing error {}", PREFIX, line_num, number, err)
+    pub fn parse(err: ParseIntError, number: &str) -> String {
+        format!("{} failed to parse number '{}' due to parsing error {}", PREFIX, number, err)
     }
 }
 
@@ -30,17 +30,17 @@ impl NumbersParser {
             .map_err(error::number_regex)
     }
 
-    fn parse_line(&self, line: Line) -> Result<u64, String> {
+    fn parse_line(&self, line: Line) -> Result<u64, PipelineError> {
         match self.number_re.captures(&line.text).map(|c|c.extract()) {
             Some((_, [number_txt])) => number_txt.parse()
-                .map_err(|err|error::parse(err, number_txt, line.number)),
-            None => Err(error::line_match(line.number)),
+                .map_err(|err|PipelineError::at(&line, line.column_at(0), error::parse(err, number_txt))),
+            None => Err(PipelineError::on_line(&line, error::line_match())),
         }
     }
 }
 
 impl Parse<Numbers> for NumbersParser {
-    fn parse(&self, vec_line: VecLine) -> Result<Numbers, String> {
+    fn parse(&self, vec_line: VecLine) -> Result<Numbers, PipelineError> {
         collect(vec_line.lines.into_iter().map(|line|self.parse_line(line)).collect()).map(Numbers)
     }
 }
\ No newline at end of file
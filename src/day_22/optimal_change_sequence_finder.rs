@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::{answer::{Answer, DisplayableAnswer}, day_22::{model::Numbers, secret_number_transform::SecretNumberTransform}, helper::movement::Delta, solver::Solve};
+use crate::{answer::{Answer, DisplayableAnswer}, day_22::{model::Numbers, secret_number_transform::SecretNumberTransform}, helper::movement::Delta, pipeline_error::PipelineError, solver::Solve};
 
 /// A finder for the optimal change sequence.
 /// 
@@ -57,7 +57,7 @@ impl OptimalChangeSequenceFinder {
 }
 
 impl Solve<Numbers> for OptimalChangeSequenceFinder {
-    fn solve(&self, input: Numbers) -> Result<Answer, String> {
+    fn solve(&self, input: Numbers) -> Result<Answer, PipelineError> {
         let Numbers(numbers) = input;
         let transform = SecretNumberTransform::default();
         let mut scores = HashMap::new();
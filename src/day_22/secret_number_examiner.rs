@@ -1,4 +1,4 @@
-use crate::{answer::DisplayableAnswer, day_22::{model::Numbers, secret_number_transform::SecretNumberTransform}, solver::Solve};
+use crate::{answer::DisplayableAnswer, day_22::{model::Numbers, secret_number_transform::SecretNumberTransform}, pipeline_error::PipelineError, solver::Solve};
 
 
 /// Given a sequence of initial secret numbers, examines
@@ -21,12 +21,13 @@ mod error {
 }
 
 impl Solve<Numbers> for SecretNumberExaminer {
-    fn solve(&self, input: Numbers) -> Result<crate::answer::Answer, String> {
+    fn solve(&self, input: Numbers) -> Result<crate::answer::Answer, PipelineError> {
         let Numbers(numbers) = input;
         let transform = SecretNumberTransform::default();
         
         numbers.into_iter().map(|secret|transform.iterative_evolve(secret, self.n))
             .try_fold(0u64, |acc, num|acc.checked_add(num).ok_or_else(error::overflow))
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
@@ -3,15 +3,17 @@ pub struct SecretNumberTransform {
     modulo: u64
 }
 
+impl Default for SecretNumberTransform {
+    fn default() -> SecretNumberTransform {
+        Self::new(16_777_216)
+    }
+}
+
 impl SecretNumberTransform {
     pub fn new(modulo: u64) -> SecretNumberTransform {
         SecretNumberTransform { modulo }
     }
 
-    pub fn default() -> SecretNumberTransform {
-        Self::new(16_777_216)
-    }
-
     /// Evolve secret number `n` times. Returns the sequence of evolution
     pub fn iterative_evolve_sequence(&self, secret_number: u64, n: usize) -> Vec<u64> {
         let mut secret_number = secret_number;
@@ -1,5 +1,5 @@
 
-use crate::{answer::DisplayableAnswer, day_23::{local_network_graph::LocalNetworkGraph, mesh_finder, model::LocalNetwork}, helper::display::vector_display, solver::Solve};
+use crate::{answer::DisplayableAnswer, day_23::{local_network_graph::LocalNetworkGraph, mesh_finder, model::LocalNetwork}, helper::display::vector_display, pipeline_error::PipelineError, solver::Solve};
 
 
 pub struct LanPartyPasswordFinder;
@@ -23,11 +23,12 @@ impl LanPartyPasswordFinder {
 }
 
 impl Solve<LocalNetwork> for LanPartyPasswordFinder {
-    fn solve(&self, input: LocalNetwork) -> Result<crate::answer::Answer, String> {
+    fn solve(&self, input: LocalNetwork) -> Result<crate::answer::Answer, PipelineError> {
         LocalNetworkGraph::new(input)
             .map(|network|mesh_finder::find_largest_meshes(&network))
             .and_then(Self::get_lan_party)
             .map(|lan_party|vector_display(&lan_party, ","))
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
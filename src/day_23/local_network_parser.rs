@@ -1,4 +1,4 @@
-use crate::{day_23::model::{Connection, LocalNetwork}, helper::result::collect, parser::Parse, reader::{Line, VecLine}};
+use crate::{day_23::model::{Connection, LocalNetwork}, helper::result::collect, parser::Parse, pipeline_error::PipelineError, reader::{Line, VecLine}};
 
 
 mod error {
@@ -8,8 +8,8 @@ mod error {
         format!("{} could not create connection RE due to error: {}", PREFIX, err)
     }
 
-    pub fn regex_match(line_num: usize) -> String {
-        format!("{} line #{} does not match connection RE", PREFIX, line_num)
+    pub fn regex_match() -> String {
+        format!("{} line does not match connection RE", PREFIX)
     }
 }
 
@@ -24,16 +24,16 @@ impl LocalNetworkParser {
             .map_err(error::connection_re)
     }
 
-    fn parse_line(&self, line: Line) -> Result<Connection, String> {
+    fn parse_line(&self, line: Line) -> Result<Connection, PipelineError> {
         match self.connection_re.captures(&line.text).map(|c|c.extract()) {
             Some((_, [left, right])) => Ok(Connection(left.to_string(), right.to_string())),
-            None => Err(error::regex_match(line.number)),
+            None => Err(PipelineError::on_line(&line, error::regex_match())),
         }
     }
 }
 
 impl Parse<LocalNetwork> for LocalNetworkParser {
-    fn parse(&self, vec_line: VecLine) -> Result<LocalNetwork, String> {
+    fn parse(&self, vec_line: VecLine) -> Result<LocalNetwork, PipelineError> {
         collect(vec_line.lines.into_iter().map(|line|self.parse_line(line)).collect()).map(LocalNetwork)
     }
 }
\ No newline at end of file
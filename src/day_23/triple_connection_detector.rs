@@ -1,4 +1,4 @@
-use crate::{answer::DisplayableAnswer, day_23::{local_network_graph::LocalNetworkGraph, mesh_finder, model::LocalNetwork}, solver::Solve};
+use crate::{answer::DisplayableAnswer, day_23::{local_network_graph::LocalNetworkGraph, mesh_finder, model::LocalNetwork}, pipeline_error::PipelineError, solver::Solve};
 
 pub struct TripleConnectionDetector;
 
@@ -11,10 +11,11 @@ impl TripleConnectionDetector {
 }
 
 impl Solve<LocalNetwork> for TripleConnectionDetector {
-    fn solve(&self, input: LocalNetwork) -> Result<crate::answer::Answer, String> {
+    fn solve(&self, input: LocalNetwork) -> Result<crate::answer::Answer, PipelineError> {
         LocalNetworkGraph::new(input)
             .map(|network|mesh_finder::find_meshes_of_size(&network, 3))
             .map(Self::count_chief_historian_candidates)
             .map(DisplayableAnswer::new)
+            .map_err(PipelineError::from)
     }
 }
\ No newline at end of file
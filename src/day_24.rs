@@ -0,0 +1,25 @@
+use adder_analyser::RippleCarryAdderAnalyser;
+use circuit_simulator::CircuitSimulator;
+use model::CrossedWires;
+use parser::CrossedWiresParser;
+use verifier::CrossedWiresVerifier;
+
+use crate::{day::define_day, reading::SanitisedFileReader};
+
+pub mod model;
+pub mod parser;
+pub mod verifier;
+pub mod circuit_simulator;
+pub mod adder_analyser;
+mod test;
+
+define_day! {
+    number: 24,
+    title: "Crossed Wires",
+    input: CrossedWires,
+    reader: Ok(SanitisedFileReader::default()),
+    parser: CrossedWiresParser::new(),
+    verifier: Ok(CrossedWiresVerifier::new()),
+    part_1: Ok(CircuitSimulator),
+    part_2: Ok(RippleCarryAdderAnalyser::new(4)),
+}
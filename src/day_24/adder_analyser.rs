@@ -0,0 +1,92 @@
+use std::collections::BTreeSet;
+
+use crate::{answer::{Answer, DisplayableAnswer}, pipeline_error::PipelineError, solver::Solve};
+
+use super::model::{CrossedWires, Gate, GateOperation};
+
+mod error {
+    const PREFIX: &str = "[Solver D-24 P-2]";
+
+    pub fn no_output_wires() -> String {
+        format!("{} circuit has no output wires starting with 'z'", PREFIX)
+    }
+
+    pub fn unexpected_suspicious_count(expected: usize, wires: Vec<String>) -> String {
+        format!("{} expected to find {} swapped wires, but found {}: {}", PREFIX, expected, wires.len(), wires.join(","))
+    }
+}
+
+/// Analyses the circuit under the assumption that it is meant to be a ripple-carry adder
+/// of numbers given on `x` and `y` wires, producing their sum on `z` wires. Each bit of the
+/// adder (except the first one, which is a half-adder) is expected to be a full-adder:
+/// ```text
+/// xN XOR yN -> sN        xN AND yN -> aN
+/// sN XOR cN-1 -> zN      sN AND cN-1 -> bN
+/// aN OR bN -> cN
+/// ```
+/// where the last carry `cN` is the most significant output wire. Any gate whose output
+/// does not fit that structure has its output wire marked as swapped.
+pub struct RippleCarryAdderAnalyser {
+    swapped_pairs: usize,
+}
+
+impl RippleCarryAdderAnalyser {
+    pub fn new(swapped_pairs: usize) -> RippleCarryAdderAnalyser {
+        RippleCarryAdderAnalyser { swapped_pairs }
+    }
+
+    fn is_input(wire: &str) -> bool {
+        wire.starts_with('x') || wire.starts_with('y')
+    }
+
+    fn is_first_bit_input(wire: &str) -> bool {
+        wire == "x00" || wire == "y00"
+    }
+
+    fn feeds_into(wires: &CrossedWires, wire: &String, operation: GateOperation) -> bool {
+        wires.gates.iter().any(|gate|gate.operation == operation && gate.has_input(wire))
+    }
+
+    fn is_swapped(wires: &CrossedWires, gate: &Gate, last_output: &String) -> bool {
+        let is_output = gate.output.starts_with('z');
+        let from_inputs = gate.inputs().into_iter().all(|wire|Self::is_input(wire));
+        let from_first_bit = gate.inputs().into_iter().all(|wire|Self::is_first_bit_input(wire));
+        match gate.operation {
+            // sum bits are always produced by XOR, except for the final carry which is an OR
+            _ if is_output && gate.output != *last_output && gate.operation != GateOperation::Xor => true,
+            _ if gate.output == *last_output && gate.operation != GateOperation::Or && !from_first_bit => true,
+
+            // XOR of intermediate wires produces a sum bit
+            GateOperation::Xor if !from_inputs => !is_output,
+
+            // XOR of input wires is an intermediate sum `sN` that must be combined with the carry
+            GateOperation::Xor => !from_first_bit && !Self::feeds_into(wires, &gate.output, GateOperation::Xor),
+
+            // AND gates produce partial carries `aN` and `bN` that are combined with OR
+            GateOperation::And => !from_first_bit && !Self::feeds_into(wires, &gate.output, GateOperation::Or),
+
+            GateOperation::Or => false,
+        }
+    }
+}
+
+impl Solve<CrossedWires> for RippleCarryAdderAnalyser {
+    fn solve(&self, input: CrossedWires) -> Result<Answer, PipelineError> {
+        let last_output = input.gates.iter()
+            .map(|gate|&gate.output)
+            .filter(|wire|wire.starts_with('z'))
+            .max()
+            .ok_or_else(error::no_output_wires)?;
+
+        let swapped_wires: BTreeSet<_> = input.gates.iter()
+            .filter(|gate|Self::is_swapped(&input, gate, last_output))
+            .map(|gate|gate.output.clone())
+            .collect();
+
+        let swapped_wires: Vec<_> = swapped_wires.into_iter().collect();
+        if swapped_wires.len() != 2 * self.swapped_pairs {
+            return Err(error::unexpected_suspicious_count(2 * self.swapped_pairs, swapped_wires).into());
+        }
+        Ok(DisplayableAnswer::new(swapped_wires.join(",")))
+    }
+}
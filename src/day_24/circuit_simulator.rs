@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use crate::{answer::{Answer, DisplayableAnswer}, pipeline_error::PipelineError, solver::Solve};
+
+use super::model::{CrossedWires, Gate};
+
+mod error {
+    const PREFIX: &str = "[Solver D-24 P-1]";
+
+    pub fn no_output_wires() -> String {
+        format!("{} circuit has no output wires starting with 'z'", PREFIX)
+    }
+
+    pub fn too_many_output_wires(count: usize) -> String {
+        format!("{} circuit has {} output wires, but at most 64 are supported", PREFIX, count)
+    }
+
+    pub fn unevaluated_wire(wire: &String) -> String {
+        format!("{} wire '{}' could not be evaluated", PREFIX, wire)
+    }
+}
+
+/// Simulates the gate circuit and reads out the number represented by the `z` wires,
+/// where `z00` is the least significant bit.
+pub struct CircuitSimulator;
+
+impl CircuitSimulator {
+    /// Evaluates all wires of the circuit. Gates are evaluated in rounds: in each round,
+    /// every gate whose inputs are known gets evaluated. Cycles are ruled out by the verifier.
+    fn simulate(input: &CrossedWires) -> HashMap<String, bool> {
+        let mut values: HashMap<String, bool> = input.initial_values.iter()
+            .map(|initial_value|(initial_value.wire.clone(), initial_value.value))
+            .collect();
+        let mut pending: Vec<&Gate> = input.gates.iter().collect();
+        while !pending.is_empty() {
+            let pending_count = pending.len();
+            pending.retain(|gate|match (values.get(&gate.left), values.get(&gate.right)) {
+                (Some(&left), Some(&right)) => {
+                    values.insert(gate.output.clone(), gate.operation.apply(left, right));
+                    false
+                },
+                _ => true,
+            });
+            if pending.len() == pending_count { break; }
+        }
+        values
+    }
+
+    fn read_output(input: &CrossedWires, values: &HashMap<String, bool>) -> Result<u64, String> {
+        let mut output_wires: Vec<_> = input.gates.iter()
+            .map(|gate|&gate.output)
+            .chain(input.initial_values.iter().map(|initial_value|&initial_value.wire))
+            .filter(|wire|wire.starts_with('z'))
+            .collect();
+        output_wires.sort();
+
+        match output_wires.len() {
+            0 => return Err(error::no_output_wires()),
+            count if count > 64 => return Err(error::too_many_output_wires(count)),
+            _ => (),
+        };
+
+        let mut number = 0;
+        for wire in output_wires.into_iter().rev() {
+            let bit = values.get(wire).ok_or_else(||error::unevaluated_wire(wire))?;
+            number = (number << 1) | (*bit as u64);
+        }
+        Ok(number)
+    }
+}
+
+impl Solve<CrossedWires> for CircuitSimulator {
+    fn solve(&self, input: CrossedWires) -> Result<Answer, PipelineError> {
+        let values = Self::simulate(&input);
+        Self::read_output(&input, &values).map(DisplayableAnswer::new).map_err(PipelineError::from)
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+use crate::helper::display::vector_display;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct CrossedWires {
+    pub initial_values: Vec<WireValue>,
+    pub gates: Vec<Gate>,
+}
+
+impl Display for CrossedWires {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let CrossedWires { initial_values, gates } = self;
+        write!(f, "{{ Initial values: [{}], Gates: [{}] }}", vector_display(initial_values, ", "), vector_display(gates, ", "))
+    }
+}
+
+/// Initial value of a wire, i.e. `x00: 1`
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct WireValue {
+    pub wire: String,
+    pub value: bool,
+}
+
+impl Display for WireValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.wire, if self.value { 1 } else { 0 })
+    }
+}
+
+/// A gate that takes two input wires and drives its output wire, i.e. `x00 AND y00 -> z00`
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Gate {
+    pub left: String,
+    pub operation: GateOperation,
+    pub right: String,
+    pub output: String,
+}
+
+impl Gate {
+    pub fn inputs(&self) -> [&String; 2] {
+        [&self.left, &self.right]
+    }
+
+    /// Returns true if `wire` is one of the two inputs of the gate
+    pub fn has_input(&self, wire: &String) -> bool {
+        self.left == *wire || self.right == *wire
+    }
+}
+
+impl Display for Gate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {} -> {}", self.left, self.operation, self.right, self.output)
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Hash, Debug)]
+pub enum GateOperation {
+    And,
+    Or,
+    Xor,
+}
+
+impl GateOperation {
+    pub fn apply(&self, left: bool, right: bool) -> bool {
+        match self {
+            GateOperation::And => left && right,
+            GateOperation::Or  => left || right,
+            GateOperation::Xor => left ^ right,
+        }
+    }
+}
+
+impl Display for GateOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            GateOperation::And => "AND",
+            GateOperation::Or  => "OR",
+            GateOperation::Xor => "XOR",
+        })
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{helper::result::collect, parser::Parse, pipeline_error::PipelineError, reader::{Line, VecLine}};
+
+use super::model::{CrossedWires, Gate, GateOperation, WireValue};
+
+mod error {
+    const PREFIX: &str = "[Parser D-24]";
+
+    pub fn create_regex(usecase: &str, e: regex::Error) -> String {
+        format!("{} could not create regex for {}, because {}.", PREFIX, usecase, e)
+    }
+
+    pub fn unrecognised_line() -> String {
+        format!("{} line is neither an initial wire value nor a gate", PREFIX)
+    }
+
+    pub fn initial_value_after_gate() -> String {
+        format!("{} initial wire value comes after the gates section has started", PREFIX)
+    }
+}
+
+pub struct CrossedWiresParser {
+    initial_value_re: regex::Regex,
+    gate_re: regex::Regex,
+}
+
+impl CrossedWiresParser {
+    pub fn new() -> Result<CrossedWiresParser, String> {
+        let initial_value_re = regex::Regex::new(r"^([a-z0-9]+) *: *([01])$")
+            .map_err(|e|error::create_regex("initial wire value", e));
+        let gate_re = regex::Regex::new(r"^([a-z0-9]+) +(AND|OR|XOR) +([a-z0-9]+) *-> *([a-z0-9]+)$")
+            .map_err(|e|error::create_regex("gate", e));
+        match collect(vec![initial_value_re, gate_re]) {
+            Err(e) => Err(e),
+            Ok(regexes) => Ok(CrossedWiresParser {
+                initial_value_re: regexes[0].clone(),
+                gate_re: regexes[1].clone(),
+            })
+        }
+    }
+
+    fn parse_initial_value(&self, line: &Line) -> Option<WireValue> {
+        self.initial_value_re.captures(&line.text)
+            .map(|c|c.extract())
+            .map(|(_, [wire, value])|WireValue { wire: wire.to_string(), value: value == "1" })
+    }
+
+    fn parse_gate(&self, line: &Line) -> Option<Gate> {
+        self.gate_re.captures(&line.text)
+            .map(|c|c.extract())
+            .map(|(_, [left, operation, right, output])|Gate {
+                left: left.to_string(),
+                operation: match operation {
+                    "AND" => GateOperation::And,
+                    "OR"  => GateOperation::Or,
+                    _     => GateOperation::Xor,
+                },
+                right: right.to_string(),
+                output: output.to_string(),
+            })
+    }
+}
+
+impl Parse<CrossedWires> for CrossedWiresParser {
+    fn parse(&self, vec_line: VecLine) -> Result<CrossedWires, PipelineError> {
+        let mut initial_values = vec![];
+        let mut gates = vec![];
+        for line in vec_line.lines {
+            if let Some(initial_value) = self.parse_initial_value(&line) {
+                if !gates.is_empty() {
+                    return Err(PipelineError::on_line(&line, error::initial_value_after_gate()));
+                }
+                initial_values.push(initial_value);
+            } else if let Some(gate) = self.parse_gate(&line) {
+                gates.push(gate);
+            } else {
+                return Err(PipelineError::on_line(&line, error::unrecognised_line()));
+            }
+        }
+        Ok(CrossedWires { initial_values, gates })
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+pub mod suite {
+    use crate::{answer::DisplayableAnswer, day_24::{adder_analyser::RippleCarryAdderAnalyser, make_pipeline}, solver::Solve, testing::{self, get_parsed_text_result, get_verification_error, get_verified_result_ok}};
+
+    const REL_FILEPATHS: &[&str] = &[
+        "src/day_24/test/example.txt", // Example given on AOC24
+        "src/day_24/test/adder_example.txt",
+        "src/day_24/test/swapped_adder_example.txt",
+        "src/day_24/test/cycle_example.txt",
+        "src/day_24/test/undriven_example.txt",
+        "src/day_24/test/multiple_drivers_example.txt",
+    ];
+
+    #[test]
+    pub fn test_whole_flow_part_1_example() {
+        let pipeline = make_pipeline(false).unwrap();
+        testing::test_whole_flow(&pipeline, REL_FILEPATHS[0], DisplayableAnswer::new(4));
+    }
+
+    #[test]
+    pub fn test_whole_flow_part_1_adder_example() {
+        let pipeline = make_pipeline(false).unwrap();
+        testing::test_whole_flow(&pipeline, REL_FILEPATHS[1], DisplayableAnswer::new(8));
+    }
+
+    #[test]
+    pub fn test_part_2_correct_adder() {
+        let pipeline = make_pipeline(true).unwrap();
+        let input = get_verified_result_ok(&pipeline, REL_FILEPATHS[1]);
+        let solver = RippleCarryAdderAnalyser::new(0);
+        assert_eq!(solver.solve(input).unwrap().report(), DisplayableAnswer::new("").report());
+    }
+
+    #[test]
+    pub fn test_part_2_swapped_adder() {
+        let pipeline = make_pipeline(true).unwrap();
+        let input = get_verified_result_ok(&pipeline, REL_FILEPATHS[2]);
+        let solver = RippleCarryAdderAnalyser::new(2);
+        assert_eq!(solver.solve(input).unwrap().report(), DisplayableAnswer::new("a02,b01,s02,z01").report());
+    }
+
+    #[test]
+    pub fn test_part_2_unexpected_swap_count() {
+        let pipeline = make_pipeline(true).unwrap();
+        testing::get_answer_error(&pipeline, REL_FILEPATHS[2]);
+    }
+
+    #[test]
+    pub fn test_parser_errors() {
+        let pipeline = make_pipeline(false).unwrap();
+        let error = get_parsed_text_result(&pipeline, "x00: 1\n\n// gates\nx00 AND x00 -> z00\nx01: 0").unwrap_err();
+        assert_eq!(error.line_number(), Some(5));
+        assert!(error.to_string().contains("comes after the gates section"), "{}", error);
+        let error = get_parsed_text_result(&pipeline, "x00: 1\nx00 NAND x00 -> z00").unwrap_err();
+        assert_eq!(error.line_number(), Some(2));
+        assert!(error.to_string().contains("neither an initial wire value nor a gate"), "{}", error);
+    }
+
+    #[test]
+    pub fn test_verifier_cycle_error() {
+        let pipeline = make_pipeline(false).unwrap();
+        assert!(get_verification_error(&pipeline, REL_FILEPATHS[3]).contains("cycle"));
+    }
+
+    #[test]
+    pub fn test_verifier_undriven_error() {
+        let pipeline = make_pipeline(false).unwrap();
+        assert!(get_verification_error(&pipeline, REL_FILEPATHS[4]).contains("never driven"));
+    }
+
+    #[test]
+    pub fn test_verifier_multiple_drivers_error() {
+        let pipeline = make_pipeline(false).unwrap();
+        assert!(get_verification_error(&pipeline, REL_FILEPATHS[5]).contains("more than once"));
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::pipeline_error::PipelineError;
+use crate::verifier::Verify;
+
+use super::model::CrossedWires;
+
+#[derive(Default)]
+pub struct CrossedWiresVerifier;
+
+mod error {
+    const PREFIX: &str = "[Verifier D-24]";
+
+    pub fn multiple_drivers(wire: &String) -> String {
+        format!("{} wire '{}' is driven more than once (by initial value and/or gates)", PREFIX, wire)
+    }
+
+    pub fn undriven_wire(wire: &String, gate_index: usize) -> String {
+        format!("{} wire '{}' used as input of gate with index {} is never driven", PREFIX, wire, gate_index)
+    }
+
+    pub fn cycle(wires: Vec<String>) -> String {
+        format!("{} gates form a cycle, the following wires can never be evaluated: {}", PREFIX, wires.join(","))
+    }
+}
+
+impl CrossedWiresVerifier {
+    pub fn new() -> CrossedWiresVerifier { CrossedWiresVerifier }
+
+    /// Every wire may be driven at most once: either by its initial value or by exactly one gate
+    fn verify_single_driver(input: CrossedWires) -> Result<CrossedWires, String> {
+        let mut driven = HashSet::new();
+        let initial_wires = input.initial_values.iter().map(|initial_value|&initial_value.wire);
+        let gate_outputs = input.gates.iter().map(|gate|&gate.output);
+        for wire in initial_wires.chain(gate_outputs) {
+            if !driven.insert(wire) {
+                return Err(error::multiple_drivers(wire));
+            }
+        }
+        Ok(input)
+    }
+
+    /// Every gate input must be either initialised or be an output of some gate
+    fn verify_no_undriven_inputs(input: CrossedWires) -> Result<CrossedWires, String> {
+        let initial_wires = input.initial_values.iter().map(|initial_value|&initial_value.wire);
+        let gate_outputs = input.gates.iter().map(|gate|&gate.output);
+        let driven: HashSet<_> = initial_wires.chain(gate_outputs).collect();
+        for (index, gate) in input.gates.iter().enumerate() {
+            if let Some(wire) = gate.inputs().into_iter().find(|wire|!driven.contains(wire)) {
+                return Err(error::undriven_wire(wire, index));
+            }
+        }
+        Ok(input)
+    }
+
+    /// Performs topological sorting of the gates; if some gates cannot be ordered, they form a cycle
+    fn verify_acyclic(input: CrossedWires) -> Result<CrossedWires, String> {
+        let mut known: HashSet<_> = input.initial_values.iter().map(|initial_value|&initial_value.wire).collect();
+        let mut pending: HashMap<_, _> = input.gates.iter().map(|gate|(&gate.output, gate)).collect();
+        loop {
+            let ready: Vec<_> = pending.iter()
+                .filter(|(_, gate)|gate.inputs().iter().all(|wire|known.contains(wire)))
+                .map(|(&output, _)|output)
+                .collect();
+            if ready.is_empty() { break; }
+            for output in ready {
+                pending.remove(output);
+                known.insert(output);
+            }
+        }
+
+        if pending.is_empty() {
+            Ok(input)
+        } else {
+            let mut cyclic_wires: Vec<_> = pending.into_keys().cloned().collect();
+            cyclic_wires.sort();
+            Err(error::cycle(cyclic_wires))
+        }
+    }
+}
+
+impl Verify<CrossedWires> for CrossedWiresVerifier {
+    fn verify(&self, input: CrossedWires) -> Result<CrossedWires, PipelineError> {
+        Ok(input)
+            .and_then(Self::verify_single_driver)
+            .and_then(Self::verify_no_undriven_inputs)
+            .and_then(Self::verify_acyclic)
+            .map_err(PipelineError::from)
+    }
+}
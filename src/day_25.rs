@@ -1,11 +1,12 @@
 
-use crate::{day_25::{keylock_match_analyser::KeyLockMatchAnalyser, model::KeyLockSchematics, schematics_parser::SchematicsParser}, executer_manager::ExecuterManager, pipelined_executer::{try_make_pipeline, PipelinedExecuter}, reading::{SanitisedFileReader, SimpleFileReader}, solver::Solve, verifier::TrivialVerifier};
+use crate::{day::define_day, day_25::{keylock_match_analyser::KeyLockMatchAnalyser, model::KeyLockSchematics, schematics_parser::schematics_parser, verifier::schematics_verifier}, reading::{SanitisedFileReader, SimpleFileReader}};
 
-mod model;
-mod schematics_parser;
-mod keylock_match_analyser;
-mod keylock_converter;
-mod key_lock;
+pub mod model;
+pub mod schematics_parser;
+pub mod verifier;
+pub mod keylock_match_analyser;
+pub mod keylock_converter;
+pub mod key_lock;
 mod test;
 
 fn reader() -> SanitisedFileReader {
@@ -19,21 +20,12 @@ fn reader() -> SanitisedFileReader {
     )
 }
 
-fn make_pipeline_with<S>(solver: S) -> Result<PipelinedExecuter<KeyLockSchematics>, String>
-where S: Solve<KeyLockSchematics> + 'static {
-    try_make_pipeline(
-        Ok(reader()),
-        Ok(SchematicsParser::new()),
-        Ok(TrivialVerifier::new::<KeyLockSchematics>()),
-        Ok(solver)
-    )
-}
-
-fn make_pipeline() -> Result<PipelinedExecuter<KeyLockSchematics>, String> {
-    make_pipeline_with(KeyLockMatchAnalyser)
+define_day! {
+    number: 25,
+    title: "Code Chronicle",
+    input: KeyLockSchematics,
+    reader: Ok(reader()),
+    parser: Ok(schematics_parser()),
+    verifier: Ok(schematics_verifier()),
+    part_1: Ok(KeyLockMatchAnalyser),
 }
-
-
-pub fn register(manager: ExecuterManager) -> Result<ExecuterManager, String> {
-    manager.try_register(25, false, make_pipeline(), false)
-}
\ No newline at end of file
@@ -1,10 +1,17 @@
 use std::fmt::Display;
 
-use crate::helper::{display::vector_display, table::Table};
+use crate::helper::{display::vector_display, grid::CharMapping, table::Table};
 
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub struct KeyLockSchematics(pub Vec<KeyLockSchema>); 
 
+impl KeyLockSchematics {
+    pub fn schemas(&self) -> &[KeyLockSchema] {
+        let KeyLockSchematics(schemas) = self;
+        schemas
+    }
+}
+
 impl Display for KeyLockSchematics {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let KeyLockSchematics(schemas) = self;
@@ -17,17 +24,8 @@ pub struct KeyLockSchema(pub Table<KeyLockSpace>);
 
 impl KeyLockSchema {
     pub fn columns(&self) -> Vec<Vec<KeyLockSpace>> {
-        let mut columns = vec![];
         let KeyLockSchema(table) = self;
-        let (row_count, columns_count) = table.dim();
-        for column_idx in 0..columns_count {
-            let mut current_column = vec![];
-            for row_idx in 0..row_count {
-                current_column.push(*table.get(row_idx, column_idx).unwrap());
-            }
-            columns.push(current_column);
-        }
-        columns
+        table.transpose().rows().map(|column|column.to_vec()).collect()
     }
 }
 
@@ -45,11 +43,13 @@ pub enum KeyLockSpace {
     Space,
 }
 
+pub const SPACE_MAPPING: CharMapping<KeyLockSpace> = CharMapping::new(&[
+    ('#', KeyLockSpace::Block),
+    ('.', KeyLockSpace::Space),
+]);
+
 impl Display for KeyLockSpace {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", match self {
-            KeyLockSpace::Block => '#',
-            KeyLockSpace::Space => '.',
-        })
+        SPACE_MAPPING.fmt(*self, f)
     }
 }
\ No newline at end of file
@@ -1,14 +1,29 @@
 #[cfg(test)]
 pub mod suite {
-    use crate::{answer::DisplayableAnswer, day_25::make_pipeline, testing};
+    use crate::{answer::DisplayableAnswer, day_25::make_pipeline, testing::{self, get_verification_error}};
 
     const REL_FILEPATHS: &[&str] = &[
         "src/day_25/test/example.txt", // Example given on AOC24
+        "src/day_25/test/neither_example.txt", // Second schema is neither a key nor a lock
+        "src/day_25/test/misaligned_example.txt", // Second schema is shorter
     ];
 
     #[test]
     pub fn test_whole_flow_part_1_example() {
-        let pipeline = make_pipeline().unwrap();
+        let pipeline = make_pipeline(false).unwrap();
         testing::test_whole_flow(&pipeline, &REL_FILEPATHS[0], DisplayableAnswer::new(3));
     }
+
+    #[test]
+    pub fn test_verifier_neither_key_nor_lock_error() {
+        let pipeline = make_pipeline(false).unwrap();
+        let error = get_verification_error(&pipeline, REL_FILEPATHS[1]);
+        assert!(error.contains("neither lock nor key (item with index 1)"), "{}", error);
+    }
+
+    #[test]
+    pub fn test_verifier_misaligned_dimensions_error() {
+        let pipeline = make_pipeline(false).unwrap();
+        assert!(get_verification_error(&pipeline, REL_FILEPATHS[2]).contains("schema at index 1 has dimension (6, 5)"));
+    }
 }
\ No newline at end of file
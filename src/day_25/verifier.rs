@@ -0,0 +1,43 @@
+use crate::{day_25::key_lock::{Key, Lock}, verifier::{check, each, Verify}};
+
+use super::model::{KeyLockSchema, KeyLockSchematics};
+
+mod error {
+    use crate::helper::display::vector_display;
+
+    const PREFIX: &str = "[Verifier D-25]";
+
+    pub fn dimensions_mismatch(index: usize, expected: (usize, usize), actual: (usize, usize)) -> String {
+        vector_display(&vec![
+            format!("{} dimensions of schemas not aligned.", PREFIX),
+            format!("The first schema has dimension {:?},", expected),
+            format!("but schema at index {} has dimension {:?}.", index,  actual)
+        ], " ")
+    }
+
+    pub fn schema_neither_key_nor_lock() -> String {
+        format!("{} schema is matching neither lock nor key", PREFIX)
+    }
+}
+
+/// All schemas must have the dimensions of the first one
+fn verify_aligned_dimensions(schematics: &KeyLockSchematics) -> Result<(), String> {
+    let KeyLockSchematics(schemas) = schematics;
+    let dims = schemas.iter().map(|KeyLockSchema(schema_table)|schema_table.dim()).collect::<Vec<_>>();
+    match dims.iter().position(|&dim|dim != dims[0]) {
+        Some(index) => Err(error::dimensions_mismatch(index, dims[0], dims[index])),
+        None => Ok(()),
+    }
+}
+
+fn verify_key_or_lock(schema: &KeyLockSchema) -> Result<(), String> {
+    match Key::new(schema).is_ok() || Lock::new(schema).is_ok() {
+        true => Ok(()),
+        false => Err(error::schema_neither_key_nor_lock()),
+    }
+}
+
+/// Verifies the schemas have aligned dimensions and each of them is either a key or a lock
+pub fn schematics_verifier() -> impl Verify<KeyLockSchematics> {
+    check(verify_aligned_dimensions).and_then(each(KeyLockSchematics::schemas, verify_key_or_lock))
+}
@@ -1,7 +1,133 @@
-use crate::answer::Answer;
+use std::{fmt::Display, time::{Duration, Instant}};
 
-pub trait Execute {
-    fn execute(&self, input_filepath: &str) -> Result<Answer, String>;
+use crate::{answer::Answer, pipeline_error::PipelineError};
+
+/// A stage of the read, parse, verify and solve pipeline
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Stage {
+    Read,
+    Parse,
+    Verify,
+    Solve,
+}
+
+impl Stage {
+    pub fn all() -> Vec<Stage> {
+        vec![Stage::Read, Stage::Parse, Stage::Verify, Stage::Solve]
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Stage::Read   => "read",
+            Stage::Parse  => "parse",
+            Stage::Verify => "verify",
+            Stage::Solve  => "solve",
+        })
+    }
+}
+
+/// Time spent in each pipeline stage that was run, in the order of running.
+/// When a stage fails, the stages after it are not run and hence not present.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct StageTimings {
+    pub stages: Vec<(Stage, Duration)>,
+}
+
+impl StageTimings {
+    pub fn new() -> StageTimings { StageTimings::default() }
+
+    /// Runs `stage_fn` and records its duration against `stage`
+    pub fn measure<T, F>(&mut self, stage: Stage, stage_fn: F) -> T where F: FnOnce() -> T {
+        let start = Instant::now();
+        let result = stage_fn();
+        self.stages.push((stage, start.elapsed()));
+        result
+    }
+
+    pub fn get(&self, stage: Stage) -> Option<Duration> {
+        self.stages.iter().find(|(s, _)|*s == stage).map(|&(_, duration)|duration)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.stages.iter().map(|&(_, duration)|duration).sum()
+    }
+}
+
+impl Display for StageTimings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stages = self.stages.iter()
+            .map(|(stage, duration)|format!("{}: {:.3?}", stage, duration))
+            .collect::<Vec<_>>();
+        write!(f, "{} (total: {:.3?})", stages.join(", "), self.total())
+    }
+}
+
+/// Output of each pipeline stage that was run, rendered as text, in the order of running:
+/// the read lines, the parsed model, the verified model and the answer
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct StageTrace {
+    pub stages: Vec<(Stage, String)>,
+}
+
+impl StageTrace {
+    pub fn new() -> StageTrace { StageTrace::default() }
+
+    pub fn record(&mut self, stage: Stage, output: String) {
+        self.stages.push((stage, output));
+    }
+
+    /// Renders the output of each stage under its name. Output of a stage longer than `max_lines` lines
+    /// or `max_chars` characters is truncated, noting how much of it was left out.
+    pub fn report(&self, max_lines: usize, max_chars: usize) -> String {
+        self.stages.iter()
+            .map(|(stage, output)|format!("== {} ==\n{}", stage, truncate(output, max_lines, max_chars)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Keeps at most `max_lines` lines and `max_chars` characters of the text
+fn truncate(text: &str, max_lines: usize, max_chars: usize) -> String {
+    let kept = text.lines().take(max_lines).collect::<Vec<_>>().join("\n");
+    let kept = kept.chars().take(max_chars).collect::<String>().trim_end().to_string();
+    let (kept_chars, total_chars) = (kept.chars().count(), text.trim_end().chars().count());
+    match kept_chars < total_chars {
+        true => format!("{}\n... truncated, {} of {} characters shown", kept, kept_chars, total_chars),
+        false => kept,
+    }
+}
+
+pub trait Execute: Send + Sync {
+    fn execute(&self, input_filepath: &str) -> Result<Answer, PipelineError>;
+
+    /// Same as `execute`, but additionally measures the time spent in each stage of the execution.
+    /// Executers that are not staged do not override this and report no stage timings.
+    fn execute_timed(&self, input_filepath: &str) -> (Result<Answer, PipelineError>, StageTimings) {
+        (self.execute(input_filepath), StageTimings::new())
+    }
+
+    /// Same as `execute`, but additionally records the output of each stage of the execution.
+    /// Executers that are not staged do not override this and record no stage outputs.
+    fn execute_traced(&self, input_filepath: &str) -> (Result<Answer, PipelineError>, StageTrace) {
+        (self.execute(input_filepath), StageTrace::new())
+    }
+
+    /// Renders the model the input file is parsed (and verified) into, using its `Display` implementation.
+    /// Executers that do not parse their input into a model do not override this and report an error.
+    fn display_model(&self, _input_filepath: &str) -> Result<String, PipelineError> {
+        Err(PipelineError::new(String::from("[Executer] executer does not expose its parsed model")))
+    }
+}
+
+pub type Executer = Box<dyn Execute>;
+
+/// Executes both parts of a day against the same input, sharing the work that does not depend on the part
+pub trait ExecuteBoth: Send + Sync {
+    /// Returns the outcome and the stage timings of part 1 and part 2, in that order.
+    /// Timings of the shared stages are included in both.
+    fn execute_both(&self, input_filepath: &str) -> [(Result<Answer, PipelineError>, StageTimings); 2];
 }
 
-pub type Executer = Box<dyn Execute>;
\ No newline at end of file
+pub type BothPartsExecuter = Box<dyn ExecuteBoth>;
@@ -1,32 +1,135 @@
-use std::collections::HashMap;
-use crate::executer::Execute;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Debug, Display};
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use crate::executer::{BothPartsExecuter, Execute, StageTimings, StageTrace};
+use crate::helper::display::vector_display;
+use crate::helper::result::zip;
+use crate::parameters::{ParameterSpec, Parameters};
+use crate::pipelined_executer::{PipelinedExecuter, PipelinedPairExecuter};
+use crate::pipeline_error::PipelineError;
 use crate::helper::re::get_captures;
+use crate::reading::STDIN_PATH;
 use crate::{answer::Answer, executer::Executer};
 
+/// A unique key identifying the registered executer: the event year, the day and the part of the problem
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct ExecuterKey {
+    pub year: u16,
+    pub day: u8,
+    pub is_part_2: bool,
+}
+
+impl Display for ExecuterKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-D{:02}{}", self.year, self.day, if self.is_part_2 { "-P2" } else { "" })
+    }
+}
+
+/// Outcome of a single executer run against an input file, together with the time it took overall and in each stage
+#[derive(Debug)]
+pub struct ExecutionRecord {
+    pub key: ExecuterKey,
+    pub input_filepath: String,
+    pub outcome: Result<Answer, PipelineError>,
+    pub elapsed: Duration,
+    pub timings: StageTimings,
+}
+
 pub struct ExecuterManager {
-    registered_executers: HashMap<String, Executer>,
-    
-    day_pattern_re: regex::Regex
+    registered_executers: HashMap<ExecuterKey, Executer>,
+    /// Executers of both parts sharing the input processing, keyed by year and day
+    registered_pairs: HashMap<(u16, u8), BothPartsExecuter>,
+    /// Number of worker threads executing independent jobs, see `execute_jobs`
+    workers: usize,
+    /// Values overriding the defaults of the declared parameters, see `try_register_parameterised_day`
+    parameters: Parameters,
+    /// Parameters declared by the days, keyed by year and day
+    parameter_specs: HashMap<(u16, u8), &'static [ParameterSpec]>,
+    /// Year of the executed problems, overriding the one resolved from the input filepath, see `with_year`
+    year: Option<u16>,
+
+    day_pattern_re: regex::Regex,
+    year_pattern_re: regex::Regex,
 }
 
 impl ExecuterManager {
     const PREFIX : &str = "[ExecuterManager]";
+    /// Year of the first Advent of Code event
+    const FIRST_YEAR: u16 = 2015;
 
     pub fn new() -> Result<ExecuterManager, String> {
         let day_pattern = regex::Regex::new(r"d(?:ay)?(?:\.|-|_)?(\d{2})")
             .map_err(|e| format!("{} compilation of day pattern regex failed with error '{}'", Self::PREFIX, e));
+        let year_pattern = regex::Regex::new(r"^y?(\d{4})$")
+            .map_err(|e| format!("{} compilation of year pattern regex failed with error '{}'", Self::PREFIX, e));
 
-        day_pattern.map(|dp| ExecuterManager{ day_pattern_re: dp, registered_executers: HashMap::new() })
+        zip(day_pattern, year_pattern, |dp, yp| ExecuterManager{
+            day_pattern_re: dp,
+            year_pattern_re: yp,
+            registered_executers: HashMap::new(),
+            registered_pairs: HashMap::new(),
+            workers: 1,
+            parameters: Parameters::new(),
+            parameter_specs: HashMap::new(),
+            year: None,
+        })
+    }
+
+    /// Sets the number of worker threads that execute independent jobs; at least one worker is always used
+    pub fn with_workers(self, workers: usize) -> ExecuterManager {
+        ExecuterManager { workers: workers.max(1), ..self }
+    }
+
+    /// Sets the values overriding the defaults of the parameters declared by the days.
+    /// It has to be called before the days are registered, as their pipelines are made from the resolved values.
+    pub fn with_parameters(self, parameters: Parameters) -> ExecuterManager {
+        ExecuterManager { parameters, ..self }
+    }
+
+    /// Sets the year of the executed problems. If `None`, the year is resolved from the input filepath, see `try_execute_executer`.
+    pub fn with_year(self, year: Option<u16>) -> ExecuterManager {
+        ExecuterManager { year, ..self }
     }
 
     /// Returns a unique key identifier for executer in question
-    fn try_get_key(day: u8, is_part_2: bool) -> Result<String, String> {
-        match day {
-            d if 1 <= d && d <= 25 => Ok(format!("D{:02}{}", d, if is_part_2 { "-P2" } else { "" })),
+    fn try_get_key(year: u16, day: u8, is_part_2: bool) -> Result<ExecuterKey, String> {
+        match (year, day) {
+            (y, _) if y < Self::FIRST_YEAR => Err(format!("{} invalid year: {}", Self::PREFIX, year)),
+            (_, d) if (1..=25).contains(&d) => Ok(ExecuterKey { year, day, is_part_2 }),
             _ => Err(format!("{} invalid day number: {}", Self::PREFIX, day))
         }
     }
 
+    /// Returns the years of all registered executers, in ascending order
+    fn registered_years(&self) -> BTreeSet<u16> {
+        self.registered_executers.keys().map(|key|key.year).collect()
+    }
+
+    /// Tries to resolve the year for the given input filepath: the year set by `with_year`, or else the year of the
+    /// path component nearest to the file that matches it, which has to be registered, or else the only year with
+    /// registered executers
+    fn try_resolve_year(&self, input_filepath: &str) -> Result<u16, String> {
+        if let Some(year) = self.year {
+            return Ok(year);
+        }
+        let years = self.registered_years();
+        let registered = || vector_display(&years.iter().collect::<Vec<_>>(), ", ");
+        let from_path = Path::new(input_filepath).components().rev()
+            .filter_map(|component|get_captures(&self.year_pattern_re, &component.as_os_str().to_string_lossy()))
+            .find_map(|v|v[1].parse::<u16>().ok());
+        match from_path {
+            Some(year) if years.contains(&year) => Ok(year),
+            Some(year) => Err(format!("{} year {} resolved from filename '{}' is not registered (registered years: {})",
+                Self::PREFIX, year, input_filepath, registered())),
+            None if years.len() == 1 => Ok(*years.first().unwrap()),
+            None => Err(format!("{} year not provided, failed to resolve year from filename: '{}' (registered years: {})",
+                Self::PREFIX, input_filepath, registered())),
+        }
+    }
+
     /// Tries to resolve the day number from the given input filepath
     fn try_resolve_day(&self, input_filepath: &str) -> Result<u8, String> {
         match get_captures(&self.day_pattern_re, input_filepath) {
@@ -35,11 +138,12 @@ impl ExecuterManager {
                 day.parse::<u8>()
                     .map_err(|e|e.to_string())
             }
+            _ if input_filepath == STDIN_PATH => Err(format!("{} day must be provided when reading the input from stdin", Self::PREFIX)),
             _ => Err(format!("{} day not provided, failed to resolve day from filename: '{}'", Self::PREFIX, input_filepath)),
         }
     }
 
-    fn handle_overwrite(self, allow_overwrite: bool, key: &String) -> Result<Self, String> {
+    fn handle_overwrite(self, allow_overwrite: bool, key: &ExecuterKey) -> Result<Self, String> {
         match allow_overwrite {
             true => Ok(self),
             false => Err(format!("{} attempted overwrite on registered key '{}'", Self::PREFIX, key))
@@ -47,6 +151,7 @@ impl ExecuterManager {
     }
 
     /// Registers an executer for specific problem.
+    /// - `year`: the year of the event the problem belongs to, e.g. 2024. Valid values: 2015 onwards.
     /// - `day`: a number representing the associated day of the problem. Valid values: 1 to 25, inclusive.
     /// - `is_part_2`: whether the executer is to be associated with part two for the given day
     /// - `executer`: executer to be registered
@@ -54,12 +159,12 @@ impl ExecuterManager {
     /// already existing key-value entry, registration will fail with an error
     /// 
     /// Returns `Result<Self, String>`: if registration failed, the error message is returned, o/w it returns self.
-    /// Main reason for the failure is if the `day` number is out of its valid range.
-    pub fn register<EXE>(mut self, day: u8, is_part_2: bool, executer: EXE, allow_overwrite: bool) -> Result<Self, String>
+    /// Main reason for the failure is if the `year` or `day` number is out of its valid range.
+    pub fn register<EXE>(mut self, year: u16, day: u8, is_part_2: bool, executer: EXE, allow_overwrite: bool) -> Result<Self, String>
     where EXE: Execute + 'static
     {
-        ExecuterManager::try_get_key(day, is_part_2)
-            .and_then(|key|match self.registered_executers.insert(key.clone(), Box::new(executer)) {
+        ExecuterManager::try_get_key(year, day, is_part_2)
+            .and_then(|key|match self.registered_executers.insert(key, Box::new(executer)) {
                 Some(_) => self.handle_overwrite(allow_overwrite, &key),
                 None => Ok(self),
             })
@@ -67,10 +172,67 @@ impl ExecuterManager {
 
     /// A shorthand when the underlying executer is wrapped in `Result<_, String>`.
     /// Effectively does the same as `register`, but handles the "unpacking"
-    pub fn try_register<EXE>(self, day: u8, is_part_2: bool, executer: Result<EXE, String>, allow_overwrite: bool)
+    pub fn try_register<EXE>(self, year: u16, day: u8, is_part_2: bool, executer: Result<EXE, String>, allow_overwrite: bool)
     -> Result<Self, String>
     where EXE: Execute + 'static {
-        executer.and_then(|exe|self.register(day, is_part_2, exe, allow_overwrite))
+        executer.and_then(|exe|self.register(year, day, is_part_2, exe, allow_overwrite))
+    }
+
+    /// Registers both parts of a day, whose pipelines are made by `make_pipeline(is_part_2)`. Besides the executer of each part,
+    /// it registers an executer of both parts that reads, parses and verifies the input only once, see `try_execute_both`.
+    /// The pipelines of the two parts must therefore differ only in their solvers.
+    pub fn try_register_day<T, F>(self, year: u16, day: u8, make_pipeline: F, allow_overwrite: bool) -> Result<Self, String>
+    where T: Eq + Display + Clone + Debug + 'static, F: Fn(bool) -> Result<PipelinedExecuter<T>, String> {
+        let pair = zip(make_pipeline(false), make_pipeline(true), PipelinedPairExecuter::new)?;
+        let mut manager = self.try_register(year, day, false, make_pipeline(false), allow_overwrite)
+            ?.try_register(year, day, true, make_pipeline(true), allow_overwrite)?;
+        manager.registered_pairs.insert((year, day), Box::new(pair));
+        Ok(manager)
+    }
+
+    /// Registers a day declaring tunable parameters, whose pipelines are made by `make_pipeline(is_part_2)`.
+    /// The overrides set by `with_parameters` that apply to the day, unscoped or scoped to it as `dXY.key`, are checked
+    /// against the declared parameters and given to the pipelines, where they take precedence over the parameters
+    /// declared in the input file (see `PipelinedExecuter::with_parameters`).
+    /// Both parts are registered as in `try_register_day`, unless the day has no part 2, in which case only part 1 is.
+    pub fn try_register_parameterised_day<T, F>(self, year: u16, day: u8, has_part_2: bool, specs: &'static [ParameterSpec], make_pipeline: F, allow_overwrite: bool)
+    -> Result<Self, String>
+    where T: Eq + Display + Clone + Debug + 'static, F: Fn(bool) -> Result<PipelinedExecuter<T>, String> {
+        let overrides = self.parameters.for_day(day);
+        ParameterSpec::resolve(specs, &overrides)
+            .map_err(|e|format!("{} year {} day {}: {}", Self::PREFIX, year, day, e))?;
+        let make_pipeline = |is_part_2|make_pipeline(is_part_2).map(|pipeline|pipeline.with_parameters(specs, overrides.clone()));
+        let mut manager = match has_part_2 {
+            true => self.try_register_day(year, day, make_pipeline, allow_overwrite)?,
+            false => self.try_register(year, day, false, make_pipeline(false), allow_overwrite)?,
+        };
+        manager.parameter_specs.insert((year, day), specs);
+        Ok(manager)
+    }
+
+    /// Checks that every overriding value set by `with_parameters` belongs to a parameter declared by some registered day,
+    /// or by the day it is scoped to
+    pub fn check_parameters(self) -> Result<Self, String> {
+        let declared = |scope: Option<u8>, name: &str|self.parameter_specs.iter()
+            .filter(|((_, day), _)|scope.is_none_or(|scope|scope == *day))
+            .flat_map(|(_, specs)|specs.iter())
+            .any(|spec|spec.name == name);
+        let undeclared = self.parameters.keys().find(|key|{
+            let (scope, name) = Parameters::scope(key);
+            !declared(scope, name)
+        });
+        match undeclared.map(Parameters::scope) {
+            Some((Some(day), name)) => Err(format!("{} day {} does not declare parameter '{}'", Self::PREFIX, day, name)),
+            Some((None, name)) => Err(format!("{} no day declares parameter '{}'", Self::PREFIX, name)),
+            None => Ok(self),
+        }
+    }
+
+    /// Returns the keys of all registered executers, sorted by year, day and then by part
+    pub fn registered_keys(&self) -> Vec<ExecuterKey> {
+        let mut keys: Vec<_> = self.registered_executers.keys().copied().collect();
+        keys.sort();
+        keys
     }
 
     /// Tries to find a registered executer to execute against the input file.
@@ -83,12 +245,200 @@ impl ExecuterManager {
     /// If the input filepath contains the any of the pattern, they will be detected and used when `day` is `None`.
     /// There are no guarantees which pattern will be used if multiple are present, e.g. for filepath `d05/input.d-12.txt`
     /// the day can be resolved either to day 5 or day 12.
-    pub  fn try_execute_executer(&self, input_filepath: &str, day: Option<u8>, is_part_2: bool) -> Result<Answer, String> {
+    ///
+    /// The year is the one set by `with_year`. If it is not set, a path component `yXYZW` or `XYZW`, where `XYZW` are four
+    /// digits, gives the year, e.g. `inputs/y2023/d05.txt` or `2023/day05.txt`; the component nearest to the file is used,
+    /// and its year has to be registered. Without such component, the year is resolved only if all registered executers
+    /// belong to the same year.
+    ///
+    /// A panicking executer is reported as an error, here as well as in the other ways of executing it.
+    pub  fn try_execute_executer(&self, input_filepath: &str, day: Option<u8>, is_part_2: bool) -> Result<Answer, PipelineError> {
+        let (key, executer) = self.try_get_executer(input_filepath, day, is_part_2)?;
+        Self::catching_panic(||executer.execute(input_filepath)).map_err(|e|e.with_problem(key.day, key.is_part_2))
+    }
+
+    /// Same as `try_execute_executer`, but additionally records the output of each stage of the execution.
+    /// Returns an error without a trace if no executer could be found.
+    pub fn try_execute_traced(&self, input_filepath: &str, day: Option<u8>, is_part_2: bool)
+    -> Result<(Result<Answer, PipelineError>, StageTrace), String> {
+        self.try_get_executer(input_filepath, day, is_part_2)
+            .map(|(key, executer)|{
+                let (outcome, trace) = std::panic::catch_unwind(AssertUnwindSafe(||executer.execute_traced(input_filepath)))
+                    .unwrap_or_else(|payload|(Err(Self::panic_error(payload)), StageTrace::new()));
+                (outcome.map_err(|e|e.with_problem(key.day, key.is_part_2)), trace)
+            })
+    }
+
+    /// Renders the parsed and verified model of the input file, as the executer found as in `try_execute_executer` sees it
+    pub fn try_display_model(&self, input_filepath: &str, day: Option<u8>, is_part_2: bool) -> Result<String, PipelineError> {
+        let (key, executer) = self.try_get_executer(input_filepath, day, is_part_2)?;
+        Self::catching_panic(||executer.display_model(input_filepath)).map_err(|e|e.with_problem(key.day, key.is_part_2))
+    }
+
+    /// Same as `try_execute_executer`, but additionally measures the time spent in each stage of the execution.
+    /// Returns an error without timings if no executer could be found.
+    pub fn try_execute_timed(&self, input_filepath: &str, day: Option<u8>, is_part_2: bool)
+    -> Result<(Result<Answer, PipelineError>, StageTimings), String> {
+        self.try_get_executer(input_filepath, day, is_part_2)
+            .map(|(key, _)|{
+                let record = self.execute_timed(key, input_filepath);
+                (record.outcome, record.timings)
+            })
+    }
+
+    /// Same as `try_execute_timed`, but captures the execution in a record. A panicking executer is recorded as failed.
+    /// Returns an error only if no executer could be found.
+    pub fn try_execute_record(&self, input_filepath: &str, day: Option<u8>, is_part_2: bool) -> Result<ExecutionRecord, String> {
+        self.try_get_executer(input_filepath, day, is_part_2)
+            .map(|(key, _)|self.execute_timed(key, input_filepath))
+    }
+
+    /// Executes all registered parts of the day against the input file, see `try_execute_executer` for day resolution.
+    /// If the day was registered with `try_register_day`, the input is read, parsed and verified only once for both parts;
+    /// each record then holds the timings of the shared stages, and its elapsed time is the total of its timings.
+    /// Otherwise, the registered parts are executed one after another.
+    ///
+    /// Returns the records ordered by part; an error is returned only if no executer could be found for the day.
+    pub fn try_execute_both(&self, input_filepath: &str, day: Option<u8>) -> Result<Vec<ExecutionRecord>, String> {
+        let keys = [self.try_resolve_key(input_filepath, day, false)?, self.try_resolve_key(input_filepath, day, true)?];
+        let (year, day) = (keys[0].year, keys[0].day);
+        let Some(pair) = self.registered_pairs.get(&(year, day)) else {
+            let records = keys.into_iter()
+                .filter(|key|self.registered_executers.contains_key(key))
+                .map(|key|self.execute_timed(key, input_filepath))
+                .collect::<Vec<_>>();
+            return match records.is_empty() {
+                true => Err(format!("{} there is no registered executer for year {} day {}", Self::PREFIX, year, day)),
+                false => Ok(records),
+            };
+        };
+        let outcomes = std::panic::catch_unwind(AssertUnwindSafe(||pair.execute_both(input_filepath)))
+            .unwrap_or_else(|payload|{
+                let error = Self::panic_error(payload);
+                [(Err(error.clone()), StageTimings::new()), (Err(error), StageTimings::new())]
+            });
+        Ok(keys.into_iter().zip(outcomes)
+            .map(|(key, (outcome, timings))|ExecutionRecord {
+                key,
+                input_filepath: input_filepath.to_string(),
+                outcome: outcome.map_err(|e|e.with_problem(key.day, key.is_part_2)),
+                elapsed: timings.total(),
+                timings,
+            })
+            .collect())
+    }
+
+    /// Resolves the key of the executer to run against the input file, see `try_execute_executer` for year and day resolution
+    pub fn try_resolve_key(&self, input_filepath: &str, day: Option<u8>, is_part_2: bool) -> Result<ExecuterKey, String> {
+        let year = self.try_resolve_year(input_filepath)?;
         day.map_or_else(||self.try_resolve_day(input_filepath), Ok)
-            .and_then(|day|ExecuterManager::try_get_key(day, is_part_2))
+            .and_then(|day|ExecuterManager::try_get_key(year, day, is_part_2))
+            .map_err(|e|format!("{} (year {})", e, year))
+    }
+
+    /// Finds the executer to run against the input file, see `try_execute_executer` for year and day resolution
+    fn try_get_executer(&self, input_filepath: &str, day: Option<u8>, is_part_2: bool) -> Result<(ExecuterKey, &Executer), String> {
+        self.try_resolve_key(input_filepath, day, is_part_2)
             .and_then(|key|self.registered_executers
                 .get(&key)
+                .map(|executer|(key, executer))
                 .ok_or(format!("There is no registered executer for key '{}'", key)))
-            ?.execute(input_filepath)
+    }
+
+    /// Describes the payload of a caught panic
+    fn panic_error(payload: Box<dyn std::any::Any + Send>) -> PipelineError {
+        let message = payload.downcast_ref::<&str>().map(|s|s.to_string())
+            .or_else(||payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(||String::from("unknown cause"));
+        PipelineError::new(format!("{} executer panicked", Self::PREFIX)).caused_by(PipelineError::new(message))
+    }
+
+    /// Runs the function, turning its panic into an error, so that a panicking executer does not abort
+    /// long-lived callers such as the interactive shell or the watcher
+    fn catching_panic<T>(run: impl FnOnce() -> Result<T, PipelineError>) -> Result<T, PipelineError> {
+        std::panic::catch_unwind(AssertUnwindSafe(run)).unwrap_or_else(|payload|Err(Self::panic_error(payload)))
+    }
+
+    /// Runs the executer registered under `key` against the input file and measures its run time.
+    /// A panicking executer is recorded as failed, so that it does not abort the remaining executions.
+    fn execute_timed(&self, key: ExecuterKey, input_filepath: &str) -> ExecutionRecord {
+        let start = Instant::now();
+        let (outcome, timings) = match self.registered_executers.get(&key) {
+            Some(executer) => std::panic::catch_unwind(AssertUnwindSafe(||executer.execute_timed(input_filepath)))
+                .unwrap_or_else(|payload|(Err(Self::panic_error(payload)), StageTimings::new())),
+            None => (Err(PipelineError::new(format!("There is no registered executer for key '{}'", key))), StageTimings::new()),
+        };
+        let outcome = outcome.map_err(|e|e.with_problem(key.day, key.is_part_2));
+        ExecutionRecord { key, input_filepath: input_filepath.to_string(), outcome, elapsed: start.elapsed(), timings }
+    }
+
+    /// Recursively collects all files inside the directory
+    fn collect_files(directory: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+        let entries = std::fs::read_dir(directory)
+            .map_err(|e|format!("{} could not read directory '{}': {}", Self::PREFIX, directory.display(), e))?;
+        for entry in entries {
+            let path = entry
+                .map_err(|e|format!("{} could not read entry of directory '{}': {}", Self::PREFIX, directory.display(), e))?
+                .path();
+            if path.is_dir() {
+                Self::collect_files(&path, files)?;
+            } else {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs all registered executers against the input files found in `input_directory` (including its subdirectories).
+    /// The year and day of each file are resolved from its path relative to `input_directory`, as in `try_execute_executer`;
+    /// files from which no year or day can be resolved are skipped. Every registered part of the resolved day is executed
+    /// against the file.
+    ///
+    /// Returns records of all executions, ordered by year, day, part and then input filepath. Failure of an individual
+    /// execution is captured in its record; an error is returned only if the directory could not be walked.
+    pub fn try_execute_directory(&self, input_directory: &str) -> Result<Vec<ExecutionRecord>, String> {
+        let directory = Path::new(input_directory);
+        let mut files = vec![];
+        Self::collect_files(directory, &mut files)?;
+        files.sort();
+
+        let mut jobs = vec![];
+        for file in files {
+            let relative_path = file.strip_prefix(directory).unwrap_or(&file).to_string_lossy().to_string();
+            let Ok(ExecuterKey { year, day, .. }) = self.try_resolve_key(&relative_path, None, false) else { continue };
+            for key in self.registered_keys().into_iter().filter(|key|(key.year, key.day) == (year, day)) {
+                jobs.push((key, file.to_string_lossy().to_string()));
+            }
+        }
+        jobs.sort();
+        Ok(self.execute_jobs(&jobs))
+    }
+
+    /// Executes each job, i.e. the executer registered under the key against the input file, and returns their records
+    /// in the order of the jobs. The jobs are spread across the configured number of worker threads.
+    /// Failure of an individual job, including a panic or a missing executer, is captured in its record.
+    pub fn execute_jobs(&self, jobs: &[(ExecuterKey, String)]) -> Vec<ExecutionRecord> {
+        if self.workers == 1 || jobs.len() <= 1 {
+            return jobs.iter().map(|(key, input_filepath)|self.execute_timed(*key, input_filepath)).collect();
+        }
+        let next_job = AtomicUsize::new(0);
+        let mut indexed_records = std::thread::scope(|scope|{
+            let workers = (0..self.workers.min(jobs.len()))
+                .map(|_|scope.spawn(||{
+                    let mut indexed_records = vec![];
+                    loop {
+                        let index = next_job.fetch_add(1, Ordering::Relaxed);
+                        let Some((key, input_filepath)) = jobs.get(index) else { break };
+                        indexed_records.push((index, self.execute_timed(*key, input_filepath)));
+                    }
+                    indexed_records
+                }))
+                .collect::<Vec<_>>();
+            workers.into_iter()
+                .flat_map(|worker|worker.join().expect("executions are guarded against panics"))
+                .collect::<Vec<_>>()
+        });
+        indexed_records.sort_by_key(|&(index, _)|index);
+        indexed_records.into_iter().map(|(_, record)|record).collect()
     }
 }
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use crate::{answer::Answer, executer::{Stage, StageTimings}, executer_manager::ExecutionRecord, helper::{display::text_table, json::JsonValue}, pipeline_error::PipelineError};
+
+const MAX_ANSWER_WIDTH: usize = 60;
+
+/// Shortens the text to a single line of at most `MAX_ANSWER_WIDTH` characters
+fn shorten(text: &str) -> String {
+    let first_line = text.lines().next().unwrap_or_default();
+    let is_multiline = text.trim_end().lines().count() > 1;
+    if first_line.chars().count() > MAX_ANSWER_WIDTH || is_multiline {
+        format!("{}...", first_line.chars().take(MAX_ANSWER_WIDTH).collect::<String>())
+    } else {
+        first_line.to_string()
+    }
+}
+
+/// Status of a failed execution, naming the failed stage and input line when they are known, e.g. `FAILED (parse, line #3)`
+fn failure_status(error: &PipelineError) -> String {
+    let location = error.stage.map(|stage|stage.to_string()).into_iter()
+        .chain(error.line_number().map(|number|format!("line #{}", number)))
+        .collect::<Vec<_>>();
+    if location.is_empty() { "FAILED".to_string() } else { format!("FAILED ({})", location.join(", ")) }
+}
+
+/// Renders the execution records as a summary table of year, day, part, answer, status, elapsed time and input file.
+/// For failed executions, the answer column holds the (shortened) error message.
+pub fn summary_table(records: &[ExecutionRecord]) -> String {
+    let rows = records.iter()
+        .map(|record|{
+            let (answer, status) = match &record.outcome {
+                Ok(answer) => (shorten(&answer.summary()), "OK".to_string()),
+                Err(error) => (shorten(&error.to_string()), failure_status(error)),
+            };
+            vec![
+                record.key.year.to_string(),
+                format!("{:02}", record.key.day),
+                if record.key.is_part_2 { "2" } else { "1" }.to_string(),
+                answer,
+                status,
+                format!("{:.3?}", record.elapsed),
+                record.input_filepath.clone(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    text_table(&["Year", "Day", "Part", "Answer", "Status", "Elapsed", "Input"], &rows)
+}
+
+/// Returns the one-line totals shown below the summary table
+pub fn summary_totals(records: &[ExecutionRecord]) -> String {
+    let failed = records.iter().filter(|record|record.outcome.is_err()).count();
+    let elapsed = records.iter().map(|record|record.elapsed).sum::<Duration>();
+    format!("{} executed, {} succeeded, {} failed in {:.3?}", records.len(), records.len() - failed, failed, elapsed)
+}
+
+fn milliseconds(duration: Duration) -> JsonValue {
+    JsonValue::Number(format!("{:.3}", duration.as_secs_f64() * 1000.0))
+}
+
+fn error_json(error: &PipelineError) -> JsonValue {
+    JsonValue::object(vec![
+        ("message", JsonValue::string(error)),
+        ("stage", JsonValue::from_option(error.stage, JsonValue::string)),
+        ("line", JsonValue::from_option(error.line_number(), JsonValue::number)),
+    ])
+}
+
+fn execution_json(year: Option<u16>, day: Option<u8>, is_part_2: bool, input_filepath: &str, outcome: Result<&Answer, &PipelineError>,
+    elapsed: Option<Duration>, timings: &StageTimings) -> JsonValue {
+    let stages = Stage::all().into_iter()
+        .filter_map(|stage|timings.get(stage).map(|duration|(stage.to_string(), milliseconds(duration))))
+        .collect();
+    JsonValue::object(vec![
+        ("year", JsonValue::from_option(year, JsonValue::number)),
+        ("day", JsonValue::from_option(day, JsonValue::number)),
+        ("part", JsonValue::number(if is_part_2 { 2 } else { 1 })),
+        ("input", JsonValue::string(input_filepath)),
+        ("status", JsonValue::string(if outcome.is_ok() { "ok" } else { "failed" })),
+        ("answer", outcome.map_or(JsonValue::Null, |answer|answer.to_json())),
+        ("error", outcome.err().map_or(JsonValue::Null, error_json)),
+        ("elapsed_ms", JsonValue::from_option(elapsed, milliseconds)),
+        ("stages_ms", JsonValue::Object(stages)),
+    ])
+}
+
+/// Renders the execution record as a single-line JSON object with year, day, part, input file, status,
+/// answer, error (message, stage and line), total elapsed time and time per stage in milliseconds
+pub fn record_json(record: &ExecutionRecord) -> String {
+    execution_json(Some(record.key.year), Some(record.key.day), record.key.is_part_2, &record.input_filepath, record.outcome.as_ref(),
+        Some(record.elapsed), &record.timings).to_string()
+}
+
+/// Renders an execution that could not be started, e.g. because no executer was found, in the format of `record_json`
+pub fn failure_json(year: Option<u16>, day: Option<u8>, is_part_2: bool, input_filepath: &str, error: &PipelineError) -> String {
+    execution_json(year, day, is_part_2, input_filepath, Err(error), None, &StageTimings::new()).to_string()
+}
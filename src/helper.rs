@@ -6,4 +6,9 @@ pub mod table;
 pub mod position;
 pub mod movement;
 pub mod boundary;
-pub mod direction;
\ No newline at end of file
+pub mod direction;
+pub mod json;
+pub mod combinator;
+pub mod grid;
+pub mod search;
+pub mod geometry;
\ No newline at end of file
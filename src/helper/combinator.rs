@@ -0,0 +1,212 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{pipeline_error::PipelineError, reader::Line};
+
+use super::table::Table;
+
+/// Failure of a combinator: the byte offset into the line where it failed, and what was expected there
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Failure {
+    pub offset: usize,
+    pub expected: String,
+}
+
+impl Failure {
+    pub fn new(offset: usize, expected: &str) -> Failure {
+        Failure { offset, expected: expected.to_string() }
+    }
+}
+
+/// Outcome of a combinator step: the parsed value and the offset right after it
+pub type Step<T> = Result<(T, usize), Failure>;
+
+type ParseFn<T> = Box<dyn Fn(&str, usize) -> Step<T> + Send + Sync>;
+
+/// A parser of a part of a single line. Combinators are composed from the primitives below
+/// (`literal`, `integer`, `spaces`, `token`) and run against a whole line with `parse_line`.
+pub struct Combinator<T> {
+    parse_fn: ParseFn<T>,
+}
+
+impl <T: 'static> Combinator<T> {
+    pub fn new<F>(parse_fn: F) -> Combinator<T> where F: Fn(&str, usize) -> Step<T> + Send + Sync + 'static {
+        Combinator { parse_fn: Box::new(parse_fn) }
+    }
+
+    /// Parses the text starting at the byte `offset`
+    pub fn parse_at(&self, text: &str, offset: usize) -> Step<T> {
+        (self.parse_fn)(text, offset)
+    }
+
+    pub fn map<U, F>(self, map_fn: F) -> Combinator<U> where U: 'static, F: Fn(T) -> U + Send + Sync + 'static {
+        Combinator::new(move |text, offset|self.parse_at(text, offset).map(|(value, next)|(map_fn(value), next)))
+    }
+
+    /// Maps the parsed value with a fallible function; its error is reported as expectation at the start of the value
+    pub fn try_map<U, F>(self, map_fn: F) -> Combinator<U> where U: 'static, F: Fn(T) -> Result<U, String> + Send + Sync + 'static {
+        Combinator::new(move |text, offset|{
+            let (value, next) = self.parse_at(text, offset)?;
+            map_fn(value).map(|mapped|(mapped, next)).map_err(|expected|Failure { offset, expected })
+        })
+    }
+
+    /// Parses `self` followed by `next`, keeping both values
+    pub fn then<U: 'static>(self, next: Combinator<U>) -> Combinator<(T, U)> {
+        Combinator::new(move |text, offset|{
+            let (first, offset) = self.parse_at(text, offset)?;
+            let (second, offset) = next.parse_at(text, offset)?;
+            Ok(((first, second), offset))
+        })
+    }
+
+    /// Parses `self` followed by `next`, keeping only the value of `self`
+    pub fn then_skip<U: 'static>(self, next: Combinator<U>) -> Combinator<T> {
+        self.then(next).map(|(first, _)|first)
+    }
+
+    /// Parses `self` followed by `next`, keeping only the value of `next`
+    pub fn skip_then<U: 'static>(self, next: Combinator<U>) -> Combinator<U> {
+        self.then(next).map(|(_, second)|second)
+    }
+
+    /// Parses `self` if possible, otherwise consumes nothing
+    pub fn optional(self) -> Combinator<Option<T>> {
+        Combinator::new(move |text, offset|Ok(match self.parse_at(text, offset) {
+            Ok((value, next)) => (Some(value), next),
+            Err(_) => (None, offset),
+        }))
+    }
+
+    /// Parses one or more occurrences of `self` separated by `separator`.
+    /// A trailing separator is not consumed.
+    pub fn separated_by<S: 'static>(self, separator: Combinator<S>) -> Combinator<Vec<T>> {
+        Combinator::new(move |text, offset|{
+            let (first, mut offset) = self.parse_at(text, offset)?;
+            let mut values = vec![first];
+            while let Ok((value, next)) = separator.parse_at(text, offset)
+                .and_then(|(_, after_separator)|self.parse_at(text, after_separator)) {
+                values.push(value);
+                offset = next;
+            }
+            Ok((values, offset))
+        })
+    }
+
+    /// Parses `self` surrounded by optional spaces
+    pub fn padded(self) -> Combinator<T> {
+        spaces().skip_then(self).then_skip(spaces())
+    }
+
+    /// Parses the whole text of the line. Failures are reported with the line and the (1-based) column.
+    pub fn parse_line(&self, line: &Line, prefix: &str) -> Result<T, PipelineError> {
+        self.then_end_at(&line.text).map_err(|failure|failure_error(line, failure, prefix))
+    }
+
+    fn then_end_at(&self, text: &str) -> Result<T, Failure> {
+        let (value, offset) = self.parse_at(text, 0)?;
+        match offset == text.len() {
+            true => Ok(value),
+            false => Err(Failure::new(offset, "end of line")),
+        }
+    }
+}
+
+fn failure_error(line: &Line, failure: Failure, prefix: &str) -> PipelineError {
+    let column = line.column_at(failure.offset);
+    let rest = &line.text[failure.offset..];
+    let found = match rest.chars().count() {
+        0 => String::from("end of line"),
+        n if n > 12 => format!("'{}...'", rest.chars().take(12).collect::<String>()),
+        _ => format!("'{}'", rest),
+    };
+    let message = format!("{} expected {}, found {}", prefix, failure.expected, found);
+    PipelineError::at(line, column, message)
+}
+
+/// Matches the exact text
+pub fn literal(expected: &'static str) -> Combinator<()> {
+    Combinator::new(move |text, offset|match text[offset..].starts_with(expected) {
+        true => Ok(((), offset + expected.len())),
+        false => Err(Failure::new(offset, &format!("'{}'", expected))),
+    })
+}
+
+/// Matches the exact text surrounded by optional spaces
+pub fn symbol(expected: &'static str) -> Combinator<()> {
+    literal(expected).padded()
+}
+
+/// Matches one or more characters satisfying the predicate; `description` names them in failures
+pub fn token<P>(description: &'static str, predicate: P) -> Combinator<String> where P: Fn(char) -> bool + Send + Sync + 'static {
+    Combinator::new(move |text, offset|{
+        let length: usize = text[offset..].chars().take_while(|&c|predicate(c)).map(char::len_utf8).sum();
+        match length {
+            0 => Err(Failure::new(offset, description)),
+            _ => Ok((text[offset..offset + length].to_string(), offset + length)),
+        }
+    })
+}
+
+/// Matches zero or more spaces
+pub fn spaces() -> Combinator<()> {
+    Combinator::new(|text, offset|Ok(((), offset + text[offset..].len() - text[offset..].trim_start_matches(' ').len())))
+}
+
+/// Matches one or more spaces
+pub fn spaces1() -> Combinator<()> {
+    token("space", |c|c == ' ').map(|_|())
+}
+
+/// Matches an optionally negative integer that fits into `N`
+pub fn integer<N>() -> Combinator<N> where N: FromStr + 'static, N::Err: Display {
+    let sign = literal("-").optional();
+    let digits = token("digit", |c|c.is_ascii_digit());
+    sign.then(digits)
+        .try_map(|(sign, digits)|{
+            let number = format!("{}{}", if sign.is_some() { "-" } else { "" }, digits);
+            number.parse::<N>().map_err(|e|format!("a valid number instead of '{}' ({})", number, e))
+        })
+}
+
+/// Parses each line with the combinator
+pub fn each_line<T: 'static>(lines: &[Line], combinator: &Combinator<T>, prefix: &str) -> Result<Vec<T>, PipelineError> {
+    lines.iter().map(|line|combinator.parse_line(line, prefix)).collect()
+}
+
+/// Returns the only line, or an error if there is not exactly one line
+pub fn single_line<'a>(lines: &'a [Line], prefix: &str) -> Result<&'a Line, PipelineError> {
+    match lines {
+        [line] => Ok(line),
+        _ => Err(format!("{} expected exactly one line, but there are {}", prefix, lines.len()).into()),
+    }
+}
+
+/// Splits the lines into consecutive groups of `size` lines, or returns an error if they cannot be split evenly
+pub fn chunks<'a>(lines: &'a [Line], size: usize, prefix: &str) -> Result<Vec<&'a [Line]>, PipelineError> {
+    match size > 0 && lines.len().is_multiple_of(size) {
+        true => Ok(lines.chunks(size).collect()),
+        false => Err(format!("{} expected the lines to form groups of {}, but there are {} lines", prefix, size, lines.len()).into()),
+    }
+}
+
+/// Splits the lines into blocks separated by empty lines; empty blocks are left out
+pub fn blocks(lines: &[Line]) -> Vec<&[Line]> {
+    lines.split(|line|line.text.trim().is_empty())
+        .filter(|block|!block.is_empty())
+        .collect()
+}
+
+/// Parses the lines as a rectangular grid of characters, mapping each character with `cell_fn`.
+/// Characters for which `cell_fn` returns `None` are reported with their line and column as not being `expected`.
+pub fn char_grid<T, F>(lines: &[Line], expected: &str, prefix: &str, cell_fn: F) -> Result<Table<T>, PipelineError>
+where F: Fn(char) -> Option<T> {
+    let mut rows = vec![];
+    for line in lines {
+        let mut row = vec![];
+        for (offset, c) in line.text.char_indices() {
+            row.push(cell_fn(c).ok_or_else(||failure_error(line, Failure::new(offset, expected), prefix))?);
+        }
+        rows.push(row);
+    }
+    Table::new(rows).map_err(|e|PipelineError::new(format!("{} {}", prefix, e)))
+}
@@ -6,4 +6,27 @@ where T: Display {
         .map(|item|item.to_string())
         .collect::<Vec<_>>()
         .join(separator)
+}
+
+/// Renders rows of cells as a plain-text table with a header line. Each column is
+/// left-aligned and as wide as its widest cell; rows shorter than the header are padded.
+pub fn text_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<_> = headers.iter().map(|header|header.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let render_row = |cells: Vec<&str>| -> String {
+        let padded = widths.iter().enumerate()
+            .map(|(i, &width)|format!("{:<width$}", cells.get(i).copied().unwrap_or(""), width = width))
+            .collect::<Vec<_>>();
+        vector_display(&padded, " | ").trim_end().to_string()
+    };
+
+    let separator = widths.iter().map(|&width|"-".repeat(width)).collect::<Vec<_>>();
+    let mut lines = vec![render_row(headers.to_vec()), vector_display(&separator, "-+-")];
+    lines.extend(rows.iter().map(|row|render_row(row.iter().map(String::as_str).collect())));
+    vector_display(&lines, "\n")
 }
\ No newline at end of file
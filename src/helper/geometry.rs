@@ -0,0 +1,130 @@
+use std::{fmt::{Debug, Display}, hash::Hash, ops::{Add, Mul, Neg, Sub}};
+
+use super::position::UPosition;
+
+/// Numeric type usable as a coordinate of `Point` and `Vector`
+pub trait Coordinate: Copy + Eq + Ord + Hash + Debug + Display + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+
+    /// Non-negative remainder of the division by `modulus`
+    fn rem_euclid(self, modulus: Self) -> Self;
+
+    fn abs_diff(self, other: Self) -> Self {
+        if self < other { other - self } else { self - other }
+    }
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            const ZERO: $t = 0;
+
+            fn rem_euclid(self, modulus: $t) -> $t { <$t>::rem_euclid(self, modulus) }
+        })*
+    };
+}
+
+coordinate!(i32, i64, isize, u32, u64, usize);
+
+/// Point of a plane with `x` growing to the right (columns) and `y` growing downwards (rows)
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Displacement between two points
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Vector<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl <T: Coordinate> Point<T> {
+    pub fn new(x: T, y: T) -> Point<T> { Point { x, y } }
+
+    pub fn manhattan(self, other: Point<T>) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Point<T>) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Returns true if the point lies in the area spanning from the origin (inclusive) to `size` (exclusive)
+    pub fn is_within(self, size: Vector<T>) -> bool {
+        T::ZERO <= self.x && self.x < size.x && T::ZERO <= self.y && self.y < size.y
+    }
+
+    /// Wraps the point around the edges of the area spanning from the origin to `size`, as on a torus
+    pub fn wrap(self, size: Vector<T>) -> Point<T> {
+        Point::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+    }
+}
+
+impl <T: Coordinate> Vector<T> {
+    pub fn new(x: T, y: T) -> Vector<T> { Vector { x, y } }
+
+    pub fn manhattan_length(self) -> T {
+        self.x.abs_diff(T::ZERO) + self.y.abs_diff(T::ZERO)
+    }
+
+    pub fn chebyshev_length(self) -> T {
+        self.x.abs_diff(T::ZERO).max(self.y.abs_diff(T::ZERO))
+    }
+}
+
+impl <T: Coordinate> Add<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+    fn add(self, vector: Vector<T>) -> Point<T> { Point::new(self.x + vector.x, self.y + vector.y) }
+}
+
+impl <T: Coordinate> Sub<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+    fn sub(self, vector: Vector<T>) -> Point<T> { Point::new(self.x - vector.x, self.y - vector.y) }
+}
+
+impl <T: Coordinate> Sub for Point<T> {
+    type Output = Vector<T>;
+    fn sub(self, other: Point<T>) -> Vector<T> { Vector::new(self.x - other.x, self.y - other.y) }
+}
+
+impl <T: Coordinate> Add for Vector<T> {
+    type Output = Vector<T>;
+    fn add(self, other: Vector<T>) -> Vector<T> { Vector::new(self.x + other.x, self.y + other.y) }
+}
+
+impl <T: Coordinate> Sub for Vector<T> {
+    type Output = Vector<T>;
+    fn sub(self, other: Vector<T>) -> Vector<T> { Vector::new(self.x - other.x, self.y - other.y) }
+}
+
+impl <T: Coordinate> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+    fn mul(self, factor: T) -> Vector<T> { Vector::new(self.x * factor, self.y * factor) }
+}
+
+impl <T: Coordinate + Neg<Output = T>> Neg for Vector<T> {
+    type Output = Vector<T>;
+    fn neg(self) -> Vector<T> { Vector::new(-self.x, -self.y) }
+}
+
+impl From<UPosition> for Point<usize> {
+    fn from(position: UPosition) -> Point<usize> { Point::new(position.col, position.row) }
+}
+
+impl From<Point<usize>> for UPosition {
+    fn from(point: Point<usize>) -> UPosition { UPosition { row: point.y, col: point.x } }
+}
+
+impl <T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+impl <T: Display> Display for Vector<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{},{}>", self.x, self.y)
+    }
+}
@@ -0,0 +1,203 @@
+use std::{fmt::{Debug, Display}, sync::Arc};
+
+use crate::{parser::Parse, pipeline_error::PipelineError, reader::{Line, VecLine}, verifier::{check, Verify}};
+
+use super::{combinator::char_grid, position::UPosition, table::Table};
+
+/// Mapping between the characters of an input grid and the fields they stand for.
+/// It works in both directions, so the same mapping can be used to parse and to display fields.
+pub struct CharMapping<F: 'static> {
+    pairs: &'static [(char, F)],
+}
+
+impl <F: Copy + Eq> CharMapping<F> {
+    pub const fn new(pairs: &'static [(char, F)]) -> CharMapping<F> {
+        CharMapping { pairs }
+    }
+
+    /// Returns the field the character stands for
+    pub fn field(&self, c: char) -> Option<F> {
+        self.pairs.iter().find(|&&(mapped, _)|mapped == c).map(|&(_, field)|field)
+    }
+
+    /// Returns the character standing for the field
+    pub fn char(&self, field: F) -> Option<char> {
+        self.pairs.iter().find(|&&(_, mapped)|mapped == field).map(|&(c, _)|c)
+    }
+
+    /// Displays the field by its character, or `?` if the field is not mapped
+    pub fn fmt(&self, field: F, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.char(field).unwrap_or('?'))
+    }
+
+    fn expected(&self) -> String {
+        let chars = self.pairs.iter().map(|(c, _)|format!("'{}'", c)).collect::<Vec<_>>();
+        format!("one of {}", chars.join(", "))
+    }
+}
+
+/// How many times an indexed field may occur in the grid
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Occurrence {
+    ExactlyOne,
+    AtMostOne,
+}
+
+/// Where a row of the grid was read from: its line number, the full source line and the offset of the row in it
+#[derive(Debug)]
+struct RowLocation {
+    number: usize,
+    source: Arc<str>,
+    offset: usize,
+}
+
+/// Where the rows of the grid were read from, so that errors can point at its cells.
+/// The locations are shared between clones, and are not part of the grid equality.
+#[derive(Clone, Debug)]
+struct CellLocations {
+    path: Option<Arc<str>>,
+    rows: Arc<[RowLocation]>,
+}
+
+impl CellLocations {
+    fn new(lines: &[Line]) -> CellLocations {
+        let path = lines.first().and_then(|line|line.path.clone());
+        let rows = lines.iter()
+            .map(|line|RowLocation { number: line.number, source: line.source.clone(), offset: line.offset })
+            .collect();
+        CellLocations { path, rows }
+    }
+
+    /// Returns the input line of the row, as it was before sanitising
+    fn line(&self, row: usize) -> Option<Line> {
+        self.rows.get(row).map(|location|Line {
+            text: location.source.chars().skip(location.offset).collect(),
+            number: location.number,
+            path: self.path.clone(),
+            source: location.source.clone(),
+            offset: location.offset,
+        })
+    }
+}
+
+impl PartialEq for CellLocations {
+    fn eq(&self, _: &CellLocations) -> bool {
+        true
+    }
+}
+
+impl Eq for CellLocations {}
+
+/// Grid of fields together with the positions of its special (indexed) fields,
+/// and the locations of its cells in the input
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Grid<F> {
+    pub table: Table<F>,
+    special: Vec<(F, Vec<UPosition>)>,
+    locations: CellLocations,
+}
+
+impl <F: Copy + Eq + Debug> Grid<F> {
+    /// Returns the positions of the indexed field, in row-major order. Fields that are not indexed have no positions.
+    pub fn positions(&self, field: F) -> &[UPosition] {
+        self.special.iter()
+            .find(|(indexed, _)|*indexed == field)
+            .map_or(&[], |(_, positions)|positions.as_slice())
+    }
+
+    /// Returns the position of the indexed field, or an error if it does not occur exactly once
+    pub fn single(&self, field: F) -> Result<UPosition, String> {
+        match self.positions(field) {
+            [position] => Ok(*position),
+            positions => Err(format!("expected exactly one {:?} in the grid, but there are {}", field, positions.len())),
+        }
+    }
+
+    /// Creates an error pointing at the cell at the position, in the input line it was parsed from
+    pub fn error_at(&self, position: UPosition, message: String) -> PipelineError {
+        match self.locations.line(position.row) {
+            Some(line) => PipelineError::at(&line, line.source_column(position.col + 1), message),
+            None => PipelineError::new(message),
+        }
+    }
+}
+
+/// Verifies the indexed field occurs exactly once in the grid, prefixing the error with the prefix
+pub fn single_field<F>(prefix: &'static str, field: F) -> impl Verify<Grid<F>> where F: Copy + Eq + Debug + Send + Sync {
+    check(move |grid: &Grid<F>|grid.single(field).map(|_|()).map_err(|message|format!("{} {}", prefix, message)))
+}
+
+impl <F: Display> Display for Grid<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.table)
+    }
+}
+
+/// Parser of a rectangular grid of characters into a `Grid` of fields, using the given `CharMapping`.
+/// Fields registered with `exactly_one` or `at_most_one` have their positions indexed and their
+/// number of occurrences checked.
+pub struct GridParser<F: 'static> {
+    prefix: &'static str,
+    mapping: CharMapping<F>,
+    indexed: Vec<(F, Occurrence)>,
+}
+
+impl <F: Copy + Eq + Debug> GridParser<F> {
+    pub fn new(prefix: &'static str, mapping: CharMapping<F>) -> GridParser<F> {
+        GridParser { prefix, mapping, indexed: vec![] }
+    }
+
+    pub fn exactly_one(self, field: F) -> GridParser<F> {
+        self.with_occurrence(field, Occurrence::ExactlyOne)
+    }
+
+    pub fn at_most_one(self, field: F) -> GridParser<F> {
+        self.with_occurrence(field, Occurrence::AtMostOne)
+    }
+
+    fn with_occurrence(mut self, field: F, occurrence: Occurrence) -> GridParser<F> {
+        self.indexed.push((field, occurrence));
+        self
+    }
+
+    fn describe(&self, field: F) -> String {
+        self.mapping.char(field).map_or(format!("{:?}", field), |c|format!("'{}'", c))
+    }
+
+    fn check(&self, lines: &[Line], field: F, occurrence: Occurrence, positions: &[UPosition]) -> Result<(), PipelineError> {
+        match (occurrence, positions) {
+            (Occurrence::ExactlyOne, []) => Err(PipelineError::new(
+                format!("{} expected exactly one {}, but there is none", self.prefix, self.describe(field)))),
+            (Occurrence::ExactlyOne | Occurrence::AtMostOne, [_, second, ..]) => {
+                let line = &lines[second.row];
+                let column = line.source_column(second.col + 1);
+                let quantity = if occurrence == Occurrence::ExactlyOne { "exactly" } else { "at most" };
+                let message = format!("{} expected {} one {}, but found another one",
+                    self.prefix, quantity, self.describe(field));
+                Err(PipelineError::at(line, column, message))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Parses the lines into a grid, checking the occurrences of the indexed fields
+    pub fn parse_lines(&self, lines: &[Line]) -> Result<Grid<F>, PipelineError> {
+        let table = char_grid(lines, &self.mapping.expected(), self.prefix, |c|self.mapping.field(c))?;
+        let mut special = vec![];
+        for &(field, occurrence) in &self.indexed {
+            let positions = table.iter()
+                .filter(|&(_, &cell)|cell == field)
+                .map(|(position, _)|position)
+                .collect::<Vec<_>>();
+            self.check(lines, field, occurrence, &positions)?;
+            special.push((field, positions));
+        }
+        Ok(Grid { table, special, locations: CellLocations::new(lines) })
+    }
+}
+
+impl <F> Parse<Grid<F>> for GridParser<F> where F: Copy + Eq + Display + Debug + Send + Sync {
+    fn parse(&self, vec_line: VecLine) -> Result<Grid<F>, PipelineError> {
+        self.parse_lines(&vec_line.lines)
+    }
+}
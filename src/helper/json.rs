@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+use super::display::vector_display;
+
+/// A minimal JSON value, rendered compactly on a single line by its `Display` implementation.
+/// Numbers are kept in their textual form, so that no precision is lost on large integers.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum JsonValue {
+    Null,
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    /// Object with fields kept in insertion order
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Creates an object from the given fields
+    pub fn object(fields: Vec<(&str, JsonValue)>) -> JsonValue {
+        JsonValue::Object(fields.into_iter().map(|(name, value)|(name.to_string(), value)).collect())
+    }
+
+    pub fn number<T: Display>(number: T) -> JsonValue {
+        JsonValue::Number(number.to_string())
+    }
+
+    pub fn string<T: Display>(text: T) -> JsonValue {
+        JsonValue::String(text.to_string())
+    }
+
+    /// Creates a number if the displayed value is an integer in its canonical form, and a string otherwise.
+    /// Texts such as `+5` or `007` parse as integers, but are not valid JSON numbers.
+    pub fn from_display<T: Display>(value: T) -> JsonValue {
+        let text = value.to_string();
+        match text.parse::<i128>().is_ok_and(|number|number.to_string() == text) {
+            true => JsonValue::Number(text),
+            false => JsonValue::String(text),
+        }
+    }
+
+    /// Maps `None` to `null` and `Some` using the given function
+    pub fn from_option<T, F>(option: Option<T>, value_fn: F) -> JsonValue where F: FnOnce(T) -> JsonValue {
+        option.map_or(JsonValue::Null, value_fn)
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+impl Display for JsonValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonValue::Null => write!(f, "null"),
+            JsonValue::Number(number) => write!(f, "{}", number),
+            JsonValue::String(text) => write!(f, "{}", escape(text)),
+            JsonValue::Array(items) => write!(f, "[{}]", vector_display(items, ",")),
+            JsonValue::Object(fields) => {
+                let fields = fields.iter()
+                    .map(|(name, value)|format!("{}:{}", escape(name), value))
+                    .collect::<Vec<_>>();
+                write!(f, "{{{}}}", vector_display(&fields, ","))
+            },
+        }
+    }
+}
@@ -84,7 +84,6 @@ impl Delta {
     }
 }
 
-#[allow(dead_code)]
 pub mod unit {
     use super::{Movement, Delta::{Dec, Inc}};
 
@@ -14,7 +14,6 @@ impl UPosition {
 
     pub fn zero() -> UPosition { UPosition::new((0, 0)) }
 
-    #[allow(dead_code)]
     /// Returns it as pair of `usize` : `(row, col)`
     pub fn as_pair(&self) -> (usize, usize) {
         (self.row, self.col)
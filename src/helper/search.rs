@@ -0,0 +1,174 @@
+use std::{cmp::Ordering, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, hash::Hash};
+
+/// Outcome of a search from one or more start states: the distance of every reached state
+/// and, for every reached state other than the starts, the set of its predecessors on shortest paths.
+pub struct SearchResult<S> {
+    pub distances: HashMap<S, u64>,
+    pub predecessors: HashMap<S, Vec<S>>,
+    /// States already expanded; equally short paths found to them later are not recorded,
+    /// so that zero-cost steps cannot make the predecessors cyclic. A strictly shorter path re-opens them.
+    settled: HashSet<S>,
+}
+
+/// A path from a start state to a goal state (both included) with its total cost
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Path<S> {
+    pub cost: u64,
+    pub states: Vec<S>,
+}
+
+impl <S: Copy + Eq + Hash> SearchResult<S> {
+    fn new() -> SearchResult<S> {
+        SearchResult { distances: HashMap::new(), predecessors: HashMap::new(), settled: HashSet::new() }
+    }
+
+    pub fn distance(&self, state: S) -> Option<u64> {
+        self.distances.get(&state).copied()
+    }
+
+    /// Records reaching `next` from `current` with the given distance. Returns true if it is a new shortest distance,
+    /// in which case `next` has to be expanded (again).
+    fn relax(&mut self, current: S, next: S, distance: u64) -> bool {
+        match self.distances.get(&next).map(|known|distance.cmp(known)) {
+            Some(Ordering::Greater) => false,
+            Some(Ordering::Equal) if self.settled.contains(&next) => false,
+            Some(Ordering::Equal) => {
+                let predecessors = self.predecessors.entry(next).or_default();
+                if !predecessors.contains(&current) { predecessors.push(current); }
+                false
+            },
+            _ => {
+                self.distances.insert(next, distance);
+                self.predecessors.insert(next, vec![current]);
+                self.settled.remove(&next);
+                true
+            },
+        }
+    }
+
+    /// Returns one shortest path to the goal, or `None` if the goal was not reached
+    pub fn path_to(&self, goal: S) -> Option<Path<S>> {
+        let cost = self.distance(goal)?;
+        let mut states = vec![goal];
+        while let Some(&previous) = self.predecessors.get(&states[states.len() - 1]).and_then(|predecessors|predecessors.first()) {
+            states.push(previous);
+        }
+        states.reverse();
+        Some(Path { cost, states })
+    }
+
+    /// Returns the number of distinct shortest paths from the starts to the goal, or 0 if the goal was not reached
+    pub fn path_count(&self, goal: S) -> u64 {
+        self.count_paths(goal, &mut HashMap::new())
+    }
+
+    fn count_paths(&self, state: S, counts: &mut HashMap<S, u64>) -> u64 {
+        if let Some(&count) = counts.get(&state) { return count; }
+        let count = match self.predecessors.get(&state) {
+            Some(predecessors) => predecessors.iter().map(|&previous|self.count_paths(previous, counts)).sum(),
+            None => u64::from(self.distances.contains_key(&state)),
+        };
+        counts.insert(state, count);
+        count
+    }
+
+    /// Returns all the states lying on any shortest path to any of the goals
+    pub fn states_on_paths_to(&self, goals: &[S]) -> HashSet<S> {
+        let mut on_paths = HashSet::new();
+        let mut stack = goals.iter().copied().filter(|goal|self.distances.contains_key(goal)).collect::<Vec<_>>();
+        while let Some(state) = stack.pop() {
+            if on_paths.insert(state) {
+                stack.extend(self.predecessors.get(&state).into_iter().flatten().copied());
+            }
+        }
+        on_paths
+    }
+}
+
+/// Breadth-first search where every step costs 1
+pub fn bfs<S, I, F>(starts: impl IntoIterator<Item = S>, mut successors: F) -> SearchResult<S>
+where S: Copy + Eq + Hash, I: IntoIterator<Item = S>, F: FnMut(S) -> I {
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if result.distances.insert(start, 0).is_none() { queue.push_back(start); }
+    }
+
+    while let Some(current) = queue.pop_front() {
+        let distance = result.distances[&current] + 1;
+        for next in successors(current) {
+            if result.relax(current, next, distance) { queue.push_back(next); }
+        }
+    }
+    result
+}
+
+/// Entry of the priority queue, ordered so that `BinaryHeap` pops the lowest priority first
+struct Entry<S> {
+    priority: u64,
+    distance: u64,
+    state: S,
+}
+
+impl <S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl <S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl <S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl <S> Eq for Entry<S> {}
+
+/// Best-first search guided by `heuristic`; stops once a state satisfying `is_goal` is settled.
+/// Without a goal and with a zero heuristic it is Dijkstra's algorithm exploring all reachable states.
+fn best_first<S, I, F, H, G>(starts: impl IntoIterator<Item = S>, mut successors: F, heuristic: H, is_goal: G) -> (SearchResult<S>, Option<S>)
+where S: Copy + Eq + Hash, I: IntoIterator<Item = (S, u64)>, F: FnMut(S) -> I, H: Fn(S) -> u64, G: Fn(S) -> bool {
+    let mut result = SearchResult::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if result.distances.insert(start, 0).is_none() {
+            queue.push(Entry { priority: heuristic(start), distance: 0, state: start });
+        }
+    }
+
+    while let Some(Entry { distance, state: current, .. }) = queue.pop() {
+        // skip entries superseded by a shorter distance found later
+        if distance > result.distances[&current] || !result.settled.insert(current) { continue; }
+        if is_goal(current) { return (result, Some(current)); }
+
+        for (next, cost) in successors(current) {
+            let next_distance = distance + cost;
+            if result.relax(current, next, next_distance) {
+                queue.push(Entry { priority: next_distance + heuristic(next), distance: next_distance, state: next });
+            }
+        }
+    }
+    (result, None)
+}
+
+/// Dijkstra's algorithm with non-negative step costs, exploring all states reachable from the starts
+pub fn dijkstra<S, I, F>(starts: impl IntoIterator<Item = S>, successors: F) -> SearchResult<S>
+where S: Copy + Eq + Hash, I: IntoIterator<Item = (S, u64)>, F: FnMut(S) -> I {
+    best_first(starts, successors, |_|0, |_|false).0
+}
+
+/// A* search from `start` to the nearest state satisfying `is_goal`.
+/// The `heuristic` must never overestimate the remaining cost, otherwise the returned path need not be the shortest.
+/// States are expanded again whenever a shorter path to them is found, so the heuristic need not be consistent;
+/// with a consistent one every state is expanded at most once.
+pub fn a_star<S, I, F, H, G>(start: S, successors: F, heuristic: H, is_goal: G) -> Option<Path<S>>
+where S: Copy + Eq + Hash, I: IntoIterator<Item = (S, u64)>, F: FnMut(S) -> I, H: Fn(S) -> u64, G: Fn(S) -> bool {
+    let (result, goal) = best_first([start], successors, heuristic, is_goal);
+    goal.and_then(|goal|result.path_to(goal))
+}
@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use crate::helper::display::vector_display;
 
-use super::{boundary::Boundary, position::UPosition};
+use super::{boundary::Boundary, movement::unit, position::UPosition};
 
 
 /// Table represents a vector of vectors, a container with rows and columns, but with added
@@ -25,6 +25,10 @@ mod error {
             format!("but row #{} has {} columns", i, row_i_column_count)
         ], " ")
     }
+
+    pub fn zip_dimension_error(dim: (usize, usize), other_dim: (usize, usize)) -> String {
+        format!("could not zip tables of different dimensions {:?} and {:?}", dim, other_dim)
+    }
 }
 
 impl <T> Table<T> {
@@ -42,7 +46,6 @@ impl <T> Table<T> {
         Ok(Table { table: adjusted_input, dim: (adjusted_row_count, col_count) })
     }
 
-    #[allow(dead_code)]
     /// Returns the dimensions of the table as `(number of rows, number of columns)`
     pub fn dim(&self) -> (usize, usize) { self.dim }
 
@@ -60,15 +63,94 @@ impl <T> Table<T> {
         self.table.get(row).and_then(|r|r.get(col))
     }
 
+    pub fn get_mut(&mut self, pos: UPosition) -> Option<&mut T> {
+        let UPosition { row, col } = pos;
+        self.table.get_mut(row).and_then(|r|r.get_mut(col))
+    }
+
+    /// Sets the value at the position, returning the previous one. Returns `None` if the position is out of the table.
+    pub fn set(&mut self, pos: UPosition, value: T) -> Option<T> {
+        self.get_mut(pos).map(|current|std::mem::replace(current, value))
+    }
+
     pub fn iter(&self) -> TableIterator<T> {
         TableIterator { table: &self, current_position: UPosition::zero() }
     }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        self.table.get(row).map(|r|r.as_slice())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.table.iter().map(|r|r.as_slice())
+    }
+
+    /// Returns the entries of the column from top to bottom, or `None` if there is no such column
+    pub fn column(&self, col: usize) -> Option<Vec<&T>> {
+        if col < self.dim.1 { Some(self.table.iter().map(|r|&r[col]).collect()) } else { None }
+    }
+
+    /// Returns the up to 4 positions adjacent to `pos` horizontally or vertically that lie in the table
+    pub fn neighbours(&self, pos: UPosition) -> impl Iterator<Item = UPosition> {
+        let boundary = self.boundary();
+        unit::all_partial().into_iter().filter_map(move |movement|boundary.apply(movement, pos))
+    }
+
+    /// Returns the up to 8 positions adjacent to `pos`, diagonals included, that lie in the table
+    pub fn neighbours_with_diagonals(&self, pos: UPosition) -> impl Iterator<Item = UPosition> {
+        let boundary = self.boundary();
+        unit::all().into_iter().filter_map(move |movement|boundary.apply(movement, pos))
+    }
+
+    /// Creates a new table of the same shape by applying `map_fn` to each entry
+    pub fn map<U, F>(&self, map_fn: F) -> Table<U> where F: Fn(&T) -> U {
+        Table {
+            table: self.table.iter().map(|r|r.iter().map(&map_fn).collect()).collect(),
+            dim: self.dim,
+        }
+    }
+
+    /// Creates a new table by combining the entries on the same positions of two tables of equal dimensions
+    pub fn zip<U, V, F>(&self, other: &Table<U>, zip_fn: F) -> Result<Table<V>, String> where F: Fn(&T, &U) -> V {
+        if self.dim != other.dim {
+            return Err(error::zip_dimension_error(self.dim, other.dim));
+        }
+        let table = self.table.iter().zip(other.table.iter())
+            .map(|(r, other_r)|r.iter().zip(other_r.iter()).map(|(a, b)|zip_fn(a, b)).collect())
+            .collect();
+        Ok(Table { table, dim: self.dim })
+    }
+}
+
+impl <T: Clone> Table<T> {
+    /// Returns the table with rows and columns swapped
+    pub fn transpose(&self) -> Table<T> {
+        let (rows, cols) = self.dim;
+        Table {
+            table: (0..cols).map(|c|(0..rows).map(|r|self.table[r][c].clone()).collect()).collect(),
+            dim: (cols, rows),
+        }
+    }
+
+    /// Returns the table rotated by 90 degrees clockwise
+    pub fn rotate(&self) -> Table<T> {
+        let mut rotated = self.transpose();
+        rotated.table.iter_mut().for_each(|r|r.reverse());
+        rotated
+    }
 }
 
+/// Renders the table as text, one line per row. Entries rendered as single characters, e.g. fields of a map,
+/// are written next to each other; otherwise the entries are right-aligned to a common width and separated by a space.
 impl <T> Display for Table<T> where T: Display {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let formatted_rows = self.table.iter().map(|row|vector_display(row, " ")).collect();
-        write!(f, "[{}]", vector_display(&formatted_rows, ","))
+        let entries = self.table.iter().map(|row|row.iter().map(|entry|entry.to_string()).collect::<Vec<_>>()).collect::<Vec<_>>();
+        let width = entries.iter().flatten().map(|entry|entry.chars().count()).max().unwrap_or(0);
+        let separator = if width > 1 { " " } else { "" };
+        let formatted_rows = entries.iter()
+            .map(|row|vector_display(&row.iter().map(|entry|format!("{:>width$}", entry)).collect(), separator))
+            .collect();
+        write!(f, "{}", vector_display(&formatted_rows, "\n"))
     }
 }
 
@@ -0,0 +1,61 @@
+//! Advent of Code 2024 solutions, built on a pipeline of reading, parsing, verifying and solving the puzzle input.
+//!
+//! Each day is a module `day_XY` exposing its model and solver types and its pipeline constructor `make_pipeline`,
+//! together with its metadata in `DAYS`.
+//! The executers of all days are registered in an `ExecuterManager` by `create_executer_manager`.
+
+pub mod reading;
+pub mod answer;
+pub mod parser;
+pub mod solver;
+pub mod verifier;
+pub mod day;
+pub mod executer;
+pub mod pipeline_error;
+pub mod reader;
+pub mod parameters;
+pub mod pipelined_executer;
+pub mod executer_manager;
+pub mod execution_summary;
+pub mod benchmark;
+pub mod regression;
+pub mod watch;
+pub mod repl;
+pub mod helper;
+mod testing;
+
+/// Declares the day modules and collects their metadata, declared by `define_day!`, into `DAYS`.
+/// Listing the module here is all it takes to register a day.
+macro_rules! days {
+    ($($day:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        /// Metadata of all days, in the order of their numbers
+        pub const DAYS: &[&day::Day] = &[$(&$day::DAY),*];
+    };
+}
+
+days!(
+    day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10,
+    day_11, day_12, day_13, day_14, day_15, day_16, day_17, day_18, day_19, day_20,
+    day_21, day_22, day_23, day_24, day_25,
+);
+
+pub use answer::Answer;
+
+use executer_manager::ExecuterManager;
+use parameters::Parameters;
+use reading::*;
+
+/// Creates the executer manager with all the days, using the default values of their parameters
+pub fn create_executer_manager() -> Result<ExecuterManager, String> {
+    create_executer_manager_with(Parameters::new())
+}
+
+/// Creates the executer manager with all the days, making their pipelines from the given parameter overrides
+pub fn create_executer_manager_with(parameters: Parameters) -> Result<ExecuterManager, String> {
+    let manager = ExecuterManager::new()?.with_parameters(parameters);
+    DAYS.iter()
+        .try_fold(manager, |manager, day|(day.register)(manager))
+        .and_then(ExecuterManager::check_parameters)
+}
@@ -0,0 +1,189 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+mod error {
+    const PREFIX: &str = "[Parameters]";
+
+    pub fn invalid_value(key: &str, value: &str, reason: String) -> String {
+        format!("{} parameter '{}' has an invalid value '{}' ({})", PREFIX, key, value, reason)
+    }
+
+    pub fn missing(key: &str) -> String {
+        format!("{} parameter '{}' is not set", PREFIX, key)
+    }
+
+    pub fn out_of_range(key: &str, value: &str, kind: &super::ParameterKind) -> String {
+        format!("{} parameter '{}' has an invalid value '{}' (expected {})", PREFIX, key, value, kind)
+    }
+}
+
+/// Key/value parameters of a pipeline. They are declared in the input file (for example `//! width=11 height=7`)
+/// or supplied on the command line, and resolved against the parameters a day declares (see `ParameterSpec`).
+/// A key supplied on the command line can be scoped to a single day as `dXY.key`, e.g. `d20.cutoff=50`.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Parameters {
+    values: BTreeMap<String, String>,
+}
+
+impl Parameters {
+    pub fn new() -> Parameters { Parameters::default() }
+
+    /// Sets the value of the parameter, returning its previous value
+    pub fn insert(&mut self, key: &str, value: &str) -> Option<String> {
+        self.values.insert(key.to_string(), value.to_string())
+    }
+
+    /// Removes the parameter, returning its value
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.values.remove(key)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns these parameters with the values of `overrides` taking precedence
+    pub fn overridden_by(&self, overrides: &Parameters) -> Parameters {
+        let mut values = self.values.clone();
+        values.extend(overrides.values.iter().map(|(key, value)|(key.clone(), value.clone())));
+        Parameters { values }
+    }
+
+    /// Returns the parameters applying to the day: the unscoped ones, overridden by the ones scoped to the day
+    pub fn for_day(&self, day: u8) -> Parameters {
+        let (mut unscoped, mut scoped) = (Parameters::new(), Parameters::new());
+        for (key, value) in &self.values {
+            match Self::scope(key) {
+                (None, key) => unscoped.insert(key, value),
+                (Some(scope), key) if scope == day => scoped.insert(key, value),
+                _ => None,
+            };
+        }
+        unscoped.overridden_by(&scoped)
+    }
+
+    /// Splits the key into the day it is scoped to, if it has the form `dXY.key`, and the key itself
+    pub fn scope(key: &str) -> (Option<u8>, &str) {
+        let scoped = key.split_once('.').and_then(|(scope, name)|match scope.strip_prefix('d') {
+            Some(day) if day.len() == 2 => day.parse::<u8>().ok().map(|day|(Some(day), name)),
+            _ => None,
+        });
+        scoped.unwrap_or((None, key))
+    }
+
+    /// Returns the keys of all parameters, in alphabetical order
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    /// Returns the parsed value of the parameter, or the `default` if the parameter is not set
+    pub fn get_or<T>(&self, key: &str, default: T) -> Result<T, String> where T: FromStr, T::Err: Display {
+        match self.get(key) {
+            Some(value) => value.parse::<T>().map_err(|e|error::invalid_value(key, value, e.to_string())),
+            None => Ok(default),
+        }
+    }
+
+    /// Returns the parsed value of the parameter, or an error if the parameter is not set
+    pub fn require<T>(&self, key: &str) -> Result<T, String> where T: FromStr, T::Err: Display {
+        match self.get(key) {
+            Some(value) => value.parse::<T>().map_err(|e|error::invalid_value(key, value, e.to_string())),
+            None => Err(error::missing(key)),
+        }
+    }
+}
+
+/// Renders the parameters as `key=value` pairs separated by spaces, in alphabetical order of the keys
+impl Display for Parameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pairs = self.values.iter().map(|(key, value)|format!("{}={}", key, value)).collect::<Vec<_>>();
+        write!(f, "{}", pairs.join(" "))
+    }
+}
+
+/// Type of values a declared parameter accepts
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum ParameterKind {
+    /// Unsigned integer within the inclusive range, which is narrowed to the type the solver takes
+    Unsigned { min: u64, max: u64 },
+}
+
+impl ParameterKind {
+    fn check(&self, key: &str, value: &str) -> Result<(), String> {
+        match *self {
+            ParameterKind::Unsigned { min, max } => match value.parse::<u64>() {
+                Ok(number) if (min..=max).contains(&number) => Ok(()),
+                Ok(_) => Err(error::out_of_range(key, value, self)),
+                Err(e) => Err(error::invalid_value(key, value, e.to_string())),
+            },
+        }
+    }
+}
+
+impl Display for ParameterKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ParameterKind::Unsigned { min: 0, max: u64::MAX } => write!(f, "unsigned integer"),
+            ParameterKind::Unsigned { min, max: u64::MAX } => write!(f, "unsigned integer of at least {}", min),
+            ParameterKind::Unsigned { min, max } => write!(f, "unsigned integer in {}..={}", min, max),
+        }
+    }
+}
+
+/// Declaration of a tunable parameter of a day: its name, type, default value and what it controls.
+/// A parameter without a default value is left to the solvers, e.g. if it defaults to different values in the two parts.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct ParameterSpec {
+    pub name: &'static str,
+    pub kind: ParameterKind,
+    pub default: Option<&'static str>,
+    pub description: &'static str,
+}
+
+impl ParameterSpec {
+    pub const fn unsigned(name: &'static str, default: &'static str, description: &'static str) -> ParameterSpec {
+        ParameterSpec { name, kind: ParameterKind::Unsigned { min: 0, max: u64::MAX }, default: Some(default), description }
+    }
+
+    /// Declares an unsigned parameter whose default value is chosen by the solvers, as the description tells
+    pub const fn unsigned_by_part(name: &'static str, description: &'static str) -> ParameterSpec {
+        ParameterSpec { default: None, ..ParameterSpec::unsigned(name, "", description) }
+    }
+
+    /// Restricts the values to be at least `min`
+    pub const fn at_least(self, min: u64) -> ParameterSpec {
+        let ParameterKind::Unsigned { max, .. } = self.kind;
+        ParameterSpec { kind: ParameterKind::Unsigned { min, max }, ..self }
+    }
+
+    /// Restricts the values to be at most `max`, e.g. the maximum of the type the solver takes
+    pub const fn at_most(self, max: u64) -> ParameterSpec {
+        let ParameterKind::Unsigned { min, .. } = self.kind;
+        ParameterSpec { kind: ParameterKind::Unsigned { min, max }, ..self }
+    }
+
+    /// Resolves the values of the declared parameters: the given value if there is one, the default otherwise.
+    /// Values of declared parameters are checked against their type; given parameters that are not declared are kept as they are.
+    pub fn resolve(specs: &[ParameterSpec], given: &Parameters) -> Result<Parameters, String> {
+        let mut parameters = given.clone();
+        for spec in specs {
+            if let Some(value) = given.get(spec.name).or(spec.default) {
+                spec.kind.check(spec.name, value)?;
+                parameters.insert(spec.name, value);
+            }
+        }
+        Ok(parameters)
+    }
+}
+
+impl Display for ParameterSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.default {
+            Some(default) => write!(f, "{} ({}, default {}): {}", self.name, self.kind, default, self.description),
+            None => write!(f, "{} ({}): {}", self.name, self.kind, self.description),
+        }
+    }
+}
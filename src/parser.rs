@@ -1,13 +1,14 @@
 use std::fmt::{Debug, Display};
-use crate::reader::VecLine;
+use crate::{pipeline_error::PipelineError, reader::{Line, VecLine}};
 
 pub type Parser<T> = Box<dyn Parse<T>>;
 
-pub trait Parse<T: Eq + Display + Clone + Debug>
+pub trait Parse<T: Eq + Display + Clone + Debug>: Send + Sync
 {
-    fn parse(&self, vec_line: VecLine) -> Result<T, String>;
+    fn parse(&self, vec_line: VecLine) -> Result<T, PipelineError>;
 }
 
+#[derive(Default)]
 pub struct TrivialParser;
 
 impl TrivialParser {
@@ -15,7 +16,26 @@ impl TrivialParser {
 }
 
 impl Parse<VecLine> for TrivialParser {
-    fn parse(&self, lines: VecLine) -> Result<VecLine, String> {
+    fn parse(&self, lines: VecLine) -> Result<VecLine, PipelineError> {
         Ok(lines)
     }
-}
\ No newline at end of file
+}
+
+type LinesParseFn<T> = Box<dyn Fn(&[Line]) -> Result<T, PipelineError> + Send + Sync>;
+
+/// Parser defined by a function of the input lines, typically composed from `helper::combinator`
+pub struct LinesParser<T> {
+    parse_fn: LinesParseFn<T>,
+}
+
+impl <T> LinesParser<T> {
+    pub fn new<F>(parse_fn: F) -> LinesParser<T> where F: Fn(&[Line]) -> Result<T, PipelineError> + Send + Sync + 'static {
+        LinesParser { parse_fn: Box::new(parse_fn) }
+    }
+}
+
+impl <T: Eq + Display + Clone + Debug> Parse<T> for LinesParser<T> {
+    fn parse(&self, vec_line: VecLine) -> Result<T, PipelineError> {
+        (self.parse_fn)(&vec_line.lines)
+    }
+}
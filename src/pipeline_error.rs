@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use crate::{executer::Stage, reader::Line};
+
+/// An error produced while executing the read, parse, verify and solve pipeline.
+///
+/// Besides the human readable `message`, it carries the pipeline `stage` where it occurred,
+/// the `day` and `part` of the executed problem, optionally the offending input `line` (and
+/// the 1-based `column` of the file, which accounts for the characters sanitising removed from the line)
+/// and the error that caused it. Stage, day and part are filled in by the pipeline and the
+/// executer manager as the error propagates, so stage implementations need not provide them.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PipelineError {
+    pub stage: Option<Stage>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub line: Option<Box<Line>>,
+    pub column: Option<usize>,
+    pub message: String,
+    pub cause: Option<Box<PipelineError>>,
+}
+
+impl PipelineError {
+    pub fn new(message: String) -> PipelineError {
+        PipelineError { stage: None, day: None, part: None, line: None, column: None, message, cause: None }
+    }
+
+    /// Creates an error pointing at the offending input line
+    pub fn on_line(line: &Line, message: String) -> PipelineError {
+        PipelineError { line: Some(Box::new(line.clone())), ..PipelineError::new(message) }
+    }
+
+    /// Creates an error pointing at the 1-based column of the file, as given by `Line::source_column`, of the offending input line
+    pub fn at(line: &Line, column: usize, message: String) -> PipelineError {
+        PipelineError { column: Some(column), ..PipelineError::on_line(line, message) }
+    }
+
+    /// Attaches the error that caused this one
+    pub fn caused_by(self, cause: PipelineError) -> PipelineError {
+        PipelineError { cause: Some(Box::new(cause)), ..self }
+    }
+
+    /// Sets the stage, unless already known
+    pub fn with_stage(self, stage: Stage) -> PipelineError {
+        PipelineError { stage: self.stage.or(Some(stage)), ..self }
+    }
+
+    /// Sets the day and part, unless already known
+    pub fn with_problem(self, day: u8, is_part_2: bool) -> PipelineError {
+        PipelineError {
+            day: self.day.or(Some(day)),
+            part: self.part.or(Some(if is_part_2 { 2 } else { 1 })),
+            ..self
+        }
+    }
+
+    /// Returns the line number of the offending input line, if known
+    pub fn line_number(&self) -> Option<usize> {
+        self.line.as_ref().map(|line|line.number)
+    }
+
+    /// Renders the offending input line in the style of a compiler diagnostic: its location, its text
+    /// and carets under the token at the column, e.g.
+    /// ```text
+    ///   --> input.txt:3:5
+    ///    |
+    ///  3 | 190: 1x 19
+    ///    |      ^^
+    /// ```
+    /// Returns `None` if the line is not known. Without a known column, the location and text of the line are shown.
+    pub fn snippet(&self) -> Option<String> {
+        let line = self.line.as_ref()?;
+        let number = line.number.to_string();
+        let gutter = " ".repeat(number.len());
+        let mut rows = vec![
+            format!("{}--> {}", gutter, line.location(self.column)),
+            format!("{} |", gutter),
+            format!("{} | {}", number, line.source),
+        ];
+        if let Some(column) = self.column.filter(|&column|column > 0) {
+            rows.push(format!("{} | {}", gutter, carets(&line.source, column - 1)));
+        }
+        Some(rows.join("\n"))
+    }
+
+    /// Renders the error followed by the snippet of the offending input line, if known
+    pub fn diagnostic(&self) -> String {
+        match self.snippet() {
+            Some(snippet) => format!("{}\n{}", self, snippet),
+            None => self.to_string(),
+        }
+    }
+
+    /// Returns this error followed by its chain of causes
+    pub fn chain(&self) -> Vec<&PipelineError> {
+        let mut chain = vec![self];
+        while let Some(cause) = chain[chain.len() - 1].cause.as_deref() {
+            chain.push(cause);
+        }
+        chain
+    }
+}
+
+/// Carets under the token starting at the 0-based character index of the text, reaching to the next whitespace.
+/// Tabs before the token are kept, so that the carets line up with the text.
+fn carets(text: &str, index: usize) -> String {
+    let indent = text.chars().take(index).map(|c|if c == '\t' { '\t' } else { ' ' }).collect::<String>();
+    let length = text.chars().skip(index).take_while(|c|!c.is_whitespace()).count().max(1);
+    format!("{}{}", indent, "^".repeat(length))
+}
+
+impl From<String> for PipelineError {
+    fn from(message: String) -> PipelineError {
+        PipelineError::new(message)
+    }
+}
+
+impl Display for PipelineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let messages = self.chain().into_iter().map(|error|error.message.as_str()).collect::<Vec<_>>();
+        write!(f, "{}", messages.join(": "))
+    }
+}
+
+impl std::error::Error for PipelineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.cause.as_deref().map(|cause|cause as &(dyn std::error::Error + 'static))
+    }
+}
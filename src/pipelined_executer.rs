@@ -1,5 +1,5 @@
 use std::fmt::{Debug, Display};
-use crate::{answer::Answer, executer::Execute, helper::result::zip, parser::{Parse, Parser}, reader::{Read, Reader}, solver::{Solve, Solver}, verifier::{Verifier, Verify}};
+use crate::{answer::Answer, executer::{Execute, ExecuteBoth, Stage, StageTimings, StageTrace}, helper::result::zip, pipeline_error::PipelineError, parser::{Parse, Parser}, parameters::{ParameterSpec, Parameters}, reader::{Read, Reader, VecLine}, solver::{Solve, Solver}, verifier::{Verifier, Verify}};
 
 /// A pipelined executer on generic `T`. It captures the idea of
 /// reading, parsing, verifying and solving, and it implements
@@ -9,6 +9,8 @@ pub struct PipelinedExecuter<T> where T: Eq + Display + Clone + Debug {
     parser: Parser<T>,
     verifier: Verifier<T>,
     solver: Solver<T>,
+    specs: &'static [ParameterSpec],
+    overrides: Parameters,
 }
 
 impl <T> PipelinedExecuter<T> where T: Eq + Display + Clone + Debug + 'static {
@@ -20,42 +22,139 @@ impl <T> PipelinedExecuter<T> where T: Eq + Display + Clone + Debug + 'static {
             parser: Box::new(parser),
             verifier: Box::new(verifier),
             solver: Box::new(solver),
+            specs: &[],
+            overrides: Parameters::new(),
         }
     }
+
+    /// Sets the parameters the pipeline declares and the values overriding them, e.g. given on the command line.
+    /// The parameters declared in the input file are resolved against them when reading: the overriding values
+    /// take precedence over the ones in the input file, which take precedence over the defaults.
+    pub fn with_parameters(self, specs: &'static [ParameterSpec], overrides: Parameters) -> PipelinedExecuter<T> {
+        PipelinedExecuter { specs, overrides, ..self }
+    }
+}
+
+impl <T> PipelinedExecuter<T> where T: Eq + Display + Clone + Debug {
+    fn resolve_parameters(&self, declared: &Parameters) -> Result<Parameters, PipelineError> {
+        ParameterSpec::resolve(self.specs, &declared.overridden_by(&self.overrides)).map_err(PipelineError::from)
+    }
 }
 
 impl <T> Read for PipelinedExecuter<T>  where T: Eq + Display + Clone + Debug {
-    fn read(&self, input_file_path: &str) -> Result<crate::reader::VecLine, String> {
+    fn read(&self, input_file_path: &str) -> Result<VecLine, PipelineError> {
         self.reader.read(input_file_path)
+            .and_then(|lines|self.resolve_parameters(&lines.parameters).map(|parameters|lines.with_parameters(parameters)))
+            .map_err(|e| e.with_stage(Stage::Read))
     }
 }
 
 impl <T> Parse<T> for PipelinedExecuter<T>  where T: Eq + Display + Clone + Debug {
-    fn parse(&self, vec_line: crate::reader::VecLine) -> Result<T, String> {
-        self.parser.parse(vec_line)
+    fn parse(&self, vec_line: VecLine) -> Result<T, PipelineError> {
+        self.parser.parse(vec_line).map_err(|e| e.with_stage(Stage::Parse))
     }
 }
 
 impl <T> Verify<T> for PipelinedExecuter<T>  where T: Eq + Display + Clone + Debug {
-    fn verify(&self, input: T) -> Result<T, String> {
-        self.verifier.verify(input)
+    fn verify(&self, input: T) -> Result<T, PipelineError> {
+        self.resolve_parameters(&Parameters::new())
+            .and_then(|parameters|self.verifier.verify_with(input, &parameters))
+            .map_err(|e| e.with_stage(Stage::Verify))
+    }
+
+    fn verify_with(&self, input: T, parameters: &Parameters) -> Result<T, PipelineError> {
+        self.verifier.verify_with(input, parameters).map_err(|e| e.with_stage(Stage::Verify))
     }
 }
 
 impl <T> Solve<T> for PipelinedExecuter<T>  where T: Eq + Display + Clone + Debug {
-    fn solve(&self, input: T) -> Result<Answer, String> {
-        self.solver.solve(input)
+    fn solve(&self, input: T) -> Result<Answer, PipelineError> {
+        self.resolve_parameters(&Parameters::new())
+            .and_then(|parameters|self.solver.solve_with(input, &parameters))
+            .map_err(|e| e.with_stage(Stage::Solve))
     }
+
+    fn solve_with(&self, input: T, parameters: &Parameters) -> Result<Answer, PipelineError> {
+        self.solver.solve_with(input, parameters).map_err(|e| e.with_stage(Stage::Solve))
+    }
+}
+
+/// Parameters resolved when reading the input file, or none if reading failed
+fn parameters_of(input_lines: &Result<VecLine, PipelineError>) -> Parameters {
+    input_lines.as_ref().map(|lines| lines.parameters.clone()).unwrap_or_default()
 }
 
 impl <T> Execute for PipelinedExecuter<T> where T: Eq + Display + Clone + Debug {
-    fn execute(&self, input_file_path: &str) -> Result<Answer, String> {
-        let input_lines = self.reader.read(input_file_path);
-        let parsed_input = input_lines.and_then(|lines| self.parser.parse(lines));
-        let verified_input = parsed_input.and_then(|input| self.verifier.verify(input));
-        let solution = verified_input.and_then(|input| self.solver.solve(input));
+    fn execute(&self, input_file_path: &str) -> Result<Answer, PipelineError> {
+        let input_lines = self.read(input_file_path);
+        let parameters = parameters_of(&input_lines);
+        let parsed_input = input_lines.and_then(|lines| self.parse(lines));
+        let verified_input = parsed_input.and_then(|input| self.verify_with(input, &parameters));
+        let solution = verified_input.and_then(|input| self.solve_with(input, &parameters));
         solution
     }
+
+    fn execute_timed(&self, input_file_path: &str) -> (Result<Answer, PipelineError>, StageTimings) {
+        let mut timings = StageTimings::new();
+        let input_lines = timings.measure(Stage::Read, || self.read(input_file_path));
+        let parameters = parameters_of(&input_lines);
+        let parsed_input = input_lines.and_then(|lines| timings.measure(Stage::Parse, || self.parse(lines)));
+        let verified_input = parsed_input.and_then(|input| timings.measure(Stage::Verify, || self.verify_with(input, &parameters)));
+        let solution = verified_input.and_then(|input| timings.measure(Stage::Solve, || self.solve_with(input, &parameters)));
+        (solution, timings)
+    }
+
+    fn execute_traced(&self, input_file_path: &str) -> (Result<Answer, PipelineError>, StageTrace) {
+        let mut trace = StageTrace::new();
+        let input_lines = self.read(input_file_path).inspect(|lines| trace.record(Stage::Read, lines.listing()));
+        let parameters = parameters_of(&input_lines);
+        let parsed_input = input_lines.and_then(|lines| self.parse(lines)).inspect(|input| trace.record(Stage::Parse, input.to_string()));
+        let verified_input = parsed_input.and_then(|input| self.verify_with(input, &parameters)).inspect(|input| trace.record(Stage::Verify, input.to_string()));
+        let solution = verified_input.and_then(|input| self.solve_with(input, &parameters)).inspect(|answer| trace.record(Stage::Solve, answer.report()));
+        (solution, trace)
+    }
+
+    fn display_model(&self, input_file_path: &str) -> Result<String, PipelineError> {
+        let input_lines = self.read(input_file_path);
+        let parameters = parameters_of(&input_lines);
+        input_lines
+            .and_then(|lines| self.parse(lines))
+            .and_then(|input| self.verify_with(input, &parameters))
+            .map(|input| input.to_string())
+    }
+}
+
+/// Executes both parts of a day whose pipelines differ only in their solvers.
+/// The input is read, parsed and verified once by the part 1 pipeline,
+/// and the verified input is cloned for the part 2 solver.
+pub struct PipelinedPairExecuter<T> where T: Eq + Display + Clone + Debug {
+    part_1: PipelinedExecuter<T>,
+    part_2: PipelinedExecuter<T>,
+}
+
+impl <T> PipelinedPairExecuter<T> where T: Eq + Display + Clone + Debug {
+    pub fn new(part_1: PipelinedExecuter<T>, part_2: PipelinedExecuter<T>) -> PipelinedPairExecuter<T> {
+        PipelinedPairExecuter { part_1, part_2 }
+    }
+}
+
+impl <T> ExecuteBoth for PipelinedPairExecuter<T> where T: Eq + Display + Clone + Debug {
+    fn execute_both(&self, input_file_path: &str) -> [(Result<Answer, PipelineError>, StageTimings); 2] {
+        let mut timings = StageTimings::new();
+        let input_lines = timings.measure(Stage::Read, || self.part_1.read(input_file_path));
+        let parameters = parameters_of(&input_lines);
+        let parsed_input = input_lines.and_then(|lines| timings.measure(Stage::Parse, || self.part_1.parse(lines)));
+        let verified_input = parsed_input.and_then(|input| timings.measure(Stage::Verify, || self.part_1.verify_with(input, &parameters)));
+        match verified_input {
+            Ok(input) => {
+                let mut part_2_timings = timings.clone();
+                let part_1_solution = timings.measure(Stage::Solve, || self.part_1.solve_with(input.clone(), &parameters));
+                let part_2_solution = part_2_timings.measure(Stage::Solve, || self.part_2.solve_with(input, &parameters));
+                [(part_1_solution, timings), (part_2_solution, part_2_timings)]
+            },
+            Err(error) => [(Err(error.clone()), timings.clone()), (Err(error), timings)],
+        }
+    }
 }
 
 /// Providing all components in their `Result<_,String>` form, where creation of each
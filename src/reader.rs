@@ -1,23 +1,58 @@
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
-use crate::helper;
+use crate::{helper, parameters::Parameters, pipeline_error::PipelineError};
 
 pub type Reader = Box<dyn Read>;
 
-pub trait Read {
-    fn read(&self, input_file_path: &str) -> Result<VecLine, String>;
+pub trait Read: Send + Sync {
+    fn read(&self, input_file_path: &str) -> Result<VecLine, PipelineError>;
 }
 
-/// A line is some text read from a file, and the row number of the line inside the file
+/// A line is some text read from a file, and the row number of the line inside the file.
+/// It also keeps the path of the file, if read from one, the line as it was read, and the number of characters
+/// sanitising removed from the start of the line, so that columns of the text can be traced back to the file.
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Line {
     pub text: String,
     pub number: usize,
+    pub path: Option<Arc<str>>,
+    pub source: Arc<str>,
+    pub offset: usize,
 }
 
 impl Line {
     pub fn new(text: String, number: usize) -> Line {
-        Line { text, number }
+        Line { source: Arc::from(text.as_str()), text, number, path: None, offset: 0 }
+    }
+
+    pub fn with_path(self, path: Arc<str>) -> Line {
+        Line { path: Some(path), ..self }
+    }
+
+    /// Returns the line with its text replaced by a part of it, starting `removed` characters further
+    pub fn sanitised(&self, text: String, removed: usize) -> Line {
+        Line { text, offset: self.offset + removed, ..self.clone() }
+    }
+
+    /// Returns the 1-based column in the file of the 1-based column of the text
+    pub fn source_column(&self, column: usize) -> usize {
+        self.offset + column
+    }
+
+    /// Returns the 1-based column in the file of the character starting at the byte `offset` of the text
+    pub fn column_at(&self, offset: usize) -> usize {
+        self.source_column(self.text[..offset].chars().count() + 1)
+    }
+
+    /// Describes where the line, or its 1-based column in the file, is: e.g. `input.txt:3:5`,
+    /// or `line #3, column 5` if the line was not read from a file
+    pub fn location(&self, column: Option<usize>) -> String {
+        match (&self.path, column) {
+            (Some(path), Some(column)) => format!("{}:{}:{}", path, self.number, column),
+            (Some(path), None) => format!("{}:{}", path, self.number),
+            (None, Some(column)) => format!("line #{}, column {}", self.number, column),
+            (None, None) => format!("line #{}", self.number),
+        }
     }
 
     pub fn textf(&self) -> String {
@@ -37,11 +72,40 @@ impl Display for Line {
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct VecLine {
-    pub lines: Vec<Line>
+    pub lines: Vec<Line>,
+    pub parameters: Parameters,
 }
 
 impl VecLine {
-    pub fn new(lines: Vec<Line>) -> VecLine  { VecLine{ lines } }
+    pub fn new(lines: Vec<Line>) -> VecLine  { VecLine{ lines, parameters: Parameters::new() } }
+
+    pub fn with_parameters(self, parameters: Parameters) -> VecLine {
+        VecLine { parameters, ..self }
+    }
+
+    /// Marks every line as read from the file at the path
+    pub fn with_path(self, path: &str) -> VecLine {
+        let path: Arc<str> = Arc::from(path);
+        VecLine { lines: self.lines.into_iter().map(|line|line.with_path(path.clone())).collect(), ..self }
+    }
+
+    /// Splits the text into lines, numbered from 1
+    pub fn from_text(text: &str) -> VecLine {
+        VecLine::new(text.lines()
+            .enumerate()
+            .map(|(line_num, line_str)| Line::new(String::from(line_str), line_num + 1))
+            .collect())
+    }
+
+    /// Renders the lines one per row, each preceded by its line number, after the parameters, if there are any
+    pub fn listing(&self) -> String {
+        let width = self.lines.last().map_or(1, |line|line.number.to_string().len());
+        let parameters = (!self.parameters.is_empty()).then(||format!("parameters: {}", self.parameters));
+        parameters.into_iter()
+            .chain(self.lines.iter().map(|line|format!("{:>width$} | {}", line.number, line.text, width = width)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 }
 
 impl Display for VecLine {
@@ -1,13 +1,19 @@
 mod simple_file_reader;
+mod stdin_reader;
+mod string_reader;
 mod sanitised_file_reader;
 mod empty_line_trimming;
 mod input_end_comment;
 mod line_comment;
+mod directive;
 mod line;
 mod line_trim;
 mod test;
 
-pub use self::simple_file_reader::SimpleFileReader;
+pub use self::simple_file_reader::{SimpleFileReader, STDIN_PATH};
+pub use self::stdin_reader::StdinReader;
+#[allow(unused_imports)]
+pub use self::string_reader::StringReader;
 pub use self::sanitised_file_reader::SanitisedFileReader;
 
 #[allow(unused_imports)]
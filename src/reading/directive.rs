@@ -0,0 +1,45 @@
+/// A header directive declares puzzle parameters inside the input file, for example `//! width=11 height=7`.
+/// Directive lines are taken out of the input before line comments are processed,
+/// and their key/value pairs are exposed to the pipeline as `Parameters`.
+pub const DIRECTIVE_PATTERN: &str = "//!";
+
+pub mod reading_only {
+    use crate::{parameters::Parameters, pipeline_error::PipelineError, reader::{Line, VecLine}};
+
+    use super::*;
+
+    const PREFIX: &str = "[Directive]";
+
+    fn parse_directive(line: &Line, text: &str, parameters: &mut Parameters) -> Result<(), PipelineError> {
+        let mut rest = text;
+        while let Some(begin) = rest.find(|c: char|!c.is_whitespace()) {
+            let end = rest[begin..].find(char::is_whitespace).map_or(rest.len(), |length|begin + length);
+            let pair = &rest[begin..end];
+            let column = line.column_at(line.text.len() - rest.len() + begin);
+            let error = |message: String|PipelineError::at(line, column, format!("{} {}", PREFIX, message));
+            match pair.split_once('=') {
+                Some((key, value)) if !key.is_empty() && !value.is_empty() => {
+                    if parameters.insert(key, value).is_some() {
+                        return Err(error(format!("parameter '{}' is declared more than once", key)));
+                    }
+                },
+                _ => return Err(error(format!("expected a parameter as key=value, found '{}'", pair))),
+            }
+            rest = &rest[end..];
+        }
+        Ok(())
+    }
+
+    /// Removes the directive lines and collects their parameters
+    pub fn apply(vec_line: VecLine) -> Result<VecLine, PipelineError> {
+        let mut parameters = vec_line.parameters;
+        let mut lines = vec![];
+        for line in vec_line.lines {
+            match line.text.trim_start().strip_prefix(DIRECTIVE_PATTERN) {
+                Some(text) => parse_directive(&line, text, &mut parameters)?,
+                None => lines.push(line),
+            }
+        }
+        Ok(VecLine::new(lines).with_parameters(parameters))
+    }
+}
@@ -3,7 +3,6 @@
 /// Meaning, if it matches a given pattern, anything in the file
 /// that comes after this type of comment will be dropped out.
 /// Similar to the idea of a block comment, but always extended to the end of file.
-#[allow(dead_code)]
 pub enum InputEndComment {
     None,
     Pattern(String),
@@ -15,9 +14,8 @@ pub mod reading_only {
     use super::*;
 
     fn split_line(line: Line, pattern: &String) -> Vec<Option<Line>> {
-        let line_number = line.numberf();
-        let mut splits : Vec<_> = line.textf().split(pattern)
-            .map(|s| Some(Line::new(String::from(s), line_number))) 
+        let mut splits : Vec<_> = line.text.split(pattern)
+            .map(|s| Some(line.sanitised(String::from(s), 0)))
             .collect();
 
         // We mark the end of input by putting Option::None where the cut-off is
@@ -1,7 +1,6 @@
 
 /// An option for a comment that is quite common in coding.
 /// A line comment - wherever the pattern is noticed, everything till the end of the line is ignored.
-#[allow(dead_code)]
 pub enum LineComment {
     None,
     Pattern(String),
@@ -15,7 +14,7 @@ pub mod reading_only {
 
     fn trim_comment(line: Line, pattern: &String) -> Line {
         let trimmed = line.textf().split(pattern).map(String::from).collect::<Vec<_>>()[0].clone();
-        Line::new(trimmed, line.numberf())
+        line.sanitised(trimmed, 0)
     }
 
     pub fn apply(line_comment: &LineComment, vec_line: VecLine) -> VecLine {
@@ -1,4 +1,3 @@
-#[allow(dead_code)]
 pub enum LineTrim {
     None,
     Start,
@@ -12,18 +11,24 @@ pub mod reading_only {
 
     use super::*;
 
-    fn apply_trim_fn(lines: Vec<Line>, trim_fn: Box<dyn Fn(String) -> String>) -> Vec<Line> {
+    /// Trims each line, keeping the number of characters trimmed from its start in its offset
+    fn trim_lines(lines: Vec<Line>, trim_start: bool, trim_end: bool) -> Vec<Line> {
         lines.into_iter()
-            .map(|line| Line::new(trim_fn(line.textf()), line.numberf()))
+            .map(|line| {
+                let start = if trim_start { line.text.len() - line.text.trim_start().len() } else { 0 };
+                let end = if trim_end { line.text.trim_end().len().max(start) } else { line.text.len() };
+                let removed = line.text[..start].chars().count();
+                line.sanitised(line.text[start..end].to_owned(), removed)
+            })
             .collect()
     }
 
     pub fn apply(line_trim: &LineTrim, vec_line: VecLine) -> VecLine {
         let lines = vec_line.lines;
         let processed_lines = match line_trim {
-            LineTrim::Both => apply_trim_fn(lines, Box::new(|s: String| s.trim().to_owned())),
-            LineTrim::Start => apply_trim_fn(lines, Box::new(|s: String| s.trim_start().to_owned())),
-            LineTrim::End => apply_trim_fn(lines, Box::new(|s: String| s.trim_end().to_owned())),
+            LineTrim::Both => trim_lines(lines, true, true),
+            LineTrim::Start => trim_lines(lines, true, false),
+            LineTrim::End => trim_lines(lines, false, true),
             _ => lines
         };
         VecLine::new(processed_lines)
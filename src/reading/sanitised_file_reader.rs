@@ -1,6 +1,8 @@
+use crate::pipeline_error::PipelineError;
 use crate::reader::Read;
 use crate::reader::VecLine;
 
+use super::directive;
 use super::empty_line_trimming;
 use super::input_end_comment;
 use super::input_end_comment::*;
@@ -19,6 +21,12 @@ pub struct SanitisedFileReader {
     empty_line_trim: EmptyLineTrimming,
 }
 
+impl Default for SanitisedFileReader {
+    fn default() -> SanitisedFileReader {
+        SanitisedFileReader::default_with(SimpleFileReader::new())
+    }
+}
+
 impl SanitisedFileReader {
     pub fn new<R>(
         underlying_reader: R,
@@ -36,29 +44,33 @@ impl SanitisedFileReader {
         }
     }
 
-    pub fn default() -> SanitisedFileReader {
-    SanitisedFileReader::new(
-        SimpleFileReader::new(),
-        LineComment::Pattern(format!("//")),
-        InputEndComment::Pattern(format!("####")),
-        LineTrim::Both,
-        EmptyLineTrimming::All,
-    )
+    /// Sanitises the lines of the given reader with the default settings
+    pub fn default_with<R>(underlying_reader: R) -> SanitisedFileReader where R: Read + 'static {
+        SanitisedFileReader::new(
+            underlying_reader,
+            LineComment::Pattern(format!("//")),
+            InputEndComment::Pattern(format!("####")),
+            LineTrim::Both,
+            EmptyLineTrimming::All,
+        )
     }
 
-    fn sanitise(&self, lines: VecLine) -> VecLine {
+    /// Header directives are taken out after the input end comment, but before line comments would remove them
+    fn sanitise(&self, lines: VecLine) -> Result<VecLine, PipelineError> {
         let end_comment_trim = |lines| input_end_comment::reading_only::apply(&self.end_comment, lines);
         let line_comment_trim = |lines| line_comment::reading_only::apply(&self.line_comment, lines);
         let line_trim_fn = |lines| line_trim::reading_only::apply(&self.trim, lines);
         let empty_line_trim_fn = |lines| empty_line_trimming::reading_only::apply(&self.empty_line_trim, lines);
-        empty_line_trim_fn(line_trim_fn(line_comment_trim(end_comment_trim(lines))))
+        let lines = directive::reading_only::apply(end_comment_trim(lines))?;
+        let parameters = lines.parameters.clone();
+        Ok(empty_line_trim_fn(line_trim_fn(line_comment_trim(lines))).with_parameters(parameters))
     }
 }
 
 impl Read for SanitisedFileReader {
-    fn read(&self, input_file_path: &str) -> Result<VecLine, String> {
+    fn read(&self, input_file_path: &str) -> Result<VecLine, PipelineError> {
         match self.underlying_reader.read(input_file_path) {
-            Ok(result) => Ok(self.sanitise(result)),
+            Ok(result) => self.sanitise(result),
             rtn => rtn
         }
     }
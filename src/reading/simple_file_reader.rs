@@ -1,4 +1,13 @@
-use crate::reader::{Line, Read, VecLine};
+use crate::pipeline_error::PipelineError;
+use crate::reader::{Read, VecLine};
+
+use super::StdinReader;
+
+/// Path that makes the file readers read the standard input instead of a file
+pub const STDIN_PATH: &str = "-";
+
+/// Reads the whole file; the path `-` reads the standard input instead
+#[derive(Default)]
 pub struct SimpleFileReader;
 
 impl SimpleFileReader {
@@ -6,16 +15,13 @@ impl SimpleFileReader {
 }
 
 impl Read for SimpleFileReader {
-    fn read(&self, input_file_path: &str) -> Result<VecLine, String> {
+    fn read(&self, input_file_path: &str) -> Result<VecLine, PipelineError> {
+        if input_file_path == STDIN_PATH {
+            return StdinReader::new().read(input_file_path);
+        }
         match std::fs::read_to_string(input_file_path) {
-            Ok(text) => {
-                let lines = text.lines()
-                    .enumerate()
-                    .map(|(line_num, line_str)| Line::new(String::from(line_str), line_num + 1))
-                    .collect();
-                Ok(VecLine::new(lines))
-            }
-            Err(err) => Err(format!("Error when reading the file '{}': {}", input_file_path, err))
+            Ok(text) => Ok(VecLine::from_text(&text).with_path(input_file_path)),
+            Err(err) => Err(format!("Error when reading the file '{}': {}", input_file_path, err).into())
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+use std::io::Read as _;
+
+use crate::pipeline_error::PipelineError;
+use crate::reader::{Read, VecLine};
+
+/// Name of the standard input in the locations of its lines
+pub const STDIN_NAME: &str = "<stdin>";
+
+/// Reads the whole standard input, regardless of the given path
+#[derive(Default)]
+pub struct StdinReader;
+
+impl StdinReader {
+    pub fn new() -> StdinReader { StdinReader }
+}
+
+impl Read for StdinReader {
+    fn read(&self, _input_file_path: &str) -> Result<VecLine, PipelineError> {
+        let mut text = String::new();
+        match std::io::stdin().read_to_string(&mut text) {
+            Ok(_) => Ok(VecLine::from_text(&text).with_path(STDIN_NAME)),
+            Err(err) => Err(format!("Error when reading the standard input: {}", err).into())
+        }
+    }
+}
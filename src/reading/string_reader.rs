@@ -0,0 +1,17 @@
+use crate::pipeline_error::PipelineError;
+use crate::reader::{Read, VecLine};
+
+/// Reads the text it holds in memory, regardless of the given path
+pub struct StringReader {
+    text: String,
+}
+
+impl StringReader {
+    pub fn new(text: &str) -> StringReader { StringReader { text: text.to_string() } }
+}
+
+impl Read for StringReader {
+    fn read(&self, _input_file_path: &str) -> Result<VecLine, PipelineError> {
+        Ok(VecLine::from_text(&self.text))
+    }
+}
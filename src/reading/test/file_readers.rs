@@ -1,7 +1,7 @@
 #[cfg(test)]
 pub mod suite {
 
-    use crate::{reader::{Line, Read, VecLine}, testing::resolve_filepath};
+    use crate::{parameters::Parameters, reader::{Line, Read, VecLine}, testing::resolve_filepath};
 
     use super::super::super::*;
 
@@ -17,11 +17,18 @@ pub mod suite {
         assert_eq!(vec_lines.lines.len(), 4);
     }
 
+    /// The second line of the test file, sanitised to the text with `removed` characters trimmed from its start
+    fn sanitised_second_line(text: &str, removed: usize) -> Line {
+        Line::new(String::from("   This is a test   "), 2).sanitised(String::from(text), removed)
+    }
+
+    /// The expected lines are compared as read from the test file
     fn test_sanitised_file_reader_helper<R>(sfr: R, expected: Vec<Line>) where R: Read + 'static {
-        let res = sfr.read(&resolve_filepath(TESTFILE_RELATIVE_PATH));
+        let filepath = resolve_filepath(TESTFILE_RELATIVE_PATH);
+        let res = sfr.read(&filepath);
         assert!(res.is_ok());
         let lines = res.unwrap();
-        assert_eq!(lines, VecLine::new(expected));
+        assert_eq!(lines, VecLine::new(expected).with_path(&filepath));
     }
 
     #[test]
@@ -100,7 +107,7 @@ pub mod suite {
             settings::EmptyLineTrimming::Both,
         );
         let expected = vec![
-            Line::new(String::from("This is a test"), 2),
+            sanitised_second_line("This is a test", 3),
         ];
         test_sanitised_file_reader_helper(sfr, expected);
     }
@@ -115,7 +122,7 @@ pub mod suite {
             settings::EmptyLineTrimming::Both,
         );
         let expected = vec![
-            Line::new(String::from("This is"), 2),
+            sanitised_second_line("This is", 3),
         ];
         test_sanitised_file_reader_helper(sfr, expected);
     }
@@ -130,7 +137,7 @@ pub mod suite {
             settings::EmptyLineTrimming::Both,
         );
         let expected = vec![
-            Line::new(String::from("Th"), 2),
+            sanitised_second_line("Th", 3),
         ];
         test_sanitised_file_reader_helper(sfr, expected);
     }
@@ -146,7 +153,7 @@ pub mod suite {
         );
         let expected = vec![
             Line::new(String::from(""),    1),
-            Line::new(String::from("   "), 2), // cutoff at start of 'This', three white spaces are left untouched
+            sanitised_second_line("   ", 0), // cutoff at start of 'This', three white spaces are left untouched
         ];
         test_sanitised_file_reader_helper(sfr, expected);
     }
@@ -162,7 +169,7 @@ pub mod suite {
             settings::EmptyLineTrimming::All,
         );
         let expected = vec![
-            Line::new(String::from("This is a test   "), 2),
+            sanitised_second_line("This is a test   ", 3),
         ];
         test_sanitised_file_reader_helper(sfr, expected);
     }
@@ -178,8 +185,65 @@ pub mod suite {
             settings::EmptyLineTrimming::All,
         );
         let expected = vec![
-            Line::new(String::from("   This is a test"), 2),
+            sanitised_second_line("   This is a test", 0),
         ];
         test_sanitised_file_reader_helper(sfr, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_string_reader() {
+        let reader = StringReader::new("first\n\n  third // comment\n####\nignored");
+        assert_eq!(reader.read("").unwrap().lines.len(), 5);
+        let expected = vec![
+            Line::new(String::from("first"), 1),
+            Line::new(String::from("  third // comment"), 3).sanitised(String::from("third"), 2),
+        ];
+        assert_eq!(SanitisedFileReader::default_with(reader).read("").unwrap(), VecLine::new(expected));
+    }
+
+    #[test]
+    fn test_directive_parameters() {
+        let reader = StringReader::new("//! width=11 height=7\nfirst // comment\n  //!  seconds=100  \nsecond");
+        let vec_line = SanitisedFileReader::default_with(reader).read("").unwrap();
+        let expected = vec![
+            Line::new(String::from("first // comment"), 2).sanitised(String::from("first"), 0),
+            Line::new(String::from("second"), 4),
+        ];
+        assert_eq!(vec_line.lines, expected);
+        assert_eq!(vec_line.parameters.get("width"), Some("11"));
+        assert_eq!(vec_line.parameters.get_or("height", 103), Ok(7));
+        assert_eq!(vec_line.parameters.get_or("seconds", 0), Ok(100));
+        assert_eq!(vec_line.parameters.get_or("missing", 42), Ok(42));
+    }
+
+    #[test]
+    fn test_directive_after_input_end_is_ignored() {
+        let reader = StringReader::new("first\n####\n//! width=11");
+        let vec_line = SanitisedFileReader::default_with(reader).read("").unwrap();
+        assert_eq!(vec_line.parameters, Parameters::new());
+    }
+
+    #[test]
+    fn test_directive_errors() {
+        let read = |text: &str|SanitisedFileReader::default_with(StringReader::new(text)).read("");
+        let error = read("first\n//! width=11 height").unwrap_err();
+        assert_eq!((error.line_number(), error.column), (Some(2), Some(14)));
+        assert!(error.to_string().contains("[Directive] expected a parameter as key=value, found 'height'"), "{}", error);
+        assert!(read("//! width=11\n//! width=12").unwrap_err().to_string().contains("'width' is declared more than once"));
+        assert!(read("//! =11").is_err());
+
+        let mut parameters = Parameters::new();
+        parameters.insert("width", "wide");
+        assert!(parameters.get_or("width", 0u16).unwrap_err().contains("'width' has an invalid value 'wide'"));
+    }
+
+    #[test]
+    fn test_location_survives_sanitisation() {
+        let filepath = resolve_filepath(TESTFILE_RELATIVE_PATH);
+        let vec_line = SanitisedFileReader::default().read(&filepath).unwrap();
+        let line = &vec_line.lines[0];
+        assert_eq!((line.text.as_str(), line.offset), ("This is a test", 3));
+        assert_eq!(line.location(Some(line.source_column(6))), format!("{}:2:9", filepath));
+        assert_eq!(StringReader::new("x").read("").unwrap().lines[0].location(None), "line #1");
+    }
+}
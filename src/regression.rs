@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use answers_parser::AnswersParser;
+use checker::{CheckRecord, CheckStatus};
+use model::AnswersStore;
+
+use crate::{executer_manager::ExecuterManager, parser::Parse, reader::Read, reading::SimpleFileReader};
+
+mod model;
+mod answers_parser;
+mod checker;
+mod test;
+
+/// Summary of a regression check over an answers file
+pub struct CheckOutcome {
+    pub records: Vec<CheckRecord>,
+    /// Number of missing answers that were recorded into the answers file
+    pub recorded: usize,
+}
+
+impl CheckOutcome {
+    pub fn count(&self, predicate: fn(&CheckStatus) -> bool) -> usize {
+        self.records.iter().filter(|record|predicate(&record.status)).count()
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.count(|status|matches!(status, CheckStatus::Fail(_))) > 0
+    }
+
+    /// Renders a line per entry (with the diff for failed ones) followed by the totals
+    pub fn report(&self) -> String {
+        let mut lines = vec![];
+        for CheckRecord { entry, status } in self.records.iter() {
+            let target = format!("D{:02} P{} {}", entry.day, if entry.is_part_2 { 2 } else { 1 }, entry.input_filepath);
+            match status {
+                CheckStatus::Pass => lines.push(format!("PASS    {}", target)),
+                CheckStatus::Fail(diff) => {
+                    lines.push(format!("FAIL    {}", target));
+                    lines.extend(diff.lines().map(|line|format!("        {}", line)));
+                },
+                CheckStatus::Missing(Ok(_)) => lines.push(format!("MISSING {}", target)),
+                CheckStatus::Missing(Err(error)) => lines.push(format!("MISSING {} (execution failed: {})", target, error)),
+            }
+        }
+        lines.push(String::new());
+        lines.push(format!("{} passed, {} failed, {} missing, {} recorded",
+            self.count(|status|*status == CheckStatus::Pass),
+            self.count(|status|matches!(status, CheckStatus::Fail(_))),
+            self.count(|status|matches!(status, CheckStatus::Missing(_))),
+            self.recorded));
+        lines.join("\n")
+    }
+}
+
+fn load_answers(answers_filepath: &str) -> Result<AnswersStore, String> {
+    SimpleFileReader::new().read(answers_filepath)
+        .and_then(|lines|AnswersParser::new()?.parse(lines))
+        .map_err(|e|e.to_string())
+}
+
+/// Checks every entry of the answers file against the current answers produced by the executer manager.
+/// Relative input paths in the answers file are resolved against the directory of the answers file.
+/// If `record` is set, missing answers are inserted from the current results into the answers file,
+/// keeping its comments and blank lines.
+pub fn check_answers(manager: &ExecuterManager, answers_filepath: &str, record: bool) -> Result<CheckOutcome, String> {
+    let store = load_answers(answers_filepath)?;
+    let base_directory = Path::new(answers_filepath).parent().unwrap_or(Path::new(""));
+    let records = checker::check(manager, &store, base_directory);
+    if !record {
+        return Ok(CheckOutcome { records, recorded: 0 });
+    }
+
+    let text = std::fs::read_to_string(answers_filepath)
+        .map_err(|e|format!("[Regression check] could not read answers file '{}': {}", answers_filepath, e))?;
+    let (updated_text, recorded) = checker::record_missing(&text, &records);
+    if recorded > 0 {
+        std::fs::write(answers_filepath, updated_text)
+            .map_err(|e|format!("[Regression check] could not write answers file '{}': {}", answers_filepath, e))?;
+    }
+    Ok(CheckOutcome { records, recorded })
+}
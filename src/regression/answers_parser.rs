@@ -0,0 +1,84 @@
+use crate::{parser::Parse, pipeline_error::PipelineError, reader::{Line, VecLine}};
+
+use super::model::{AnswerEntry, AnswersStore};
+
+mod error {
+    const PREFIX: &str = "[Answers parser]";
+
+    pub fn create_regex(e: regex::Error) -> String {
+        format!("{} could not create regex for entry header, because {}.", PREFIX, e)
+    }
+
+    pub fn outside_of_entry(line_num: usize) -> String {
+        format!("{} line #{} is neither a comment nor part of an entry; entries start with '@@ DXY PZ <input path>'", PREFIX, line_num)
+    }
+
+    pub fn duplicate_entry(line_num: usize, header: &str) -> String {
+        format!("{} entry '{}' on line #{} is already defined", PREFIX, header, line_num)
+    }
+}
+
+/// Parses the plain-text answers file. The lines following an entry header belong to its expected report,
+/// which ends at the first empty line or at the next header. Outside of the reports, blank lines and
+/// comments, i.e. lines starting with `#`, are ignored; a report itself may contain lines starting with `#`.
+pub struct AnswersParser {
+    header_re: regex::Regex,
+}
+
+impl AnswersParser {
+    pub fn new() -> Result<AnswersParser, String> {
+        regex::Regex::new(r"^@@ +D(\d{2}) +P([12]) +(.+?) *$")
+            .map(|header_re|AnswersParser { header_re })
+            .map_err(error::create_regex)
+    }
+
+    fn parse_header(&self, line: &Line) -> Option<AnswerEntry> {
+        self.header_re.captures(&line.text)
+            .map(|c|c.extract())
+            .and_then(|(_, [day, part, input_filepath])|day.parse().ok().map(|day|AnswerEntry {
+                day,
+                is_part_2: part == "2",
+                input_filepath: input_filepath.to_string(),
+                expected: None,
+                header_line: line.number,
+            }))
+    }
+
+    fn finish_entry(mut entry: AnswerEntry, body: Vec<String>) -> AnswerEntry {
+        let expected = body.join("\n").trim_end().to_string();
+        entry.expected = if expected.is_empty() { None } else { Some(expected) };
+        entry
+    }
+}
+
+impl Parse<AnswersStore> for AnswersParser {
+    fn parse(&self, vec_line: VecLine) -> Result<AnswersStore, PipelineError> {
+        let mut entries: Vec<AnswerEntry> = vec![];
+        let mut current: Option<(AnswerEntry, Vec<String>)> = None;
+        // Whether the report of the current entry has ended with an empty line
+        let mut report_ended = false;
+        for line in vec_line.lines {
+            if let Some(entry) = self.parse_header(&line) {
+                if let Some((previous, body)) = current.take() {
+                    entries.push(Self::finish_entry(previous, body));
+                }
+                let is_duplicate = entries.iter()
+                    .any(|e|(e.day, e.is_part_2, &e.input_filepath) == (entry.day, entry.is_part_2, &entry.input_filepath));
+                if is_duplicate {
+                    return Err(error::duplicate_entry(line.number, &entry.header()).into());
+                }
+                current = Some((entry, vec![]));
+                report_ended = false;
+            } else if let Some((_, body)) = current.as_mut().filter(|_|!report_ended) {
+                report_ended = line.text.is_empty();
+                body.push(line.text);
+            } else if !line.text.trim().is_empty() && !line.text.starts_with('#') {
+                return Err(error::outside_of_entry(line.number).into());
+            }
+        }
+        if let Some((previous, body)) = current {
+            entries.push(Self::finish_entry(previous, body));
+        }
+        Ok(AnswersStore { entries })
+    }
+}
@@ -0,0 +1,104 @@
+use std::{collections::HashMap, path::Path};
+
+use crate::executer_manager::{ExecuterManager, ExecutionRecord};
+
+use super::model::{AnswerEntry, AnswersStore};
+
+/// Outcome of checking a single answer entry
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum CheckStatus {
+    /// The current answer matches the expected one
+    Pass,
+    /// The current answer differs from the expected one; holds the line diff (or the execution error)
+    Fail(String),
+    /// There is no expected answer; holds the current answer, if the execution succeeded
+    Missing(Result<String, String>),
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct CheckRecord {
+    pub entry: AnswerEntry,
+    pub status: CheckStatus,
+}
+
+/// Produces a line-by-line diff of expected and actual text, listing only differing lines.
+/// Expected lines are prefixed with `-` and actual lines with `+`.
+pub fn line_diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<_> = expected.lines().collect();
+    let actual_lines: Vec<_> = actual.lines().collect();
+    let mut diff = vec![];
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        let (expected_line, actual_line) = (expected_lines.get(i), actual_lines.get(i));
+        if expected_line == actual_line { continue; }
+        diff.push(format!("@ line {}", i + 1));
+        if let Some(line) = expected_line { diff.push(format!("- {}", line)); }
+        if let Some(line) = actual_line { diff.push(format!("+ {}", line)); }
+    }
+    diff.join("\n")
+}
+
+/// Resolves the input filepath of the entry; relative paths are relative to `base_directory`
+fn resolve_input(base_directory: &Path, input_filepath: &str) -> String {
+    base_directory.join(input_filepath).to_string_lossy().to_string()
+}
+
+/// Checks the entry against its execution record, or the error if no executer could be found for the entry
+fn check_entry(entry: &AnswerEntry, record: Result<ExecutionRecord, String>) -> CheckRecord {
+    let actual = record
+        .and_then(|record|record.outcome.map_err(|e|e.to_string()))
+        .map(|answer|answer.report().trim_end().to_string());
+    let status = match (&entry.expected, actual) {
+        (None, actual) => CheckStatus::Missing(actual),
+        (Some(expected), Ok(actual)) if *expected == actual => CheckStatus::Pass,
+        (Some(expected), Ok(actual)) => CheckStatus::Fail(line_diff(expected, &actual)),
+        (Some(_), Err(error)) => CheckStatus::Fail(format!("execution failed: {}", error)),
+    };
+    CheckRecord { entry: entry.clone(), status }
+}
+
+/// Runs each entry of the answers store through the executer manager and compares the trimmed
+/// report against the expected answer. Relative input paths are resolved against `base_directory`,
+/// which is normally the directory of the answers file. The year of each entry is resolved from its input path as given,
+/// see `ExecuterManager::try_execute_executer`; entries whose year cannot be resolved fail.
+pub fn check(manager: &ExecuterManager, store: &AnswersStore, base_directory: &Path) -> Vec<CheckRecord> {
+    let inputs = store.entries.iter()
+        .map(|entry|resolve_input(base_directory, &entry.input_filepath))
+        .collect::<Vec<_>>();
+    let keys = store.entries.iter()
+        .map(|entry|manager.try_resolve_key(&entry.input_filepath, Some(entry.day), entry.is_part_2))
+        .collect::<Vec<_>>();
+    let jobs = keys.iter().zip(&inputs)
+        .filter_map(|(key, input)|key.as_ref().ok().map(|key|(*key, input.clone())))
+        .collect::<Vec<_>>();
+    let mut records = manager.execute_jobs(&jobs).into_iter();
+    store.entries.iter().zip(keys)
+        .map(|(entry, key)|check_entry(entry, key.map(|_|records.next().expect("a record is made for each job"))))
+        .collect()
+}
+
+/// Inserts the current answers of the missing entries right below their headers into the text of the answers file,
+/// leaving all its other lines, comments and blank lines included, untouched. An inserted answer is ended with
+/// an empty line, unless the header is followed by one already or is the last line.
+/// Returns the updated text and the number of recorded answers.
+pub fn record_missing(text: &str, records: &[CheckRecord]) -> (String, usize) {
+    let answers = records.iter()
+        .filter_map(|record|match &record.status {
+            CheckStatus::Missing(Ok(actual)) => Some((record.entry.header_line, actual)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let lines = text.split_inclusive('\n').collect::<Vec<_>>();
+    let mut updated = String::with_capacity(text.len());
+    for (index, line) in lines.iter().enumerate() {
+        updated.push_str(line);
+        if let Some(answer) = answers.get(&(index + 1)) {
+            if !line.ends_with('\n') { updated.push('\n'); }
+            updated.push_str(answer);
+            updated.push('\n');
+            if lines.get(index + 1).is_some_and(|next|!next.trim_end_matches(['\r', '\n']).is_empty()) {
+                updated.push('\n');
+            }
+        }
+    }
+    (updated, answers.len())
+}
@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+use crate::helper::display::vector_display;
+
+/// Expected answer of a single day and part for an input file. The `expected` answer is
+/// the report of the answer with trailing whitespace removed, or `None` if not yet known.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct AnswerEntry {
+    pub day: u8,
+    pub is_part_2: bool,
+    pub input_filepath: String,
+    pub expected: Option<String>,
+    /// Number of the header line in the answers file
+    pub header_line: usize,
+}
+
+impl AnswerEntry {
+    /// Returns the header line of the entry in the answers file
+    pub fn header(&self) -> String {
+        format!("@@ D{:02} P{} {}", self.day, if self.is_part_2 { 2 } else { 1 }, self.input_filepath)
+    }
+}
+
+impl Display for AnswerEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.expected {
+            Some(expected) => write!(f, "{}\n{}", self.header(), expected),
+            None => write!(f, "{}", self.header()),
+        }
+    }
+}
+
+/// Collection of expected answers. Its `Display` is the plain-text answers file format:
+/// every entry starts with a header line `@@ DXY PZ <input path>` and is followed by the lines
+/// of the expected report, up to the first empty line. An entry without any report lines has a missing answer.
+/// Between the entries, blank lines and comments starting with `#` may follow the empty line ending a report.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct AnswersStore {
+    pub entries: Vec<AnswerEntry>,
+}
+
+impl Display for AnswersStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", vector_display(&self.entries, "\n"))
+    }
+}
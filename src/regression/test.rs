@@ -0,0 +1,95 @@
+#[cfg(test)]
+pub mod suite {
+    use crate::{create_executer_manager, parser::Parse, reader::Read, reading::SimpleFileReader, regression::{answers_parser::AnswersParser, check_answers, checker::{line_diff, record_missing, CheckRecord, CheckStatus}, model::AnswerEntry}, testing::resolve_filepath};
+
+    const REL_FILEPATHS: &[&str] = &[
+        "src/regression/test/answers.txt",
+    ];
+
+    #[test]
+    pub fn test_answers_parsing() {
+        let lines = SimpleFileReader::new().read(&resolve_filepath(REL_FILEPATHS[0])).unwrap();
+        let store = AnswersParser::new().unwrap().parse(lines).unwrap();
+        let summary = store.entries.iter()
+            .map(|entry|(entry.day, entry.is_part_2, entry.expected.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(summary, vec![
+            (24, false, Some("The answer is: 4".to_string())),
+            (24, false, Some("The answer is: 9".to_string())),
+            (23, true, None),
+            (23, false, Some("The answer is: 7".to_string())),
+        ]);
+    }
+
+    #[test]
+    pub fn test_answers_parsing_errors() {
+        let parse = |text: &str|AnswersParser::new().unwrap().parse(
+            crate::reader::VecLine::new(text.lines().enumerate()
+                .map(|(i, line)|crate::reader::Line::new(line.to_string(), i + 1))
+                .collect()));
+        assert!(parse("The answer is: 4").unwrap_err().to_string().contains("line #1"));
+        assert!(parse("@@ D01 P1 a.txt\n1\n@@ D01 P1 a.txt\n2").unwrap_err().to_string().contains("already defined"));
+        assert!(parse("@@ D01 P1 a.txt\n1\n\nstray").unwrap_err().to_string().contains("line #4"));
+    }
+
+    #[test]
+    pub fn test_answers_parsing_comments_between_entries() {
+        // A report ends at the empty line, so the comment and the blank line after it are not part of it,
+        // while a report line starting with '#' is
+        let text = "@@ D01 P1 a.txt\n1\n\n# comment\n \n@@ D01 P2 a.txt\n# #\n2";
+        let lines = crate::reader::VecLine::from_text(text);
+        let store = AnswersParser::new().unwrap().parse(lines).unwrap();
+        let expected = store.entries.iter().map(|entry|entry.expected.clone()).collect::<Vec<_>>();
+        assert_eq!(expected, vec![Some("1".to_string()), Some("# #\n2".to_string())]);
+    }
+
+    #[test]
+    pub fn test_line_diff() {
+        assert_eq!(line_diff("a\nb\nc", "a\nb\nc"), "");
+        assert_eq!(line_diff("a\nb", "a\nx\ny"), "@ line 2\n- b\n+ x\n@ line 3\n+ y");
+    }
+
+    #[test]
+    pub fn test_check() {
+        let manager = create_executer_manager().unwrap();
+        let outcome = check_answers(&manager, &resolve_filepath(REL_FILEPATHS[0]), false).unwrap();
+        let statuses = outcome.records.iter().map(|record|&record.status).collect::<Vec<_>>();
+
+        assert_eq!(statuses[0], &CheckStatus::Pass);
+        assert_eq!(statuses[1], &CheckStatus::Fail(line_diff("The answer is: 9", "The answer is: 8")));
+        assert_eq!(statuses[2], &CheckStatus::Missing(Ok("The answer is: co,de,ka,ta".to_string())));
+        assert!(matches!(statuses[3], CheckStatus::Fail(error) if error.contains("execution failed")));
+        assert!(outcome.has_failures());
+        assert_eq!(outcome.recorded, 0);
+    }
+
+    #[test]
+    pub fn test_check_with_record() {
+        let input = resolve_filepath("src/day_24/test/example.txt");
+        let adder_input = resolve_filepath("src/day_24/test/adder_example.txt");
+        let answers_filepath = std::env::temp_dir().join(format!("aoc_2024_answers_{}.txt", std::process::id()));
+        let answers_filepath = answers_filepath.to_string_lossy().to_string();
+        std::fs::write(&answers_filepath, format!("# Comment kept\n\n@@ D24 P1 {}\n\n# Comment between entries\n@@ D24 P1 {}\nThe answer is: 9", input, adder_input)).unwrap();
+
+        let manager = create_executer_manager().unwrap();
+        let outcome = check_answers(&manager, &answers_filepath, true).unwrap();
+        assert_eq!(outcome.recorded, 1);
+
+        // Checking again, the recorded answer is now expected and it passes
+        let outcome = check_answers(&manager, &answers_filepath, true).unwrap();
+        let written = std::fs::read_to_string(&answers_filepath).unwrap();
+        std::fs::remove_file(&answers_filepath).unwrap();
+        assert_eq!(outcome.records[0].status, CheckStatus::Pass);
+        assert_eq!(outcome.recorded, 0);
+        assert_eq!(written, format!("# Comment kept\n\n@@ D24 P1 {}\nThe answer is: 4\n\n# Comment between entries\n@@ D24 P1 {}\nThe answer is: 9", input, adder_input));
+    }
+
+    #[test]
+    pub fn test_record_missing_ends_answer() {
+        // The recorded answer is ended by an empty line, so that it does not run into the next header
+        let entry = AnswerEntry { day: 1, is_part_2: false, input_filepath: "a.txt".to_string(), expected: None, header_line: 1 };
+        let records = [CheckRecord { entry, status: CheckStatus::Missing(Ok("1".to_string())) }];
+        assert_eq!(record_missing("@@ D01 P1 a.txt\n@@ D01 P2 a.txt\n", &records), ("@@ D01 P1 a.txt\n1\n\n@@ D01 P2 a.txt\n".to_string(), 1));
+        assert_eq!(record_missing("@@ D01 P1 a.txt", &records), ("@@ D01 P1 a.txt\n1\n".to_string(), 1));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{create_executer_manager_with, executer_manager::ExecuterManager, helper::display::vector_display, parameters::Parameters, pipeline_error::PipelineError};
+
+mod error {
+    const PREFIX: &str = "[Repl]";
+
+    pub fn unknown_command(line: &str) -> String {
+        format!("{} unknown command '{}', type 'help' for the list of commands", PREFIX, line)
+    }
+
+    pub fn invalid_value(what: &str, value: &str) -> String {
+        format!("{} invalid {} '{}'", PREFIX, what, value)
+    }
+
+    pub fn no_input_file() -> String {
+        format!("{} no input file selected, use 'file <PATH>' or 'run <PATH>'", PREFIX)
+    }
+}
+
+const HELP: &str = "Commands:
+  run [PATH]          Solve the selected day and part, switching to the input file if given
+  both [PATH]         Solve both parts, switching to the input file if given
+  model [PATH]        Show the parsed model of the input file
+  file <PATH>         Select the input file
+  day <DAY|auto>      Select the day, or resolve it from the input file path
+  year <YEAR|auto>    Select the year, or resolve it from the input file path
+  part <1|2>          Select the part
+  param [KEY=VALUE]   Override a parameter, or list the overrides
+  unset <KEY>         Remove the override of the parameter
+  status              Show the selection and parameter overrides
+  error               Show the details of the last error
+  help                Print this message
+  quit                Leave the shell";
+
+/// Outcome of handling a line entered in the shell
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Reply {
+    /// Text to print; an empty text prints nothing
+    Output(String),
+    Quit,
+}
+
+/// State of an interactive shell: the executer manager built once and kept in memory, the selected input file,
+/// day, year and part, the parameter overrides and the last error. The manager is built again only when the
+/// parameter overrides or the year change.
+pub struct Session {
+    manager: ExecuterManager,
+    parameters: Parameters,
+    input_filepath: Option<String>,
+    day: Option<u8>,
+    year: Option<u16>,
+    is_part_2: bool,
+    last_error: Option<PipelineError>,
+}
+
+impl Session {
+    pub fn new(parameters: Parameters, year: Option<u16>) -> Result<Session, String> {
+        let manager = create_executer_manager_with(parameters.clone())?.with_year(year);
+        Ok(Session { manager, parameters, input_filepath: None, day: None, year, is_part_2: false, last_error: None })
+    }
+
+    /// Selects the input file
+    pub fn with_input(self, input_filepath: Option<String>) -> Session {
+        Session { input_filepath, ..self }
+    }
+
+    /// Handles the line entered in the shell. A failed command is reported and remembered as the last error.
+    pub fn handle(&mut self, line: &str) -> Reply {
+        match self.command(line.trim()) {
+            Ok(reply) => reply,
+            Err(error) => {
+                let reply = Reply::Output(error.to_string());
+                self.last_error = Some(error);
+                reply
+            },
+        }
+    }
+
+    fn command(&mut self, line: &str) -> Result<Reply, PipelineError> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let output = match words.as_slice() {
+            [] => String::new(),
+            ["quit" | "exit"] => return Ok(Reply::Quit),
+            ["help"] => HELP.to_string(),
+            ["run", path @ ..] if path.len() <= 1 => self.run(path.first())?,
+            ["both", path @ ..] if path.len() <= 1 => self.run_both(path.first())?,
+            ["model", path @ ..] if path.len() <= 1 => {
+                let input_filepath = self.select_input(path.first())?;
+                self.manager.try_display_model(&input_filepath, self.day, self.is_part_2)?
+            },
+            ["file", path] => {
+                self.input_filepath = Some(path.to_string());
+                format!("Input file: {}", path)
+            },
+            ["day", "auto"] => { self.day = None; self.status() },
+            ["day", day] => {
+                self.day = Some(day.parse().map_err(|_|error::invalid_value("day", day))?);
+                self.status()
+            },
+            ["year", year] => {
+                let year = match *year {
+                    "auto" => None,
+                    year => Some(year.parse().map_err(|_|error::invalid_value("year", year))?),
+                };
+                self.rebuild(self.parameters.clone(), year)?;
+                self.status()
+            },
+            ["part", "1"] => { self.is_part_2 = false; self.status() },
+            ["part", "2"] => { self.is_part_2 = true; self.status() },
+            ["part", part] => return Err(error::invalid_value("part", part).into()),
+            ["param"] => self.parameters_display(),
+            ["param", assignment] => {
+                let (key, value) = assignment.split_once('=')
+                    .filter(|(key, value)|!key.is_empty() && !value.is_empty())
+                    .ok_or_else(||error::invalid_value("parameter, expected KEY=VALUE,", assignment))?;
+                let mut parameters = self.parameters.clone();
+                parameters.insert(key, value);
+                self.rebuild(parameters, self.year)?;
+                self.parameters_display()
+            },
+            ["unset", key] => {
+                let mut parameters = self.parameters.clone();
+                parameters.remove(key);
+                self.rebuild(parameters, self.year)?;
+                self.parameters_display()
+            },
+            ["status"] => self.status(),
+            ["error"] => self.last_error.as_ref().map_or_else(||String::from("No error so far"), describe_error),
+            _ => return Err(error::unknown_command(line).into()),
+        };
+        Ok(Reply::Output(output))
+    }
+
+    /// Builds the executer manager again with the parameters and the year, keeping the current one if it fails
+    fn rebuild(&mut self, parameters: Parameters, year: Option<u16>) -> Result<(), String> {
+        self.manager = create_executer_manager_with(parameters.clone())?.with_year(year);
+        self.parameters = parameters;
+        self.year = year;
+        Ok(())
+    }
+
+    /// Switches to the input file, if given, and returns the selected one
+    fn select_input(&mut self, input_filepath: Option<&&str>) -> Result<String, String> {
+        if let Some(input_filepath) = input_filepath {
+            self.input_filepath = Some(input_filepath.to_string());
+        }
+        self.input_filepath.clone().ok_or_else(error::no_input_file)
+    }
+
+    fn run(&mut self, input_filepath: Option<&&str>) -> Result<String, PipelineError> {
+        let input_filepath = self.select_input(input_filepath)?;
+        let (outcome, timings) = self.manager.try_execute_timed(&input_filepath, self.day, self.is_part_2)?;
+        Ok(format!("Timings: {}\n{}", timings, outcome?.report().trim_end()))
+    }
+
+    fn run_both(&mut self, input_filepath: Option<&&str>) -> Result<String, PipelineError> {
+        let input_filepath = self.select_input(input_filepath)?;
+        let mut lines = vec![];
+        for record in self.manager.try_execute_both(&input_filepath, self.day)? {
+            lines.push(format!("Part {}:", if record.key.is_part_2 { 2 } else { 1 }));
+            lines.push(format!("Timings: {}", record.timings));
+            match record.outcome {
+                Ok(answer) => lines.push(answer.report().trim_end().to_string()),
+                Err(error) => {
+                    lines.push(error.to_string());
+                    self.last_error = Some(error);
+                },
+            }
+        }
+        Ok(vector_display(&lines, "\n"))
+    }
+
+    fn parameters_display(&self) -> String {
+        match self.parameters.is_empty() {
+            true => String::from("Parameters: defaults"),
+            false => format!("Parameters: {}", self.parameters),
+        }
+    }
+
+    fn status(&self) -> String {
+        let auto = String::from("auto");
+        vector_display(&vec![
+            format!("Input file: {}", self.input_filepath.as_deref().unwrap_or("none")),
+            format!("Day: {}", self.day.map_or(auto.clone(), |day|format!("{:02}", day))),
+            format!("Year: {}", self.year.map_or(auto, |year|year.to_string())),
+            format!("Part: {}", if self.is_part_2 { 2 } else { 1 }),
+            self.parameters_display(),
+        ], "\n")
+    }
+}
+
+/// Describes the error with everything known about it: the chain of messages, the stage, the problem
+/// and the snippet of the input line
+fn describe_error(error: &PipelineError) -> String {
+    let mut lines = vec![error.to_string()];
+    if let Some(stage) = error.stage {
+        lines.push(format!("Stage: {}", stage));
+    }
+    if let (Some(day), Some(part)) = (error.day, error.part) {
+        lines.push(format!("Problem: day {:02}, part {}", day, part));
+    }
+    lines.extend(error.snippet());
+    vector_display(&lines, "\n")
+}
@@ -1,7 +1,37 @@
-use crate::answer::Answer;
+use crate::{answer::Answer, parameters::Parameters, pipeline_error::PipelineError};
 
 pub type Solver<T> = Box<dyn Solve<T>>;
 
-pub trait Solve<T: Clone> {
-    fn solve(&self, input: T) -> Result<Answer, String>;
+pub trait Solve<T: Clone>: Send + Sync {
+    fn solve(&self, input: T) -> Result<Answer, PipelineError>;
+
+    /// Solves with the parameters declared in the input file. Solvers without parameters just ignore them.
+    fn solve_with(&self, input: T, _parameters: &Parameters) -> Result<Answer, PipelineError> {
+        self.solve(input)
+    }
+}
+
+type MakeSolverFn<T> = Box<dyn Fn(&Parameters) -> Result<Solver<T>, String> + Send + Sync>;
+
+/// A solver that is created only once the parameters declared in the input file are known.
+/// Solving without parameters creates the solver from an empty set, i.e. with the defaults.
+pub struct ParameterisedSolver<T> {
+    make_solver: MakeSolverFn<T>,
+}
+
+impl <T: Clone> ParameterisedSolver<T> {
+    pub fn new<S, F>(make_solver: F) -> ParameterisedSolver<T>
+    where S: Solve<T> + 'static, F: Fn(&Parameters) -> Result<S, String> + Send + Sync + 'static {
+        ParameterisedSolver { make_solver: Box::new(move |parameters|make_solver(parameters).map(|solver|Box::new(solver) as Solver<T>)) }
+    }
+}
+
+impl <T: Clone> Solve<T> for ParameterisedSolver<T> {
+    fn solve(&self, input: T) -> Result<Answer, PipelineError> {
+        self.solve_with(input, &Parameters::new())
+    }
+
+    fn solve_with(&self, input: T, parameters: &Parameters) -> Result<Answer, PipelineError> {
+        (self.make_solver)(parameters)?.solve(input)
+    }
 }
\ No newline at end of file
@@ -1,6 +1,18 @@
 mod test_executer_manager;
 mod testing_utils;
-mod test_arguments;
+mod test_benchmark;
+mod test_pipeline_error;
+mod test_json_output;
+mod test_combinator;
+mod test_grid;
+mod test_table;
+mod test_search;
+mod test_geometry;
+mod test_days;
+mod test_watch;
+mod test_repl;
+mod test_trace;
+mod test_verifier;
 
 #[cfg(test)]
 pub use self::testing_utils::*;
\ No newline at end of file
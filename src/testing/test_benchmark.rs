@@ -0,0 +1,45 @@
+#[cfg(test)]
+pub mod benchmark_test_suite {
+    use std::time::Duration;
+
+    use crate::{benchmark::{run_benchmark, DurationStats}, create_executer_manager, executer::Stage, testing::resolve_filepath};
+
+    #[test]
+    pub fn test_duration_stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(DurationStats::new(vec![]), None);
+        assert_eq!(DurationStats::new(vec![ms(5), ms(1), ms(3)]), Some(DurationStats { min: ms(1), median: ms(3), max: ms(5) }));
+        assert_eq!(DurationStats::new(vec![ms(8), ms(2), ms(4), ms(1)]), Some(DurationStats { min: ms(1), median: ms(3), max: ms(8) }));
+    }
+
+    #[test]
+    pub fn test_pipelined_executer_measures_all_stages() {
+        let manager = create_executer_manager().unwrap();
+        let input = resolve_filepath("src/day_24/test/example.txt");
+        let (outcome, timings) = manager.try_execute_timed(&input, None, false).unwrap();
+        assert!(outcome.is_ok());
+        assert_eq!(timings.stages.iter().map(|&(stage, _)|stage).collect::<Vec<_>>(), Stage::all());
+    }
+
+    #[test]
+    pub fn test_pipelined_executer_stops_measuring_at_failed_stage() {
+        let manager = create_executer_manager().unwrap();
+        let input = resolve_filepath("src/day_24/test/cycle_example.txt");
+        let (outcome, timings) = manager.try_execute_timed(&input, None, false).unwrap();
+        assert!(outcome.is_err());
+        assert_eq!(timings.stages.iter().map(|&(stage, _)|stage).collect::<Vec<_>>(), vec![Stage::Read, Stage::Parse, Stage::Verify]);
+    }
+
+    #[test]
+    pub fn test_run_benchmark() {
+        let manager = create_executer_manager().unwrap();
+        let input = resolve_filepath("src/day_24/test/example.txt");
+        let report = run_benchmark(&manager, &input, None, false, 3).unwrap();
+        assert_eq!(report.samples.len(), 3);
+        assert!(Stage::all().into_iter().all(|stage|report.stage_stats(stage).is_some()));
+
+        assert!(run_benchmark(&manager, &input, None, false, 0).is_err());
+        let failing_input = resolve_filepath("src/day_24/test/cycle_example.txt");
+        assert!(run_benchmark(&manager, &failing_input, None, false, 3).unwrap_err().contains("run #1"));
+    }
+}
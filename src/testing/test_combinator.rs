@@ -0,0 +1,54 @@
+#[cfg(test)]
+pub mod combinator_test_suite {
+    use crate::{helper::combinator::{blocks, char_grid, chunks, each_line, integer, literal, single_line, spaces1, symbol}, reader::Line};
+
+    fn lines(texts: &[&str]) -> Vec<Line> {
+        texts.iter().enumerate().map(|(i, text)|Line::new(text.to_string(), i + 1)).collect()
+    }
+
+    #[test]
+    pub fn test_separated_integers() {
+        let numbers = integer::<i64>().separated_by(symbol(","));
+        let line = Line::new("3, -4 ,5".to_string(), 1);
+        assert_eq!(numbers.parse_line(&line, "[Test]").unwrap(), vec![3, -4, 5]);
+    }
+
+    #[test]
+    pub fn test_error_carries_line_and_column() {
+        let pair = integer::<u32>().then_skip(literal(": ")).then(integer::<u32>().separated_by(spaces1()));
+        let input = lines(&["1: 2 3", "4: 5 x"]);
+        let error = each_line(&input, &pair, "[Test]").unwrap_err();
+        assert_eq!(error.line_number(), Some(2));
+        assert_eq!(error.column, Some(5));
+        assert_eq!(error.to_string(), "[Test] expected end of line, found ' x'");
+    }
+
+    #[test]
+    pub fn test_integer_overflow_is_reported() {
+        let line = Line::new("300".to_string(), 7);
+        let error = integer::<u8>().parse_line(&line, "[Test]").unwrap_err();
+        assert_eq!(error.column, Some(1));
+        assert!(error.to_string().starts_with("[Test] expected a valid number instead of '300'"));
+    }
+
+    #[test]
+    pub fn test_line_groups() {
+        let input = lines(&["a", "b", "", "", "c"]);
+        assert_eq!(blocks(&input).iter().map(|block|block.len()).collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(chunks(&input[..4], 2, "[Test]").unwrap().len(), 2);
+        assert!(chunks(&input, 2, "[Test]").is_err());
+        assert!(single_line(&input, "[Test]").is_err());
+        assert_eq!(single_line(&input[..1], "[Test]").unwrap().text, "a");
+    }
+
+    #[test]
+    pub fn test_char_grid() {
+        let digit = |c: char|c.to_digit(10);
+        let grid = char_grid(&lines(&["12", "34"]), "a digit", "[Test]", digit).unwrap();
+        assert_eq!(grid.dim(), (2, 2));
+
+        let error = char_grid(&lines(&["12", "3?"]), "a digit", "[Test]", digit).unwrap_err();
+        assert_eq!((error.line_number(), error.column), (Some(2), Some(2)));
+        assert_eq!(error.to_string(), "[Test] expected a digit, found '?'");
+    }
+}
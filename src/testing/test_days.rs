@@ -0,0 +1,24 @@
+#[cfg(test)]
+pub mod days_test_suite {
+    use crate::{create_executer_manager, day::YEAR, executer_manager::ExecuterKey, DAYS};
+
+    #[test]
+    pub fn test_days_are_declared_in_order() {
+        let numbers = DAYS.iter().map(|day|day.number).collect::<Vec<_>>();
+        assert_eq!(numbers, (1..=25).collect::<Vec<_>>());
+        assert!(DAYS.iter().all(|day|day.year == YEAR));
+        assert!(DAYS.iter().all(|day|!day.title.is_empty()));
+        assert_eq!(DAYS.iter().filter(|day|!day.has_part_2).map(|day|day.number).collect::<Vec<_>>(), vec![25]);
+    }
+
+    #[test]
+    pub fn test_all_declared_parts_are_registered() {
+        let keys = create_executer_manager().unwrap().registered_keys();
+        let expected = DAYS.iter()
+            .flat_map(|day|[false, true].into_iter()
+                .filter(|&is_part_2|!is_part_2 || day.has_part_2)
+                .map(|is_part_2|ExecuterKey { year: day.year, day: day.number, is_part_2 }))
+            .collect::<Vec<_>>();
+        assert_eq!(keys, expected);
+    }
+}
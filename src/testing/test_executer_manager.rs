@@ -1,6 +1,11 @@
 #[cfg(test)]
 pub mod executer_manager_test_suite {
-    use crate::{answer::{Answer, DisplayableAnswer}, executer::Execute, executer_manager::ExecuterManager};
+    use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+
+    use crate::{answer::{Answer, DisplayableAnswer}, executer::Execute, executer_manager::{ExecuterKey, ExecuterManager}, parameters::{ParameterSpec, Parameters}, parser::TrivialParser, pipeline_error::PipelineError, pipelined_executer::try_make_pipeline, reader::{Read, VecLine}, solver::{ParameterisedSolver, Solve}, verifier::TrivialVerifier};
+
+    /// Year under which the testing executers are registered
+    const YEAR: u16 = 2024;
 
     /// Creates an standard displayable answer implementation that reports string `value`
     fn str_report(value: &str) -> Answer {
@@ -21,7 +26,7 @@ pub mod executer_manager_test_suite {
     }
     
     impl Execute for TestingExecuter{
-        fn execute(&self, _input_filepath: &str) -> Result<Answer, String> {
+        fn execute(&self, _input_filepath: &str) -> Result<Answer, PipelineError> {
             Ok(str_report(&self.value))
         }
     }
@@ -36,7 +41,7 @@ pub mod executer_manager_test_suite {
     fn get_filled_exec_manager() -> ExecuterManager {
         let mut manager = get_exec_manager();
         for (day, ip2) in [(3, false), (5, true), (11, false)] {
-            let res = manager.register(day, ip2, TestingExecuter::new(day, ip2), false);
+            let res = manager.register(YEAR, day, ip2, TestingExecuter::new(day, ip2), false);
             assert!(res.is_ok());
             manager = res.unwrap();
         }
@@ -97,14 +102,76 @@ pub mod executer_manager_test_suite {
         let manager = get_exec_manager();
         
         // It should be fine to register, day 3 part 2 or day 25 part 1
-        let res = manager.register(3, true, TestingExecuter::new(3, true), false);
+        let res = manager.register(YEAR, 3, true, TestingExecuter::new(3, true), false);
         assert!(res.is_ok());
-        let res = res.unwrap().register(25, false, TestingExecuter::new(25, false), false);
+        let res = res.unwrap().register(YEAR, 25, false, TestingExecuter::new(25, false), false);
         assert!(res.is_ok());
 
         // It should FAIL registering, say, day 0 and 26
-        assert!(get_exec_manager().register(0, true, TestingExecuter::new(0, true), false).is_err());
-        assert!(get_exec_manager().register(26, false, TestingExecuter::new(26, false), false).is_err());
+        assert!(get_exec_manager().register(YEAR, 0, true, TestingExecuter::new(0, true), false).is_err());
+        assert!(get_exec_manager().register(YEAR, 26, false, TestingExecuter::new(26, false), false).is_err());
+
+        // ... and so it should for a year before the first event
+        assert!(get_exec_manager().register(2014, 3, false, TestingExecuter::new(3, false), false).is_err());
+    }
+
+    #[test]
+    pub fn test_executer_manager_year_resolution() {
+        // Day 5 part 2 is registered for 2024, and now for 2023 as well
+        let manager = get_filled_exec_manager()
+            .register(2023, 5, true, TestingExecuter { value: String::from("2023") }, false)
+            .unwrap();
+        for (input, expected) in [
+            ("y2023/d05.txt", "2023"),
+            ("inputs/2024/day-05.txt", "5A"),
+            ("2024/y2023/d05.txt", "2023"),
+        ] {
+            let report = manager.try_execute_executer(input, None, true);
+            assert_eq!(report.unwrap().report(), str_report(expected).report(), "{}", input);
+        }
+
+        // With multiple years registered, the year cannot be resolved from a path without it
+        let error = manager.try_execute_executer("d05.txt", None, true).unwrap_err();
+        assert!(error.to_string().contains("year not provided") && error.to_string().contains("registered years: 2023, 2024"), "{}", error);
+
+        // An explicitly set year takes precedence over the one in the path
+        let manager = manager.with_year(Some(2023));
+        assert_eq!(manager.try_execute_executer("2024/d05.txt", None, true).unwrap().report(), str_report("2023").report());
+        assert!(manager.try_execute_executer("2024/d03.txt", None, false).is_err());
+
+        // With a single year registered, it is used for paths without a year
+        let manager = get_filled_exec_manager();
+        assert_eq!(manager.try_execute_executer("d05.txt", None, true).unwrap().report(), str_report("5A").report());
+
+        // ... but a path naming another year is not run by it
+        let error = manager.try_execute_executer("inputs/y2023/d05.txt", None, true).unwrap_err();
+        assert!(error.to_string().contains("year 2023 resolved from filename 'inputs/y2023/d05.txt' is not registered (registered years: 2024)"), "{}", error);
+
+        // ... unless another year is set explicitly, which the errors tell
+        let error = manager.with_year(Some(2023)).try_execute_executer("d05.txt", None, true).unwrap_err();
+        assert!(error.to_string().contains("2023-D05-P2"), "{}", error);
+        let error = get_filled_exec_manager().try_execute_executer("d42.txt", None, true).unwrap_err();
+        assert!(error.to_string().contains("invalid day number: 42 (year 2024)"), "{}", error);
+    }
+
+    /// An executer panicking on execution
+    struct PanickingExecuter;
+
+    impl Execute for PanickingExecuter {
+        fn execute(&self, _input_filepath: &str) -> Result<Answer, PipelineError> {
+            panic!("attempt to add with overflow")
+        }
+    }
+
+    #[test]
+    pub fn test_panicking_executer_is_reported() {
+        let manager = get_exec_manager().register(YEAR, 14, true, PanickingExecuter, false).unwrap();
+        let is_panic = |error: &PipelineError|error.to_string().contains("executer panicked")
+            && error.to_string().contains("attempt to add with overflow") && error.day == Some(14);
+
+        assert!(is_panic(&manager.try_execute_executer("input.txt", Some(14), true).unwrap_err()));
+        assert!(is_panic(&manager.try_execute_timed("input.txt", Some(14), true).unwrap().0.unwrap_err()));
+        assert!(is_panic(&manager.try_execute_traced("input.txt", Some(14), true).unwrap().0.unwrap_err()));
     }
 
     #[test]
@@ -119,13 +186,186 @@ pub mod executer_manager_test_suite {
         assert_eq!(report.unwrap().report(), str_report("11").report())
     }
 
+    #[test]
+    pub fn test_executer_manager_stdin_day_resolution() {
+        let manager = get_filled_exec_manager();
+
+        // Reading from stdin leaves no filepath to resolve the day from, so the day has to be given explicitly
+        let error = manager.try_execute_executer("-", None, false).unwrap_err();
+        assert!(error.to_string().contains("stdin"));
+        let report = manager.try_execute_executer("-", Some(3), false);
+        assert_eq!(report.unwrap().report(), str_report("3").report());
+    }
+
     #[test]
     pub fn test_overwrite_detection() {
         // We already have day 5 part 2 registered, but we set the overwrite flag to true to allow this
-        assert!(get_filled_exec_manager().register(5, true, TestingExecuter::new(5, true), true).is_ok());
+        assert!(get_filled_exec_manager().register(YEAR, 5, true, TestingExecuter::new(5, true), true).is_ok());
 
         // Now, we do it again, but with overwrite flag set to false, we expect failure
-        assert!(get_filled_exec_manager().register(5, true, TestingExecuter::new(5, true), false).is_err());
+        assert!(get_filled_exec_manager().register(YEAR, 5, true, TestingExecuter::new(5, true), false).is_err());
         
     }
-}
\ No newline at end of file
+
+    #[test]
+    pub fn test_execute_directory() {
+        let manager = get_filled_exec_manager();
+        let directory = format!("{}/src/testing/test/inputs", env!("CARGO_MANIFEST_DIR"));
+        let records = manager.try_execute_directory(&directory);
+        assert!(records.is_ok());
+
+        // `d03.txt` resolves to day 3, which has only part 1 registered, and `nested/day-05.txt` resolves
+        // to day 5, which has only part 2 registered. `readme.txt` does not resolve to any day and is skipped.
+        let records = records.unwrap();
+        let summary = records.iter()
+            .map(|record|(record.key.day, record.key.is_part_2, record.outcome.as_ref().unwrap().report()))
+            .collect::<Vec<_>>();
+        assert_eq!(summary, vec![(3, false, str_report("3").report()), (5, true, str_report("5A").report())]);
+        assert!(records[1].input_filepath.ends_with("day-05.txt"));
+    }
+
+    #[test]
+    pub fn test_execute_jobs_in_parallel() {
+        let manager = get_filled_exec_manager().with_workers(4);
+        let jobs = (0..20)
+            .map(|i|(ExecuterKey { year: YEAR, day: [3, 5, 11][i % 3], is_part_2: i % 3 == 1 }, format!("input-{}.txt", i)))
+            .collect::<Vec<_>>();
+        let records = manager.execute_jobs(&jobs);
+
+        // Records come in the order of the jobs, regardless of which worker executed them
+        let summary = records.iter()
+            .map(|record|(record.key, record.input_filepath.clone(), record.outcome.as_ref().unwrap().report()))
+            .collect::<Vec<_>>();
+        let expected = jobs.iter()
+            .map(|(key, input_filepath)|(*key, input_filepath.clone(), str_report(&format!("{}{}", key.day, if key.is_part_2 { "A" } else { "" })).report()))
+            .collect::<Vec<_>>();
+        assert_eq!(summary, expected);
+
+        // A job without a registered executer fails on its own
+        let records = manager.execute_jobs(&[(ExecuterKey { year: YEAR, day: 3, is_part_2: true }, "a.txt".to_string()), jobs[0].clone()]);
+        assert!(records[0].outcome.is_err() && records[1].outcome.is_ok());
+    }
+
+    #[test]
+    pub fn test_execute_missing_directory() {
+        let manager = get_filled_exec_manager();
+        assert!(manager.try_execute_directory("this/directory/does/not/exist").is_err());
+    }
+
+    /// Reader of an in-memory input that counts how many times it was read
+    struct CountingReader {
+        reads: Arc<AtomicUsize>,
+    }
+
+    impl Read for CountingReader {
+        fn read(&self, _input_file_path: &str) -> Result<VecLine, PipelineError> {
+            self.reads.fetch_add(1, Ordering::Relaxed);
+            Ok(VecLine::from_text("a\nb\nc"))
+        }
+    }
+
+    /// Solver answering the number of lines, multiplied by the factor
+    struct LineCounter(usize);
+
+    impl Solve<VecLine> for LineCounter {
+        fn solve(&self, input: VecLine) -> Result<Answer, PipelineError> {
+            Ok(DisplayableAnswer::new(input.lines.len() * self.0))
+        }
+    }
+
+    #[test]
+    pub fn test_execute_both_shares_reading() {
+        let reads = Arc::new(AtomicUsize::new(0));
+        let make_pipeline = |is_part_2: bool|try_make_pipeline(
+            Ok(CountingReader { reads: reads.clone() }),
+            Ok(TrivialParser::new()),
+            Ok(TrivialVerifier::new::<VecLine>()),
+            Ok(LineCounter(if is_part_2 { 10 } else { 1 })));
+        let manager = get_exec_manager().try_register_day(YEAR, 7, make_pipeline, false).unwrap();
+
+        let records = manager.try_execute_both("input.txt", Some(7)).unwrap();
+        assert_eq!(reads.load(Ordering::Relaxed), 1);
+        let answers = records.iter()
+            .map(|record|(record.key.is_part_2, record.outcome.as_ref().unwrap().report()))
+            .collect::<Vec<_>>();
+        assert_eq!(answers, vec![(false, DisplayableAnswer::new(3usize).report()), (true, DisplayableAnswer::new(30usize).report())]);
+
+        // Single parts are registered as well
+        assert_eq!(manager.try_execute_executer("input.txt", Some(7), true).unwrap().report(), DisplayableAnswer::new(30usize).report());
+    }
+
+    #[test]
+    pub fn test_execute_both_without_shared_executer() {
+        // Days registered part by part are executed one part after another, skipping the unregistered parts
+        let manager = get_filled_exec_manager();
+        let records = manager.try_execute_both("day05/input.txt", None).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].outcome.as_ref().unwrap().report(), str_report("5A").report());
+        assert!(manager.try_execute_both("day06/input.txt", None).is_err());
+    }
+
+    /// Reader of three lines, declaring the parameters in the input file
+    struct DeclaringReader(Parameters);
+
+    impl Read for DeclaringReader {
+        fn read(&self, _input_file_path: &str) -> Result<VecLine, PipelineError> {
+            Ok(VecLine::from_text("a\nb\nc").with_parameters(self.0.clone()))
+        }
+    }
+
+    const FACTOR_PARAMETERS: &[ParameterSpec] = &[
+        ParameterSpec::unsigned("factor", "10", "Factor of the number of lines in part 2").at_least(1).at_most(1000),
+    ];
+
+    fn register_factor_day(manager: ExecuterManager, declared: &Parameters) -> Result<ExecuterManager, String> {
+        let make_pipeline = |is_part_2: bool|try_make_pipeline(
+            Ok(DeclaringReader(declared.clone())),
+            Ok(TrivialParser::new()),
+            Ok(TrivialVerifier::new::<VecLine>()),
+            Ok(ParameterisedSolver::new(move |parameters|
+                parameters.require("factor").map(|factor|LineCounter(if is_part_2 { factor } else { 1 })))));
+        manager.try_register_parameterised_day(YEAR, 7, true, FACTOR_PARAMETERS, make_pipeline, false)
+            .and_then(ExecuterManager::check_parameters)
+    }
+
+    fn parameters(pairs: &[(&str, &str)]) -> Parameters {
+        let mut parameters = Parameters::new();
+        for (key, value) in pairs {
+            parameters.insert(key, value);
+        }
+        parameters
+    }
+
+    #[test]
+    pub fn test_parameterised_day() {
+        let answer = |overrides: &[(&str, &str)], declared: &[(&str, &str)]|{
+            let manager = register_factor_day(get_exec_manager().with_parameters(parameters(overrides)), &parameters(declared)).unwrap();
+            manager.try_execute_executer("input.txt", Some(7), true).unwrap().report()
+        };
+        // Default value, then the value declared in the input file
+        assert_eq!(answer(&[], &[]), DisplayableAnswer::new(30usize).report());
+        assert_eq!(answer(&[], &[("factor", "2")]), DisplayableAnswer::new(6usize).report());
+        // Overriding values take precedence over the input file, and scoped ones over unscoped ones
+        assert_eq!(answer(&[("factor", "100")], &[("factor", "2")]), DisplayableAnswer::new(300usize).report());
+        assert_eq!(answer(&[("d07.factor", "5")], &[("factor", "2")]), DisplayableAnswer::new(15usize).report());
+        assert_eq!(answer(&[("factor", "100"), ("d07.factor", "5")], &[]), DisplayableAnswer::new(15usize).report());
+    }
+
+    #[test]
+    pub fn test_parameterised_day_errors() {
+        let with_override = |key: &str, value: &str|register_factor_day(get_exec_manager().with_parameters(parameters(&[(key, value)])), &Parameters::new());
+        let error = with_override("factor", "many").err().unwrap();
+        assert!(error.contains("day 7") && error.contains("'factor' has an invalid value 'many'"), "{}", error);
+        let error = with_override("factor", "1001").err().unwrap();
+        assert!(error.contains("day 7") && error.contains("expected unsigned integer in 1..=1000"), "{}", error);
+        let error = with_override("divisor", "2").err().unwrap();
+        assert!(error.contains("no day declares parameter 'divisor'"), "{}", error);
+        let error = with_override("d08.factor", "2").err().unwrap();
+        assert!(error.contains("day 8 does not declare parameter 'factor'"), "{}", error);
+
+        // Values declared in the input file are checked when reading
+        let manager = register_factor_day(get_exec_manager(), &parameters(&[("factor", "0")])).unwrap();
+        let error = manager.try_execute_executer("input.txt", Some(7), true).err().unwrap();
+        assert!(error.to_string().contains("expected unsigned integer in 1..=1000"), "{}", error);
+    }
+}
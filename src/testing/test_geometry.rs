@@ -0,0 +1,42 @@
+#[cfg(test)]
+pub mod geometry_test_suite {
+    use crate::helper::{geometry::{Point, Vector}, position::UPosition};
+
+    #[test]
+    pub fn test_arithmetic() {
+        let point = Point::new(2, -3);
+        let vector = Vector::new(4, 1);
+        assert_eq!(point + vector, Point::new(6, -2));
+        assert_eq!(point - vector, Point::new(-2, -4));
+        assert_eq!(Point::new(6, -2) - point, vector);
+        assert_eq!(vector * 3 - vector, Vector::new(8, 2));
+        assert_eq!(-vector, Vector::new(-4, -1));
+    }
+
+    #[test]
+    pub fn test_distances() {
+        let a = Point::new(1u64, 7);
+        let b = Point::new(4u64, 2);
+        assert_eq!(a.manhattan(b), 8);
+        assert_eq!(a.chebyshev(b), 5);
+        assert_eq!(Vector::new(-3, 2).manhattan_length(), 5);
+        assert_eq!(Vector::new(-3, 2).chebyshev_length(), 3);
+    }
+
+    #[test]
+    pub fn test_wrapping() {
+        let area = Vector::new(11, 7);
+        assert_eq!((Point::new(2, 4) + Vector::new(2, -3) * 5).wrap(area), Point::new(1, 3));
+        assert!(Point::new(10, 6).is_within(area));
+        assert!(!Point::new(-1, 6).is_within(area));
+        assert!(!Point::new(0, 7).is_within(area));
+    }
+
+    #[test]
+    pub fn test_uposition_conversion() {
+        let position = UPosition::new((3, 5));
+        let point = Point::from(position);
+        assert_eq!((point.x, point.y), (5, 3));
+        assert_eq!(UPosition::from(point), position);
+    }
+}
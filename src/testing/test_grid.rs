@@ -0,0 +1,80 @@
+#[cfg(test)]
+pub mod grid_test_suite {
+    use crate::{helper::{grid::{CharMapping, GridParser}, position::UPosition}, reader::Line};
+
+    #[derive(Clone, Copy, Eq, PartialEq, Debug)]
+    enum Cell {
+        Wall,
+        Free,
+        Start,
+        Exit,
+    }
+
+    const MAPPING: CharMapping<Cell> = CharMapping::new(&[
+        ('#', Cell::Wall),
+        ('.', Cell::Free),
+        ('S', Cell::Start),
+        ('X', Cell::Exit),
+    ]);
+
+    fn lines(texts: &[&str]) -> Vec<Line> {
+        texts.iter().enumerate().map(|(i, text)|Line::new(text.to_string(), i + 1)).collect()
+    }
+
+    fn parser() -> GridParser<Cell> {
+        GridParser::new("[Test]", MAPPING).exactly_one(Cell::Start).at_most_one(Cell::Exit)
+    }
+
+    #[test]
+    pub fn test_mapping_works_both_ways() {
+        assert_eq!(MAPPING.field('S'), Some(Cell::Start));
+        assert_eq!(MAPPING.field('?'), None);
+        assert_eq!(MAPPING.char(Cell::Wall), Some('#'));
+    }
+
+    #[test]
+    pub fn test_special_cells_are_indexed() {
+        let grid = parser().parse_lines(&lines(&["#S.", "..#"])).unwrap();
+        assert_eq!(grid.table.dim(), (2, 3));
+        assert_eq!(grid.single(Cell::Start), Ok(UPosition::new((0, 1))));
+        assert!(grid.positions(Cell::Exit).is_empty());
+        assert!(grid.positions(Cell::Wall).is_empty());
+        assert!(grid.single(Cell::Exit).is_err());
+    }
+
+    #[test]
+    pub fn test_error_points_at_cell() {
+        let lines = vec![Line::new("  #S.".to_string(), 4).sanitised("#S.".to_string(), 2)];
+        let grid = parser().parse_lines(&lines).unwrap();
+        let error = grid.error_at(UPosition::new((0, 1)), "bad start".to_string());
+        assert_eq!((error.line_number(), error.column), (Some(4), Some(4)));
+        assert!(error.snippet().unwrap().ends_with("4 |   #S.\n  |    ^^"), "{}", error.snippet().unwrap());
+        assert_eq!(grid.error_at(UPosition::new((1, 0)), "outside".to_string()).line, None);
+    }
+
+    #[test]
+    pub fn test_equality_ignores_locations() {
+        let moved = vec![Line::new("  #S.".to_string(), 7).sanitised("#S.".to_string(), 2)];
+        let grid = parser().parse_lines(&lines(&["#S."])).unwrap();
+        let moved_grid = parser().parse_lines(&moved).unwrap();
+        assert_eq!(grid, moved_grid);
+        assert_eq!(moved_grid.clone().error_at(UPosition::new((0, 1)), "bad start".to_string()).line_number(), Some(7));
+        assert_ne!(grid, parser().parse_lines(&lines(&["#.S"])).unwrap());
+    }
+
+    #[test]
+    pub fn test_occurrence_constraints() {
+        let error = parser().parse_lines(&lines(&["#..", "..#"])).unwrap_err();
+        assert_eq!(error.to_string(), "[Test] expected exactly one 'S', but there is none");
+
+        let error = parser().parse_lines(&lines(&["#SX", "X.#"])).unwrap_err();
+        assert_eq!((error.line_number(), error.column), (Some(2), Some(1)));
+        assert_eq!(error.to_string(), "[Test] expected at most one 'X', but found another one");
+    }
+
+    #[test]
+    pub fn test_unsupported_character() {
+        let error = parser().parse_lines(&lines(&["#S.", ".?#"])).unwrap_err();
+        assert_eq!(error.to_string(), "[Test] expected one of '#', '.', 'S', 'X', found '?#'");
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+pub mod json_output_test_suite {
+    use crate::{answer::DisplayableAnswer, create_executer_manager, execution_summary::{failure_json, record_json}, helper::json::JsonValue, pipeline_error::PipelineError, testing::resolve_filepath};
+
+    #[test]
+    pub fn test_json_rendering() {
+        let value = JsonValue::object(vec![
+            ("text", JsonValue::string("say \"hi\"\\\n\tbye\u{1}")),
+            ("items", JsonValue::Array(vec![JsonValue::Null, JsonValue::number(-12)])),
+            ("empty", JsonValue::object(vec![])),
+        ]);
+        assert_eq!(value.to_string(), r#"{"text":"say \"hi\"\\\n\tbye\u0001","items":[null,-12],"empty":{}}"#);
+    }
+
+    #[test]
+    pub fn test_displayable_answer_json() {
+        assert_eq!(DisplayableAnswer::new(18446744073709551615u64).to_json(), JsonValue::number("18446744073709551615"));
+        assert_eq!(DisplayableAnswer::new("z00,z01").to_json(), JsonValue::string("z00,z01"));
+        assert_eq!(DisplayableAnswer::new("+5").to_json(), JsonValue::string("+5"));
+        assert_eq!(DisplayableAnswer::new("007").to_json(), JsonValue::string("007"));
+        assert_eq!(DisplayableAnswer::new(-7).to_json(), JsonValue::number(-7));
+    }
+
+    #[test]
+    pub fn test_record_json() {
+        let manager = create_executer_manager().unwrap();
+        let input = resolve_filepath("src/day_24/test/example.txt");
+        let json = record_json(&manager.try_execute_record(&input, None, false).unwrap());
+        assert!(json.starts_with(r#"{"year":2024,"day":24,"part":1,"input":"#));
+        assert!(json.contains(r#""status":"ok","answer":4,"error":null,"elapsed_ms":"#));
+        assert!(json.contains(r#""stages_ms":{"read":"#));
+
+        let input = resolve_filepath("src/day_24/test/cycle_example.txt");
+        let json = record_json(&manager.try_execute_record(&input, None, true).unwrap());
+        assert!(json.contains(r#""status":"failed","answer":null,"error":{"message":"[Verifier D-24] "#));
+        assert!(json.contains(r#""stage":"verify","line":null}"#));
+    }
+
+    #[test]
+    pub fn test_failure_json() {
+        let error = PipelineError::new("no executer".to_string());
+        assert_eq!(failure_json(None, None, true, "input.txt", &error),
+            r#"{"year":null,"day":null,"part":2,"input":"input.txt","status":"failed","answer":null,"error":{"message":"no executer","stage":null,"line":null},"elapsed_ms":null,"stages_ms":{}}"#);
+    }
+}
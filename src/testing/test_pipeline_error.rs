@@ -0,0 +1,69 @@
+#[cfg(test)]
+pub mod pipeline_error_test_suite {
+    use std::sync::Arc;
+
+    use crate::{create_executer_manager, executer::Stage, pipeline_error::PipelineError, reader::Line, testing::resolve_filepath};
+
+    #[test]
+    pub fn test_display_joins_cause_chain() {
+        let cause = PipelineError::on_line(&Line::new("x00: 2".to_string(), 3), "bad wire value".to_string());
+        let error = PipelineError::new("[Parser D-24] parsing failed".to_string()).caused_by(cause);
+        assert_eq!(error.to_string(), "[Parser D-24] parsing failed: bad wire value");
+        assert_eq!(error.chain().len(), 2);
+        assert_eq!(error.line_number(), None);
+        assert_eq!(error.cause.unwrap().line_number(), Some(3));
+    }
+
+    #[test]
+    pub fn test_context_is_not_overwritten() {
+        let error = PipelineError::from("failure".to_string())
+            .with_stage(Stage::Parse)
+            .with_problem(7, true)
+            .with_stage(Stage::Solve)
+            .with_problem(8, false);
+        assert_eq!((error.stage, error.day, error.part), (Some(Stage::Parse), Some(7), Some(2)));
+        assert_eq!(error.to_string(), "failure");
+    }
+
+    #[test]
+    pub fn test_manager_attaches_stage_day_and_part() {
+        let manager = create_executer_manager().unwrap();
+        let error = manager.try_execute_executer(&resolve_filepath("src/day_24/test/cycle_example.txt"), None, false).unwrap_err();
+        assert_eq!((error.stage, error.day, error.part), (Some(Stage::Verify), Some(24), Some(1)));
+        assert!(error.to_string().starts_with("[Verifier D-24]"));
+
+        let error = manager.try_execute_executer("no-such-file.txt", Some(3), true).unwrap_err();
+        assert_eq!((error.stage, error.day, error.part), (Some(Stage::Read), Some(3), Some(2)));
+    }
+
+    #[test]
+    pub fn test_snippet_points_at_token() {
+        // The line was read as "  190: 10 x19" and trimmed, so the 'x' is at column 11 of the file
+        let line = Line::new("  190: 10 x19".to_string(), 3).with_path(Arc::from("input.txt")).sanitised("190: 10 x19".to_string(), 2);
+        let error = PipelineError::at(&line, 11, "unexpected token".to_string());
+        assert_eq!(error.snippet().unwrap(), " --> input.txt:3:11\n  |\n3 |   190: 10 x19\n  |           ^^^");
+        assert_eq!(error.diagnostic(), format!("unexpected token\n{}", error.snippet().unwrap()));
+    }
+
+    #[test]
+    pub fn test_snippet_without_column_or_path() {
+        let error = PipelineError::on_line(&Line::new("\tabc".to_string(), 12), "bad line".to_string());
+        assert_eq!(error.snippet().unwrap(), "  --> line #12\n   |\n12 | \tabc");
+        let error = PipelineError::at(&Line::new("\tabc".to_string(), 12), 2, "bad token".to_string());
+        assert!(error.snippet().unwrap().ends_with("line #12, column 2\n   |\n12 | \tabc\n   | \t^^^"));
+        assert_eq!(PipelineError::new("no line".to_string()).snippet(), None);
+        assert_eq!(PipelineError::new("no line".to_string()).diagnostic(), "no line");
+    }
+
+    #[test]
+    pub fn test_column_survives_line_trim() {
+        let manager = create_executer_manager().unwrap();
+        let input = resolve_filepath("src/day_14/test/indented_error_example.txt");
+        let error = manager.try_execute_executer(&input, None, false).unwrap_err();
+        assert_eq!((error.stage, error.line_number(), error.column), (Some(Stage::Parse), Some(3), Some(15)));
+        assert!(error.to_string().contains("expected digit, found 'x3'"), "{}", error);
+        let snippet = error.snippet().unwrap();
+        assert!(snippet.starts_with(&format!(" --> {}:3:15", input)), "{}", snippet);
+        assert!(snippet.ends_with("3 |    p=6,3 v=-1,x3\n  |               ^^"), "{}", snippet);
+    }
+}
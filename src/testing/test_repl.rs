@@ -0,0 +1,63 @@
+#[cfg(test)]
+pub mod repl_test_suite {
+    use crate::{parameters::Parameters, repl::{Reply, Session}, testing::resolve_filepath};
+
+    fn session() -> Session {
+        Session::new(Parameters::new(), None).unwrap()
+    }
+
+    /// Handles the line and returns the output
+    fn output(session: &mut Session, line: &str) -> String {
+        match session.handle(line) {
+            Reply::Output(output) => output,
+            Reply::Quit => panic!("unexpected quit on '{}'", line),
+        }
+    }
+
+    #[test]
+    pub fn test_run_and_switch_part() {
+        let mut session = session();
+        let input = resolve_filepath("src/day_07/test/example.txt");
+        assert!(output(&mut session, &format!("run {}", input)).ends_with("The answer is: 3749"));
+        output(&mut session, "part 2");
+        assert!(output(&mut session, "run").ends_with("The answer is: 11387"));
+        let both = output(&mut session, "both");
+        assert!(both.contains("Part 1:") && both.contains("3749") && both.contains("Part 2:") && both.contains("11387"));
+        assert_eq!(session.handle("quit"), Reply::Quit);
+    }
+
+    #[test]
+    pub fn test_model() {
+        let mut session = session();
+        output(&mut session, &format!("file {}", resolve_filepath("src/day_07/test/example.txt")));
+        assert!(output(&mut session, "model").starts_with("[(190 : 10,19),(3267 : 81,40,27)"));
+    }
+
+    #[test]
+    pub fn test_parameters() {
+        let mut session = session();
+        let input = resolve_filepath("src/day_21/test/example.txt");
+        output(&mut session, "part 2");
+        assert_eq!(output(&mut session, "param chain_part_2=2"), "Parameters: chain_part_2=2");
+        assert!(output(&mut session, &format!("run {}", input)).ends_with("126384"));
+
+        // A rejected override keeps the previous manager and overrides
+        assert!(output(&mut session, "param nope=1").contains("no day declares parameter 'nope'"));
+        assert_eq!(output(&mut session, "param"), "Parameters: chain_part_2=2");
+        assert_eq!(output(&mut session, "unset chain_part_2"), "Parameters: defaults");
+    }
+
+    #[test]
+    pub fn test_errors() {
+        let mut session = session();
+        assert_eq!(output(&mut session, "error"), "No error so far");
+        assert!(output(&mut session, "run").contains("no input file selected"));
+        assert!(output(&mut session, "part 3").contains("invalid part '3'"));
+        assert!(output(&mut session, "bogus").contains("unknown command 'bogus'"));
+
+        let input = resolve_filepath("src/day_24/test/cycle_example.txt");
+        assert!(output(&mut session, &format!("run {}", input)).contains("[Verifier D-24]"));
+        let error = output(&mut session, "error");
+        assert!(error.contains("Stage: verify") && error.contains("Problem: day 24, part 1"), "{}", error);
+    }
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+pub mod search_test_suite {
+    use crate::helper::search::{a_star, bfs, dijkstra};
+
+    /// Successors on a line of numbers 0..=10: a step to either side costs 1, a jump by 5 forward costs 3
+    fn weighted(n: i32) -> Vec<(i32, u64)> {
+        [(n - 1, 1), (n + 1, 1), (n + 5, 3)].into_iter().filter(|&(m, _)|(0..=10).contains(&m)).collect()
+    }
+
+    #[test]
+    pub fn test_bfs_distances_and_predecessors() {
+        // a 2x2 grid with positions as (row, col); both paths to the opposite corner are shortest
+        let successors = |(r, c): (u8, u8)|[(r ^ 1, c), (r, c ^ 1)];
+        let result = bfs([(0, 0)], successors);
+        assert_eq!(result.distance((1, 1)), Some(2));
+        assert_eq!(result.states_on_paths_to(&[(1, 1)]).len(), 4);
+        assert_eq!(result.path_to((1, 1)).unwrap().states.len(), 3);
+        assert_eq!(result.path_to((0, 0)).unwrap().states, vec![(0, 0)]);
+    }
+
+    #[test]
+    pub fn test_path_count() {
+        let successors = |(r, c): (u8, u8)|[(r + 1, c), (r, c + 1)].into_iter().filter(|&(r, c)|r < 3 && c < 3);
+        let result = bfs([(0, 0)], successors);
+        assert_eq!(result.path_count((2, 2)), 6);
+        assert_eq!(result.path_count((0, 0)), 1);
+        assert_eq!(result.path_count((3, 3)), 0);
+    }
+
+    #[test]
+    pub fn test_zero_cost_cycle_does_not_loop() {
+        // 1 and 2 reach each other at no cost, so both are equally far from 0
+        let successors = |n: u8|match n {
+            0 => vec![(1, 1), (2, 1)],
+            1 => vec![(2, 0)],
+            2 => vec![(1, 0)],
+            _ => vec![],
+        };
+        let result = dijkstra([0], successors);
+        assert_eq!((result.distance(1), result.distance(2)), (Some(1), Some(1)));
+        assert_eq!(result.path_to(2).unwrap().cost, 1);
+        assert_eq!(result.path_to(1).unwrap().states.first(), Some(&0));
+        assert_eq!(result.states_on_paths_to(&[1, 2]).len(), 3);
+        // the state settled second is also reached through the first one, but not the other way round
+        assert_eq!(result.path_count(1) + result.path_count(2), 3);
+    }
+
+    #[test]
+    pub fn test_dijkstra_prefers_cheaper_jumps() {
+        let result = dijkstra([0], weighted);
+        assert_eq!(result.distance(10), Some(6));
+        assert_eq!(result.distance(4), Some(4));
+        assert_eq!(result.path_to(10).unwrap().states, vec![0, 5, 10]);
+        assert_eq!(result.distance(11), None);
+        assert_eq!(result.path_to(11), None);
+    }
+
+    #[test]
+    pub fn test_a_star_finds_shortest_path() {
+        let heuristic = |n: i32|(10 - n).max(0) as u64 / 5 * 3;
+        let path = a_star(0, weighted, heuristic, |n|n == 10).unwrap();
+        assert_eq!((path.cost, path.states), (6, vec![0, 5, 10]));
+        assert_eq!(a_star(0, weighted, |_|0, |n|n == 42), None);
+    }
+
+    #[test]
+    pub fn test_a_star_with_inconsistent_heuristic() {
+        // The heuristic of 'A' is admissible (A-C-G costs 4), but not consistent (A-C costs 1 and 'C' has 0),
+        // so 'C' is first expanded through the direct, more expensive step and has to be expanded again
+        let successors = |state: char|match state {
+            'S' => vec![('A', 1), ('C', 3)],
+            'A' => vec![('C', 1)],
+            'C' => vec![('G', 3)],
+            _ => vec![],
+        };
+        let heuristic = |state: char|if state == 'A' { 4 } else { 0 };
+        let path = a_star('S', successors, heuristic, |state|state == 'G').unwrap();
+        assert_eq!((path.cost, path.states), (5, vec!['S', 'A', 'C', 'G']));
+    }
+}
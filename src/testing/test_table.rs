@@ -0,0 +1,56 @@
+#[cfg(test)]
+pub mod table_test_suite {
+    use crate::helper::{position::UPosition, table::Table};
+
+    fn table() -> Table<u8> {
+        Table::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    pub fn test_set_and_get_mut() {
+        let mut table = table();
+        assert_eq!(table.set(UPosition::new((1, 2)), 9), Some(6));
+        assert_eq!(table.set(UPosition::new((2, 0)), 9), None);
+        *table.get_mut(UPosition::zero()).unwrap() += 10;
+        assert_eq!(table.to_string(), "11  2  3\n 4  5  9");
+    }
+
+    #[test]
+    pub fn test_row_and_column_views() {
+        let table = table();
+        assert_eq!(table.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(table.row(2), None);
+        assert_eq!(table.column(1), Some(vec![&2, &5]));
+        assert_eq!(table.column(3), None);
+        assert_eq!(table.rows().count(), 2);
+    }
+
+    #[test]
+    pub fn test_neighbours_respect_boundary() {
+        let table = table();
+        let corner = table.neighbours(UPosition::zero()).collect::<Vec<_>>();
+        assert_eq!(corner, vec![UPosition::new((0, 1)), UPosition::new((1, 0))]);
+        assert_eq!(table.neighbours(UPosition::new((1, 1))).count(), 3);
+        assert_eq!(table.neighbours_with_diagonals(UPosition::new((1, 1))).count(), 5);
+        assert_eq!(table.neighbours_with_diagonals(UPosition::new((0, 0))).count(), 3);
+    }
+
+    #[test]
+    pub fn test_transpose_and_rotate() {
+        let table = table();
+        assert_eq!(table.transpose().to_string(), "14\n25\n36");
+        assert_eq!(table.transpose().dim(), (3, 2));
+        assert_eq!(table.rotate().to_string(), "41\n52\n63");
+        assert_eq!(table.rotate().rotate().rotate().rotate(), table);
+    }
+
+    #[test]
+    pub fn test_map_and_zip() {
+        let table = table();
+        let doubled = table.map(|&value|value * 2);
+        assert_eq!(doubled.to_string(), " 2  4  6\n 8 10 12");
+        let differences = doubled.zip(&table, |a, b|a - b).unwrap();
+        assert_eq!(differences, table);
+        assert!(table.zip(&table.transpose(), |a, b|a + b).is_err());
+    }
+}
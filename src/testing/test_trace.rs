@@ -0,0 +1,40 @@
+#[cfg(test)]
+pub mod trace_test_suite {
+    use crate::{create_executer_manager, executer::{Stage, StageTrace}, testing::resolve_filepath};
+
+    fn stages(trace: &StageTrace) -> Vec<Stage> {
+        trace.stages.iter().map(|(stage, _)|*stage).collect()
+    }
+
+    #[test]
+    pub fn test_trace_of_all_stages() {
+        let manager = create_executer_manager().unwrap();
+        let input = resolve_filepath("src/day_14/test/example.txt");
+        let (outcome, trace) = manager.try_execute_traced(&input, None, false).unwrap();
+        assert!(outcome.is_ok());
+        assert_eq!(stages(&trace), Stage::all());
+
+        // The read lines are numbered as in the file, after the directive declaring the parameters
+        let (_, read) = &trace.stages[0];
+        assert_eq!(read.lines().take(2).collect::<Vec<_>>(), vec!["parameters: height=7 width=11", " 2 | p=0,4 v=3,-3"]);
+        assert_eq!(trace.stages[3].1, outcome.unwrap().report());
+    }
+
+    #[test]
+    pub fn test_trace_stops_at_failed_stage() {
+        let manager = create_executer_manager().unwrap();
+        let input = resolve_filepath("src/day_24/test/cycle_example.txt");
+        let (outcome, trace) = manager.try_execute_traced(&input, None, false).unwrap();
+        assert!(outcome.is_err());
+        assert_eq!(stages(&trace), vec![Stage::Read, Stage::Parse]);
+    }
+
+    #[test]
+    pub fn test_trace_report_truncation() {
+        let mut trace = StageTrace::new();
+        trace.record(Stage::Read, (1..=5).map(|i|i.to_string()).collect::<Vec<_>>().join("\n"));
+        trace.record(Stage::Parse, "x".repeat(10));
+        assert_eq!(trace.report(3, 100), "== read ==\n1\n2\n3\n... truncated, 5 of 9 characters shown\n== parse ==\nxxxxxxxxxx");
+        assert_eq!(trace.report(3, 4), "== read ==\n1\n2\n... truncated, 3 of 9 characters shown\n== parse ==\nxxxx\n... truncated, 4 of 10 characters shown");
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+pub mod verifier_test_suite {
+    use crate::{parameters::Parameters, verifier::{all_of, check, each, ParameterisedVerifier, Verify}};
+
+    fn not_empty() -> impl Verify<Vec<u32>> {
+        check(|numbers: &Vec<u32>|if numbers.is_empty() { Err(String::from("no numbers")) } else { Ok(()) })
+    }
+
+    fn sorted() -> impl Verify<Vec<u32>> {
+        check(|numbers: &Vec<u32>|if numbers.is_sorted() { Ok(()) } else { Err(String::from("numbers not sorted")) })
+    }
+
+    fn odd(number: &u32) -> Result<(), String> {
+        if number % 2 == 1 { Ok(()) } else { Err(format!("number {} is even", number)) }
+    }
+
+    fn message<T>(result: Result<T, crate::pipeline_error::PipelineError>) -> String {
+        result.err().unwrap().to_string()
+    }
+
+    #[test]
+    pub fn test_and_then() {
+        let verifier = not_empty().and_then(sorted());
+        assert_eq!(verifier.verify(vec![1, 2, 4]), Ok(vec![1, 2, 4]));
+        assert_eq!(message(verifier.verify(vec![])), "no numbers");
+        assert_eq!(message(verifier.verify(vec![2, 1])), "numbers not sorted");
+    }
+
+    #[test]
+    pub fn test_all_of() {
+        let verifier = all_of::<Vec<u32>>(vec![Box::new(sorted()), Box::new(not_empty())]);
+        assert_eq!(verifier.verify(vec![3]), Ok(vec![3]));
+        assert_eq!(message(verifier.verify(vec![])), "no numbers");
+        // The first failing verifier is reported
+        assert_eq!(message(all_of(vec![Box::new(sorted()), Box::new(not_empty())]).verify(vec![2, 1])), "numbers not sorted");
+        assert_eq!(all_of::<Vec<u32>>(vec![]).verify(vec![]), Ok(vec![]));
+    }
+
+    #[test]
+    pub fn test_each_reports_index() {
+        let verifier = each(Vec::as_slice, odd);
+        assert_eq!(verifier.verify(vec![1, 3, 5]), Ok(vec![1, 3, 5]));
+        assert_eq!(message(verifier.verify(vec![1, 3, 4, 6])), "number 4 is even (item with index 2)");
+    }
+
+    #[test]
+    pub fn test_parameterised_verifier() {
+        let verifier = ParameterisedVerifier::new(|parameters|{
+            let limit: u32 = parameters.get_or("limit", 10)?;
+            Ok(each(Vec::as_slice, move |&number: &u32|match number < limit {
+                true => Ok(()),
+                false => Err(format!("number {} exceeds {}", number, limit)),
+            }))
+        });
+        assert_eq!(verifier.verify(vec![9]), Ok(vec![9]));
+        let mut parameters = Parameters::new();
+        parameters.insert("limit", "5");
+        assert_eq!(message(verifier.verify_with(vec![1, 9], &parameters)), "number 9 exceeds 5 (item with index 1)");
+        parameters.insert("limit", "x");
+        assert!(verifier.verify_with(vec![1], &parameters).is_err());
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+pub mod watch_test_suite {
+    use std::time::{Duration, SystemTime};
+
+    use crate::{create_executer_manager, watch::{AnswerChange, Watcher}};
+
+    /// Writes the input file and sets its modification time to the given number of seconds after the epoch
+    fn write_input(input_filepath: &str, text: &str, modified_secs: u64) {
+        std::fs::write(input_filepath, text).unwrap();
+        let file = std::fs::File::options().write(true).open(input_filepath).unwrap();
+        file.set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(modified_secs)).unwrap();
+    }
+
+    #[test]
+    pub fn test_watcher_runs_on_modification() {
+        let manager = create_executer_manager().unwrap();
+        let input = std::env::temp_dir().join(format!("aoc_2024_watch_{}_d01.txt", std::process::id())).to_string_lossy().to_string();
+        let example = "3 4\n4 3\n2 5\n1 3\n3 9\n3 3\n";
+        write_input(&input, example, 1_000);
+        let mut watcher = Watcher::new(&manager, &input, None, false, Duration::from_millis(1)).unwrap();
+
+        // The first poll always runs, the following ones only once the file is modified
+        let run = watcher.poll().unwrap().unwrap();
+        assert_eq!((run.number, run.outcome.unwrap().summary(), run.change), (1, "11".to_string(), Some(AnswerChange::First)));
+        assert!(watcher.poll().unwrap().is_none());
+
+        write_input(&input, &format!("{}10 10\n", example), 2_000);
+        let run = watcher.poll().unwrap().unwrap();
+        assert_eq!((run.number, run.change), (2, Some(AnswerChange::Unchanged)));
+
+        write_input(&input, &format!("{}1 10\n", example), 3_000);
+        let run = watcher.poll().unwrap().unwrap();
+        assert_eq!(run.outcome.unwrap().summary(), "20");
+        assert!(matches!(run.change, Some(AnswerChange::Changed(previous)) if previous.contains("11")));
+
+        // A failed run has no change, and the answer is then compared with the last successful one
+        write_input(&input, "x y\n", 4_000);
+        let run = watcher.poll().unwrap().unwrap();
+        assert!(run.outcome.is_err() && run.change.is_none());
+        write_input(&input, &format!("{}1 10\n", example), 5_000);
+        assert_eq!(watcher.poll().unwrap().unwrap().change, Some(AnswerChange::Unchanged));
+
+        std::fs::remove_file(&input).unwrap();
+        assert!(watcher.poll().unwrap_err().contains("modification time"));
+    }
+
+    #[test]
+    pub fn test_watcher_errors() {
+        let manager = create_executer_manager().unwrap();
+        assert!(Watcher::new(&manager, "-", Some(1), false, Duration::from_millis(1)).is_err());
+        assert!(Watcher::new(&manager, "d01.txt", None, false, Duration::ZERO).is_err());
+        assert!(Watcher::new(&manager, "input.txt", None, false, Duration::from_millis(1)).is_err());
+    }
+}
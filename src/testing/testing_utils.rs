@@ -1,5 +1,5 @@
 #[cfg(test)]
-use crate::{pipelined_executer::PipelinedExecuter,answer::Answer};
+use crate::{pipelined_executer::PipelinedExecuter,answer::Answer,pipeline_error::PipelineError};
 
 #[cfg(test)]
 use std::fmt::{Display, Debug};
@@ -28,7 +28,7 @@ where T: Eq + Display + Clone + Debug {
     let input_file_path = &resolve_filepath(root_relative_path);
     let lines = pipeline.read(input_file_path);
     assert!(lines.is_ok());
-    pipeline.parse(lines.unwrap())
+    pipeline.parse(lines.unwrap()).map_err(|e|e.to_string())
 }
 
 /// Helper method that reads the file, parses the lines and returns an underlying value of succesful parsed result.
@@ -41,6 +41,19 @@ where T: Eq + Display + Clone + Debug {
     parsed.unwrap()
 }
 
+/// Helper method that sanitises the given text the same way as `SanitisedFileReader::default` and parses the lines.
+/// Useful for inputs too small to deserve a fixture file. Returns parsed result (successful or not), keeping
+/// the error as is, so that the offending line and column can be checked
+#[cfg(test)]
+#[allow(dead_code)]
+pub fn get_parsed_text_result<T>(pipeline: &PipelinedExecuter<T>, text: &str) -> Result<T, PipelineError>
+where T: Eq + Display + Clone + Debug {
+    use crate::{parser::Parse, reader::Read, reading::{SanitisedFileReader, StringReader}};
+    let lines = SanitisedFileReader::default_with(StringReader::new(text)).read("");
+    assert!(lines.is_ok());
+    pipeline.parse(lines.unwrap())
+}
+
 /// Helper method that reads the file, parses the lines successfully and compares parsed value against expected.
 /// Performs all assertions along the way.
 #[cfg(test)]
@@ -64,13 +77,16 @@ where T: Eq + Display + Clone + Debug {
 
 /* VERIFIER SECTION */
 
-/// Helper method that reads the file, parses the lines successfully and returns verified result.
-/// Performs all assertions along the way.
+/// Helper method that reads the file, parses the lines successfully and returns verified result,
+/// verified with the parameters declared in the file. Performs all assertions along the way.
 #[cfg(test)]
 pub fn get_verified_result<T>(pipeline: &PipelinedExecuter<T>, root_relative_path: &str) -> Result<T, String>
 where T: Eq + Display + Clone + Debug {
-    use crate::verifier::Verify;
-    pipeline.verify(get_parsed_result_ok(pipeline, root_relative_path))
+    use crate::{reader::Read, verifier::Verify};
+    let lines = pipeline.read(&resolve_filepath(root_relative_path));
+    assert!(lines.is_ok());
+    let parameters = lines.unwrap().parameters;
+    pipeline.verify_with(get_parsed_result_ok(pipeline, root_relative_path), &parameters).map_err(|e|e.to_string())
 }
 
 /// Helper method that reads the file, parses the lines successfully and returns successfully verified result.
@@ -104,8 +120,9 @@ where T: Eq + Display + Clone + Debug {
 #[cfg(test)]
 pub fn get_answer<T>(pipeline: &PipelinedExecuter<T>, root_relative_path: &str) -> Result<Answer, String>
 where T: Eq + Display + Clone + Debug {
-    use crate::solver::Solve;
-    pipeline.solve(get_verified_result_ok(pipeline, root_relative_path))
+    use crate::{reader::Read, solver::Solve};
+    let parameters = pipeline.read(&resolve_filepath(root_relative_path)).map(|lines|lines.parameters).unwrap_or_default();
+    pipeline.solve_with(get_verified_result_ok(pipeline, root_relative_path), &parameters).map_err(|e|e.to_string())
 }
 
 
@@ -1,8 +1,21 @@
+use crate::{parameters::Parameters, pipeline_error::PipelineError};
+
+
 pub type Verifier<T> = Box<dyn Verify<T>>;
 
-pub trait Verify<T>
+pub trait Verify<T>: Send + Sync
 {
-    fn verify(&self, input: T) -> Result<T, String>;
+    fn verify(&self, input: T) -> Result<T, PipelineError>;
+
+    /// Verifies with the parameters declared in the input file. Verifiers without parameters just ignore them.
+    fn verify_with(&self, input: T, _parameters: &Parameters) -> Result<T, PipelineError> {
+        self.verify(input)
+    }
+
+    /// Combines this verifier with the `next` one, which verifies the input only once this one accepted it
+    fn and_then<V>(self, next: V) -> AndThenVerifier<Self, V> where Self: Sized, V: Verify<T> {
+        AndThenVerifier { first: self, next }
+    }
 }
 
 pub struct TrivialVerifier;
@@ -12,7 +25,106 @@ impl TrivialVerifier {
 }
 
 impl <T> Verify<T> for TrivialVerifier {
-    fn verify(&self, input: T) -> Result<T, String> {
+    fn verify(&self, input: T) -> Result<T, PipelineError> {
+        Ok(input)
+    }
+}
+
+/// Verifier made by `Verify::and_then`
+pub struct AndThenVerifier<V, W> {
+    first: V,
+    next: W,
+}
+
+impl <T, V: Verify<T>, W: Verify<T>> Verify<T> for AndThenVerifier<V, W> {
+    fn verify(&self, input: T) -> Result<T, PipelineError> {
+        self.first.verify(input).and_then(|input|self.next.verify(input))
+    }
+
+    fn verify_with(&self, input: T, parameters: &Parameters) -> Result<T, PipelineError> {
+        self.first.verify_with(input, parameters).and_then(|input|self.next.verify_with(input, parameters))
+    }
+}
+
+/// Verifier applying the verifiers in their order, failing with the error of the first one rejecting the input
+pub struct AllOfVerifier<T> {
+    verifiers: Vec<Verifier<T>>,
+}
+
+pub fn all_of<T>(verifiers: Vec<Verifier<T>>) -> AllOfVerifier<T> {
+    AllOfVerifier { verifiers }
+}
+
+impl <T> Verify<T> for AllOfVerifier<T> {
+    fn verify(&self, input: T) -> Result<T, PipelineError> {
+        self.verifiers.iter().try_fold(input, |input, verifier|verifier.verify(input))
+    }
+
+    fn verify_with(&self, input: T, parameters: &Parameters) -> Result<T, PipelineError> {
+        self.verifiers.iter().try_fold(input, |input, verifier|verifier.verify_with(input, parameters))
+    }
+}
+
+/// Verifier defined by a check of the whole input
+pub struct CheckVerifier<F> {
+    check_fn: F,
+}
+
+/// Makes a verifier accepting the input if the check passes, and failing with the error of the check otherwise.
+/// The check fails either with a message, or with a `PipelineError` pointing at the offending input.
+pub fn check<T, F, E>(check_fn: F) -> CheckVerifier<F> where F: Fn(&T) -> Result<(), E> + Send + Sync, E: Into<PipelineError> {
+    CheckVerifier { check_fn }
+}
+
+impl <T, F, E> Verify<T> for CheckVerifier<F> where F: Fn(&T) -> Result<(), E> + Send + Sync, E: Into<PipelineError> {
+    fn verify(&self, input: T) -> Result<T, PipelineError> {
+        (self.check_fn)(&input).map_err(Into::into)?;
+        Ok(input)
+    }
+}
+
+/// Verifier defined by a check of every item of the input
+pub struct EachVerifier<T, I, F> {
+    items_fn: fn(&T) -> &[I],
+    check_fn: F,
+}
+
+/// Makes a verifier checking every item of the input, as returned by `items_fn`. The first item failing
+/// the check rejects the input, with the message of the check followed by the index of the item.
+pub fn each<T, I, F>(items_fn: fn(&T) -> &[I], check_fn: F) -> EachVerifier<T, I, F> where F: Fn(&I) -> Result<(), String> + Send + Sync {
+    EachVerifier { items_fn, check_fn }
+}
+
+impl <T, I, F> Verify<T> for EachVerifier<T, I, F> where F: Fn(&I) -> Result<(), String> + Send + Sync {
+    fn verify(&self, input: T) -> Result<T, PipelineError> {
+        for (index, item) in (self.items_fn)(&input).iter().enumerate() {
+            (self.check_fn)(item).map_err(|message|PipelineError::new(format!("{} (item with index {})", message, index)))?;
+        }
         Ok(input)
     }
-}
\ No newline at end of file
+}
+
+type MakeVerifierFn<T> = Box<dyn Fn(&Parameters) -> Result<Verifier<T>, String> + Send + Sync>;
+
+/// A verifier that is created only once the parameters declared in the input file are known.
+/// Verifying without parameters creates the verifier from an empty set, i.e. with the defaults.
+pub struct ParameterisedVerifier<T> {
+    make_verifier: MakeVerifierFn<T>,
+}
+
+impl <T> ParameterisedVerifier<T> {
+    pub fn new<V, F>(make_verifier: F) -> ParameterisedVerifier<T>
+    where V: Verify<T> + 'static, F: Fn(&Parameters) -> Result<V, String> + Send + Sync + 'static {
+        ParameterisedVerifier { make_verifier: Box::new(move |parameters|make_verifier(parameters).map(|verifier|Box::new(verifier) as Verifier<T>)) }
+    }
+}
+
+impl <T> Verify<T> for ParameterisedVerifier<T> {
+    fn verify(&self, input: T) -> Result<T, PipelineError> {
+        self.verify_with(input, &Parameters::new())
+    }
+
+    fn verify_with(&self, input: T, parameters: &Parameters) -> Result<T, PipelineError> {
+        (self.make_verifier)(parameters)?.verify_with(input, parameters)
+    }
+}
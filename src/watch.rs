@@ -0,0 +1,109 @@
+use std::time::{Duration, SystemTime};
+
+use crate::{answer::Answer, executer::StageTimings, executer_manager::ExecuterManager, pipeline_error::PipelineError, reading::STDIN_PATH};
+
+mod error {
+    const PREFIX: &str = "[Watch]";
+
+    pub fn zero_interval() -> String {
+        format!("{} polling interval must be at least 1 ms", PREFIX)
+    }
+
+    pub fn stdin() -> String {
+        format!("{} the standard input cannot be watched, an input file is required", PREFIX)
+    }
+
+    pub fn modification_time(input_filepath: &str, reason: String) -> String {
+        format!("{} could not read modification time of '{}': {}", PREFIX, input_filepath, reason)
+    }
+}
+
+/// How the answer of a run compares with the answer of the previous successful run
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum AnswerChange {
+    /// There was no previous successful run
+    First,
+    Unchanged,
+    /// The answer differs; holds the previous answer
+    Changed(String),
+}
+
+/// A run of the watched executer, made after its input file was modified
+#[derive(Debug)]
+pub struct WatchRun {
+    /// Number of the run, starting at 1
+    pub number: usize,
+    pub outcome: Result<Answer, PipelineError>,
+    pub timings: StageTimings,
+    /// Change of the answer; `None` if the run failed
+    pub change: Option<AnswerChange>,
+}
+
+/// Re-runs an executer against its input file whenever the file is modified.
+/// Modifications are detected by polling the modification time of the file, so no notification service is needed.
+pub struct Watcher<'a> {
+    manager: &'a ExecuterManager,
+    input_filepath: String,
+    day: Option<u8>,
+    is_part_2: bool,
+    interval: Duration,
+    last_modified: Option<SystemTime>,
+    previous_answer: Option<String>,
+    runs: usize,
+}
+
+impl<'a> Watcher<'a> {
+    /// Creates a watcher of the executer resolved as in `ExecuterManager::try_execute_executer`,
+    /// polling the input file every `interval`
+    pub fn new(manager: &'a ExecuterManager, input_filepath: &str, day: Option<u8>, is_part_2: bool, interval: Duration)
+    -> Result<Watcher<'a>, String> {
+        if interval.is_zero() { return Err(error::zero_interval()); }
+        if input_filepath == STDIN_PATH { return Err(error::stdin()); }
+        manager.try_resolve_key(input_filepath, day, is_part_2)?;
+        Ok(Watcher {
+            manager,
+            input_filepath: input_filepath.to_string(),
+            day,
+            is_part_2,
+            interval,
+            last_modified: None,
+            previous_answer: None,
+            runs: 0,
+        })
+    }
+
+    fn modified(&self) -> Result<SystemTime, String> {
+        std::fs::metadata(&self.input_filepath)
+            .and_then(|metadata|metadata.modified())
+            .map_err(|e|error::modification_time(&self.input_filepath, e.to_string()))
+    }
+
+    /// Runs the executer if the input file was modified since the last poll; the first poll always runs it.
+    /// Returns `None` if the file was not modified, and an error if its modification time could not be read
+    /// or no executer could be found.
+    pub fn poll(&mut self) -> Result<Option<WatchRun>, String> {
+        let modified = self.modified()?;
+        if self.last_modified == Some(modified) {
+            return Ok(None);
+        }
+        self.last_modified = Some(modified);
+        let (outcome, timings) = self.manager.try_execute_timed(&self.input_filepath, self.day, self.is_part_2)?;
+        let change = outcome.as_ref().ok().map(|answer|{
+            let answer = answer.report();
+            let change = match self.previous_answer.take() {
+                None => AnswerChange::First,
+                Some(previous) if previous == answer => AnswerChange::Unchanged,
+                Some(previous) => AnswerChange::Changed(previous),
+            };
+            self.previous_answer = Some(answer);
+            change
+        });
+        self.runs += 1;
+        Ok(Some(WatchRun { number: self.runs, outcome, timings, change }))
+    }
+
+    /// Waits for the polling interval
+    pub fn wait(&self) {
+        std::thread::sleep(self.interval);
+    }
+}